@@ -0,0 +1,302 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to the [`Dialog`]s and the [`Toast`]s shown over the
+//! view, closed by their [`Action`]s.
+
+use std::time::Duration;
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    themes::{Component, Scale, Spacing, State, Style, Theme},
+    widgets::{Button, Child, Label, Layout, Weighted},
+    Align,
+    Direction,
+    DebugWidget,
+    Overflow,
+    Padding,
+    Widget,
+    Zone
+};
+
+use super::{
+    keyboard::{self, Key},
+    pointer::{self, Kind},
+    Controller,
+    Handler,
+};
+
+/// Default size of a dialog.
+pub const DIALOG_SIZE: Size = [320, 160];
+
+/// Default size of a toast.
+pub const TOAST_SIZE: Size = [320, 48];
+
+/// Default time a toast is shown.
+pub const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Function to call when a dialog or a toast is closed, with the index of the
+/// chosen action, or `None` when it was dismissed.
+type CloseFn = fn(layout: &mut Layout, action: Option<usize>, theme: &Theme);
+
+/// Focusable controller of a [`Button`] closing the dialog or the toast
+/// containing it when clicked, or with enter or space.
+///
+/// The view closes the popup containing the action, and tells the dialog or
+/// the toast which action was chosen.
+#[derive(Controller)]
+pub struct Action {
+    /// The button.
+    pub widget: Box<Button>,
+    /// The zone of the button.
+    pub zone: Zone,
+    /// The transformation of the zone of the button.
+    pub matrix: Matrix,
+    /// The interaction state of the button.
+    pub state: State,
+    /// The index of the action in its dialog or its toast.
+    pub index: usize,
+    /// Whether the action was chosen, taken by the view.
+    chosen: bool,
+}
+
+impl Action {
+    /// Creates a new action.
+    pub fn new(index: usize, button: Button) -> Self {
+        Self {
+            widget: Box::new(button),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            index,
+            chosen: false,
+        }
+    }
+
+    /// Chooses the action, unless it is disabled.
+    pub fn choose(&mut self) {
+        self.chosen = self.state != State::Disabled;
+    }
+}
+
+impl Handler for Action {
+    /// Chooses the action when it is clicked.
+    fn on_pointer_default(&mut self, event: &pointer::Event, _: &Theme) {
+        if event.kind == Kind::Click {
+            self.choose();
+        }
+    }
+
+    /// Chooses the action with enter or space.
+    fn on_key_default(&mut self, event: &keyboard::Event, _: &Theme) {
+        if event.is_down(Key::Enter) || event.is_down(Key::Space) {
+            self.choose();
+        }
+    }
+
+    fn take_choice(&mut self) -> Option<usize> {
+        match std::mem::take(&mut self.chosen) {
+            true => Some(self.index),
+            false => None,
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
+
+/// Creates the row of the actions of a dialog or a toast, the button of each
+/// action sharing its width.
+fn actions(actions: &[&str]) -> Layout {
+    Layout::normal(
+        Overflow::Hide,
+        Align::Right,
+        Align::Center,
+        Direction::Column,
+        actions
+            .iter()
+            .enumerate()
+            .map(|(i, action)| Action::new(i, Button::normal(Label::normal(action))).into())
+            .collect(),
+    )
+    .with_gap(Scale::Small)
+}
+
+/// Controller of a dialog, a layout with a title, a text and actions, shown
+/// by the [`View`](crate::widgets::View) in the middle of its zone.
+///
+/// The dialog is modal, the widgets under it are not reachable until it is
+/// closed by one of its actions. Its function is called when it is closed.
+#[derive(Controller)]
+pub struct Dialog {
+    /// The layout of the dialog.
+    pub widget: Box<Layout>,
+    /// The zone of the dialog.
+    pub zone: Zone,
+    /// The transformation of the zone of the dialog.
+    pub matrix: Matrix,
+    /// The interaction state of the dialog.
+    pub state: State,
+    /// The size of the dialog.
+    pub size: Size,
+    /// Whether pressing out of the dialog or escape closes it.
+    pub dismissible: bool,
+    /// Function to call when the dialog is closed.
+    on_close: CloseFn,
+}
+
+impl Dialog {
+    /// Creates a new dialog, not dismissible, with a title, a text and the
+    /// labels of its actions.
+    pub fn new(title: &str, text: &str, actions: &[&str], on_close: CloseFn) -> Self {
+        let title = Label {
+            style: None,
+            component: Component::Dialog,
+            text: title.to_string(),
+        };
+        let text = Label {
+            style: None,
+            component: Component::Dialog,
+            text: text.to_string(),
+        };
+
+        let layout = Layout {
+            style: None,
+            component: Component::Dialog,
+            overflow: Overflow::Hide,
+            wx_align: Align::Left,
+            wy_align: Align::Top,
+            direction: Direction::Row,
+            padding: Padding::all(Scale::Medium),
+            gap: Spacing::from(Scale::Small),
+            widgets: vec![title.into(), Weighted::new(2.0, text).into(), self::actions(actions).into()],
+        };
+
+        Self {
+            widget: Box::new(layout),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            size: DIALOG_SIZE,
+            dismissible: false,
+            on_close,
+        }
+    }
+
+    /// Returns the dialog with another size.
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Returns the dialog closed by pressing out of it or by escape.
+    pub fn with_dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+}
+
+impl Handler for Dialog {
+    /// Calls the function of the dialog with the chosen action.
+    fn on_popup_close(&mut self, choice: Option<usize>, theme: &Theme) {
+        let on_close = self.on_close;
+        on_close(&mut self.widget, choice, theme);
+    }
+}
+
+/// Controller of a toast, a short message with an optional action, shown by
+/// the [`View`](crate::widgets::View) at the bottom of its zone for a while.
+///
+/// The toasts are queued, one is shown at a time until its duration passed
+/// or its action is chosen. Its function is called when it is closed.
+#[derive(Controller)]
+pub struct Toast {
+    /// The layout of the toast.
+    pub widget: Box<Layout>,
+    /// The zone of the toast.
+    pub zone: Zone,
+    /// The transformation of the zone of the toast.
+    pub matrix: Matrix,
+    /// The interaction state of the toast.
+    pub state: State,
+    /// The size of the toast.
+    pub size: Size,
+    /// The time the toast is shown.
+    pub duration: Duration,
+    /// Function to call when the toast is closed.
+    on_close: CloseFn,
+}
+
+impl Toast {
+    /// Creates a new toast with a text and the label of its action, if any.
+    pub fn new(text: &str, action: Option<&str>, on_close: CloseFn) -> Self {
+        let text = Label {
+            style: None,
+            component: Component::Toast,
+            text: text.to_string(),
+        };
+
+        let mut widgets: Vec<Box<dyn Widget>> = vec![Weighted::new(3.0, text).into()];
+
+        if let Some(action) = action {
+            widgets.push(actions(&[action]).into());
+        }
+
+        let layout = Layout {
+            style: None,
+            component: Component::Toast,
+            overflow: Overflow::Hide,
+            wx_align: Align::Left,
+            wy_align: Align::Center,
+            direction: Direction::Column,
+            padding: Padding::symmetric(Scale::Small, Scale::Medium),
+            gap: Spacing::from(Scale::Small),
+            widgets,
+        };
+
+        Self {
+            widget: Box::new(layout),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            size: TOAST_SIZE,
+            duration: TOAST_DURATION,
+            on_close,
+        }
+    }
+
+    /// Returns the toast with another size.
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Returns the toast shown for another time.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+impl Handler for Toast {
+    /// Calls the function of the toast with the chosen action.
+    fn on_popup_close(&mut self, choice: Option<usize>, theme: &Theme) {
+        let on_close = self.on_close;
+        on_close(&mut self.widget, choice, theme);
+    }
+}
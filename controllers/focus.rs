@@ -0,0 +1,72 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to the keyboard focus.
+//!
+//! One controller at most has the keyboard focus, it receives the keyboard
+//! events. The focus moves with tab and shift-tab following the order of the
+//! focusable controllers in the widget tree, or with the pointer pressing a
+//! focusable controller.
+
+/// Tracks the focused controller of a [`View`](crate::widgets::View).
+///
+/// The controllers are identified by the identifier of the drawables built
+/// for them.
+#[derive(Debug, Clone, Default)]
+pub struct Manager {
+    /// The focused controller.
+    focused: Option<usize>,
+    /// Whether the focus was given with the keyboard.
+    visible: bool,
+}
+
+impl Manager {
+    /// Returns the focused controller.
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Whether the focus must be visible, because it was given with the
+    /// keyboard.
+    pub fn is_visible(&self) -> bool {
+        self.focused.is_some() && self.visible
+    }
+
+    /// Focuses a controller, or no controller. Returns whether the focus
+    /// changed.
+    pub fn set(&mut self, focused: Option<usize>, visible: bool) -> bool {
+        let changed = self.focused != focused || self.visible != visible;
+
+        self.focused = focused;
+        self.visible = visible;
+
+        changed
+    }
+
+    /// Returns the controller after the focused one in the focus order,
+    /// going back to the first one after the last one.
+    ///
+    /// Without focused controller, it is the first one.
+    pub fn next(&self, order: &[usize]) -> Option<usize> {
+        let position = self.focused.and_then(|focused| order.iter().position(|&id| id == focused));
+
+        match position {
+            Some(position) => order.get((position + 1) % order.len()).copied(),
+            None => order.first().copied(),
+        }
+    }
+
+    /// Returns the controller before the focused one in the focus order,
+    /// going to the last one before the first one.
+    ///
+    /// Without focused controller, it is the last one.
+    pub fn previous(&self, order: &[usize]) -> Option<usize> {
+        let position = self.focused.and_then(|focused| order.iter().position(|&id| id == focused));
+
+        match position {
+            Some(position) => order.get((position + order.len() - 1) % order.len()).copied(),
+            None => order.last().copied(),
+        }
+    }
+}
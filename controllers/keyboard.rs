@@ -0,0 +1,245 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to keyboard events.
+//!
+//! The backend gives the keyboard [`Event`]s to the
+//! [`View`](crate::widgets::View), which routes them to the focused controller
+//! and moves the focus when tab is pressed.
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    themes::{State, Style, Theme},
+    widgets::Child,
+    DebugWidget,
+    Widget,
+    Zone
+};
+
+use super::{
+    propagation::{Phase, Propagation},
+    Controller,
+    Handler,
+};
+
+/// Key of the keyboard, independently of the keyboard layout for the named
+/// keys.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Key {
+    #[allow(missing_docs)]
+    Tab,
+    #[allow(missing_docs)]
+    Enter,
+    #[allow(missing_docs)]
+    Escape,
+    #[allow(missing_docs)]
+    Space,
+    #[allow(missing_docs)]
+    Backspace,
+    #[allow(missing_docs)]
+    Delete,
+    #[allow(missing_docs)]
+    Insert,
+    #[allow(missing_docs)]
+    Left,
+    #[allow(missing_docs)]
+    Right,
+    #[allow(missing_docs)]
+    Up,
+    #[allow(missing_docs)]
+    Down,
+    #[allow(missing_docs)]
+    Home,
+    #[allow(missing_docs)]
+    End,
+    #[allow(missing_docs)]
+    PageUp,
+    #[allow(missing_docs)]
+    PageDown,
+    /// Function key, from `F1`.
+    Function(u8),
+    /// Key of a character following the keyboard layout, in lower case. Used
+    /// for shortcuts, the typed text is given by [`Event::Char`].
+    Character(char),
+    /// Key unknown to the project, with the code given by the backend.
+    Other(u32),
+}
+
+/// Modifier keys held when a key is pressed or released.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Modifiers {
+    #[allow(missing_docs)]
+    pub shift: bool,
+    #[allow(missing_docs)]
+    pub control: bool,
+    #[allow(missing_docs)]
+    pub alt: bool,
+    /// The "Windows" key, or the "Command" key on macOS.
+    pub meta: bool,
+}
+
+impl Modifiers {
+    /// No modifier key is held.
+    pub const NONE: Modifiers = Modifiers {
+        shift: false,
+        control: false,
+        alt: false,
+        meta: false,
+    };
+
+    /// Only the shift key is held.
+    pub const SHIFT: Modifiers = Modifiers {
+        shift: true,
+        ..Self::NONE
+    };
+
+    /// Whether no modifier key other than shift is held.
+    pub fn is_shift_only(&self) -> bool {
+        !self.control && !self.alt && !self.meta
+    }
+}
+
+/// Keyboard event given by the backend.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// A key was pressed.
+    Down {
+        #[allow(missing_docs)]
+        key: Key,
+        #[allow(missing_docs)]
+        modifiers: Modifiers,
+        /// Whether the key is held and pressed again by the system.
+        repeat: bool,
+    },
+    /// A key was released.
+    Up {
+        #[allow(missing_docs)]
+        key: Key,
+        #[allow(missing_docs)]
+        modifiers: Modifiers,
+    },
+    /// A character was typed, following the keyboard layout and the modifier
+    /// keys.
+    Char {
+        #[allow(missing_docs)]
+        character: char,
+    },
+    /// The text being composed with an input method (IME) changed. An empty
+    /// text ends the composition.
+    Composition {
+        #[allow(missing_docs)]
+        text: String,
+        /// Position of the cursor in the composed text, in characters.
+        cursor: usize,
+    },
+    /// The composition with an input method (IME) ended, with the text to
+    /// insert.
+    Commit {
+        #[allow(missing_docs)]
+        text: String,
+    },
+}
+
+impl Event {
+    /// Creates the event of a key pressed without modifier key.
+    pub fn down(key: Key) -> Self {
+        Self::Down {
+            key,
+            modifiers: Modifiers::NONE,
+            repeat: false,
+        }
+    }
+
+    /// Creates the event of a key released without modifier key.
+    pub fn up(key: Key) -> Self {
+        Self::Up {
+            key,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    /// Whether the event is the press of a key, with modifier keys or not.
+    pub fn is_down(&self, key: Key) -> bool {
+        matches!(self, Self::Down { key: pressed, .. } if *pressed == key)
+    }
+}
+
+/// Function to call when a keyboard event is dispatched to a widget.
+type ListenerFn<T> = fn(widget: &mut T, event: &Event, propagation: &mut Propagation, theme: &Theme);
+
+/// Focusable controller listening to the keyboard events dispatched to a
+/// widget.
+///
+/// Wrapping a layout, it also listens to the events dispatched to the
+/// focused controllers in the layout, when they bubble up. It listens to them
+/// when they go down instead, with [`Self::with_capture()`].
+#[derive(Controller)]
+pub struct Listener<T: Widget> {
+    /// The wrapped widget.
+    pub widget: Box<T>,
+    /// The zone of the focusable widget.
+    pub zone: Zone,
+    /// The transformation of the zone of the focusable widget.
+    pub matrix: Matrix,
+    /// The interaction state of the widget.
+    pub state: State,
+    /// Function to call when an event is dispatched to the widget.
+    on_event: ListenerFn<T>,
+    /// Whether the events are listened in the capture phase rather than in
+    /// the bubble phase.
+    capture: bool,
+}
+
+impl<T: Widget> Listener<T> {
+    /// Creates a new keyboard listener.
+    pub fn new(widget: T, on_event: ListenerFn<T>) -> Self {
+        Self {
+            widget: Box::new(widget),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            on_event,
+            capture: false,
+        }
+    }
+
+    /// Listens to the events in the capture phase rather than in the bubble
+    /// phase, so before the controllers in the wrapped layout.
+    pub fn with_capture(mut self, capture: bool) -> Self {
+        self.capture = capture;
+        self
+    }
+}
+
+impl<T: Widget> Handler for Listener<T> {
+    /// Calls the function of the listener in the listened phases, unless the
+    /// widget is disabled.
+    fn on_key(&mut self, event: &Event, propagation: &mut Propagation, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let listened = match propagation.phase() {
+            Phase::Capture => self.capture,
+            Phase::Target => true,
+            Phase::Bubble => !self.capture,
+        };
+
+        if listened {
+            let on_event = self.on_event;
+            on_event(&mut self.widget, event, propagation, theme);
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,319 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to the [`Menu`]s opened in the overlay of the view,
+//! from a [`Select`] or as context menus.
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    themes::{State, Style, Theme},
+    widgets::{Child, Menu, Placement, Popup, Select},
+    DebugWidget,
+    Widget,
+    Zone
+};
+
+use super::{
+    keyboard::{self, Key},
+    pointer::{self, Button, Kind},
+    Controller,
+    ControllerFn,
+    Handler,
+};
+
+/// Default width of a context menu.
+pub const MENU_WIDTH: usize = 160;
+
+/// Focusable controller choosing an item of a [`Menu`] in a popup.
+///
+/// The item under the pointer is highlighted and chosen when clicked. The
+/// arrows move the highlight, enter and space choose the highlighted item.
+/// Choosing an item closes the popup, and tells the controller which opened
+/// it.
+#[derive(Controller)]
+pub struct Chooser {
+    /// The menu.
+    pub widget: Box<Menu>,
+    /// The zone of the menu.
+    pub zone: Zone,
+    /// The transformation of the zone of the menu.
+    pub matrix: Matrix,
+    /// The interaction state of the menu.
+    pub state: State,
+    /// The chosen item, taken by the view.
+    chosen: Option<usize>,
+}
+
+impl Chooser {
+    /// Creates a new chooser.
+    pub fn new(menu: Menu) -> Self {
+        Self {
+            widget: Box::new(menu),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            chosen: None,
+        }
+    }
+
+    /// Returns the item under the pointer.
+    fn item_at(&self, position: Point) -> Option<usize> {
+        let [x, y] = match self.matrix.inverse() {
+            Some(inverse) => inverse.apply([position[0] as f32, position[1] as f32]),
+            None => [position[0] as f32, position[1] as f32],
+        };
+
+        self.widget.item_at([x as isize - self.zone.x(), y as isize - self.zone.y()])
+    }
+}
+
+impl Handler for Chooser {
+    /// Highlights the item under the pointer, and chooses it when clicked.
+    fn on_pointer_default(&mut self, event: &pointer::Event, _: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let item = self.item_at(event.position);
+
+        match event.kind {
+            Kind::Enter | Kind::Move => self.widget.highlighted = item,
+            Kind::Click => self.chosen = item,
+            _ => (),
+        }
+    }
+
+    /// Moves the highlight with the arrows, and chooses the highlighted item
+    /// with enter or space.
+    fn on_key_default(&mut self, event: &keyboard::Event, _: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        match event {
+            keyboard::Event::Down { key: Key::Down, .. } => self.widget.highlight_next(true),
+            keyboard::Event::Down { key: Key::Up, .. } => self.widget.highlight_next(false),
+            keyboard::Event::Down { key: Key::Enter | Key::Space, .. } => self.chosen = self.widget.highlighted,
+            _ => (),
+        }
+    }
+
+    fn take_choice(&mut self) -> Option<usize> {
+        self.chosen.take()
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
+
+/// Focusable controller opening the options of a [`Select`] in a menu under
+/// it.
+///
+/// The menu is opened when the select is clicked, or with enter or space.
+/// The up and down arrows choose the previous or next option without opening
+/// the menu.
+#[derive(Controller)]
+pub struct Dropdown {
+    /// The select.
+    pub widget: Box<Select>,
+    /// The zone of the select.
+    pub zone: Zone,
+    /// The transformation of the zone of the select.
+    pub matrix: Matrix,
+    /// The interaction state of the select.
+    pub state: State,
+    /// Function to call when another option is chosen.
+    on_change: ControllerFn<Select>,
+    /// The popup of the menu to open, taken by the view.
+    popup: Option<Popup>,
+}
+
+impl Dropdown {
+    /// Creates a new dropdown.
+    pub fn new(select: Select, on_change: ControllerFn<Select>) -> Self {
+        Self {
+            widget: Box::new(select),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            on_change,
+            popup: None,
+        }
+    }
+
+    /// Opens the menu of the options under the select, as wide as it.
+    ///
+    /// Nothing happens when the select is disabled or already open.
+    pub fn open(&mut self) {
+        if self.state == State::Disabled || self.widget.open {
+            return;
+        }
+
+        let anchor = self.matrix.bounds(self.zone);
+        let menu = self.widget.menu();
+
+        self.popup = Some(Popup::new(anchor, Placement::Below, menu.size(anchor.width()), Chooser::new(menu)));
+        self.widget.open = true;
+    }
+
+    /// Chooses an option, then calls the function of the dropdown when it
+    /// changed.
+    pub fn choose(&mut self, option: usize, theme: &Theme) {
+        if option >= self.widget.options.len() || self.widget.selected == Some(option) {
+            return;
+        }
+
+        self.widget.selected = Some(option);
+
+        let on_change = self.on_change;
+        on_change(&mut self.widget, theme);
+    }
+}
+
+impl Handler for Dropdown {
+    /// Opens the menu when the select is clicked.
+    fn on_pointer_default(&mut self, event: &pointer::Event, _: &Theme) {
+        if event.kind == Kind::Click {
+            self.open();
+        }
+    }
+
+    /// Opens the menu with enter or space, chooses the previous or next
+    /// option with the arrows.
+    fn on_key_default(&mut self, event: &keyboard::Event, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let count = self.widget.options.len();
+
+        match event {
+            keyboard::Event::Down { key: Key::Enter | Key::Space, .. } => self.open(),
+            keyboard::Event::Down { key: Key::Down, .. } if count > 0 => {
+                let next = self.widget.selected.map_or(0, |selected| (selected + 1).min(count - 1));
+                self.choose(next, theme);
+            }
+            keyboard::Event::Down { key: Key::Up, .. } if count > 0 => {
+                let previous = self.widget.selected.map_or(0, |selected| selected.saturating_sub(1));
+                self.choose(previous, theme);
+            }
+            _ => (),
+        }
+    }
+
+    fn take_popup(&mut self) -> Option<Popup> {
+        self.popup.take()
+    }
+
+    /// Chooses the option chosen in the menu.
+    fn on_popup_close(&mut self, choice: Option<usize>, theme: &Theme) {
+        self.widget.open = false;
+
+        if let Some(choice) = choice {
+            self.choose(choice, theme);
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
+
+/// Function to call when an item of a context menu is chosen.
+type ChooseFn<T> = fn(widget: &mut T, item: usize, theme: &Theme);
+
+/// Controller opening a context menu at the pointer when a widget is pressed
+/// with the secondary button.
+#[derive(Controller)]
+pub struct Contextual<T: Widget> {
+    /// The widget with the context menu.
+    pub widget: Box<T>,
+    /// The zone of the widget.
+    pub zone: Zone,
+    /// The transformation of the zone of the widget.
+    pub matrix: Matrix,
+    /// The interaction state of the widget.
+    pub state: State,
+    /// The context menu.
+    pub menu: Menu,
+    /// Width of the context menu.
+    pub width: usize,
+    /// Function to call when an item of the context menu is chosen.
+    on_choose: ChooseFn<T>,
+    /// The popup of the menu to open, taken by the view.
+    popup: Option<Popup>,
+}
+
+impl<T: Widget> Contextual<T> {
+    /// Creates a new controller with a context menu.
+    pub fn new(widget: T, menu: Menu, on_choose: ChooseFn<T>) -> Self {
+        Self {
+            widget: Box::new(widget),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            menu,
+            width: MENU_WIDTH,
+            on_choose,
+            popup: None,
+        }
+    }
+
+    /// Opens the context menu at a point, under it or over it.
+    ///
+    /// Nothing happens when the widget is disabled.
+    pub fn open_at(&mut self, point: Point) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let anchor = Zone {
+            position: point,
+            size: [0, 0],
+        };
+
+        self.popup = Some(Popup::new(
+            anchor,
+            Placement::Below,
+            self.menu.size(self.width),
+            Chooser::new(self.menu.clone()),
+        ));
+    }
+}
+
+impl<T: Widget> Handler for Contextual<T> {
+    /// Opens the context menu when the widget is pressed with the secondary
+    /// button, unless a controller containing it prevented it.
+    fn on_pointer_default(&mut self, event: &pointer::Event, _: &Theme) {
+        if event.kind == Kind::Down && event.button == Some(Button::Secondary) {
+            self.open_at(event.position);
+        }
+    }
+
+    fn take_popup(&mut self) -> Option<Popup> {
+        self.popup.take()
+    }
+
+    /// Calls the function of the controller with the chosen item.
+    fn on_popup_close(&mut self, choice: Option<usize>, theme: &Theme) {
+        if let Some(choice) = choice {
+            let on_choose = self.on_choose;
+            on_choose(&mut self.widget, choice, theme);
+        }
+    }
+}
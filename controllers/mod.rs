@@ -5,28 +5,160 @@
 //! Controllers are wrappers for [`Widget`s](crate::Widget) having one or more 
 //! function/s to call when specific one or more event/s happen/s.
 
+pub mod dialog;
+pub mod focus;
+pub mod keyboard;
+pub mod menu;
+pub mod pointer;
+pub mod propagation;
+pub mod scroll;
+pub mod slide;
+pub mod table;
+pub mod tabs;
 pub mod tap;
+pub mod text;
+pub mod toggle;
+pub mod tree;
 
-use std::marker::PhantomData;
+use std::{collections::{HashMap, HashSet}, marker::PhantomData};
 
 use crate::{
-    graphics::draw::{Drawable, DrawableAt}, 
-    widgets::Layout,
+    graphics::{draw::{Drawable, DrawableAt}, Matrix, Point}, 
+    widgets::{unwrapped_mut, Layout, Popup},
     Widget,
-    Zone, themes::Theme,
+    Zone, themes::{State, Theme},
 };
 
+use propagation::Propagation;
+
 /// Function to call when something happen on a widget.
 type ControllerFn<T> = fn(widget: &mut T, theme: &Theme);
 
+/// Functions called when events are dispatched to a controller. Nothing 
+/// happens by default.
+/// 
+/// Every controller implements it, in order to be implemented by 
+/// [`#[derive(Controller)]`](haussmann_dev::Controller).
+pub trait Handler {
+    /// Called when a pointer event goes through the controller, in any 
+    /// [phase](propagation::Phase) of its propagation.
+    fn on_pointer(&mut self, _event: &pointer::Event, _propagation: &mut Propagation, _theme: &Theme) {}
+
+    /// Called on the target of a pointer event after its propagation, unless
+    /// its default action was prevented.
+    fn on_pointer_default(&mut self, _event: &pointer::Event, _theme: &Theme) {}
+
+    /// Called when a keyboard event goes through the controller, in any 
+    /// [phase](propagation::Phase) of its propagation. The keyboard events 
+    /// are dispatched to the focused controller.
+    fn on_key(&mut self, _event: &keyboard::Event, _propagation: &mut Propagation, _theme: &Theme) {}
+
+    /// Called on the focused controller after the propagation of a keyboard 
+    /// event, unless its default action was prevented.
+    fn on_key_default(&mut self, _event: &keyboard::Event, _theme: &Theme) {}
+
+    /// Called when the controller gains or loses the keyboard focus.
+    fn on_focus_change(&mut self, _focused: bool, _theme: &Theme) {}
+
+    /// Returns a popup to open in the overlay of the view, like a menu. The 
+    /// view takes it after dispatching each event.
+    fn take_popup(&mut self) -> Option<Popup> {
+        None
+    }
+
+    /// Returns the item chosen in the popup containing the controller, which
+    /// closes the popup. The view takes it after dispatching each event.
+    fn take_choice(&mut self) -> Option<usize> {
+        None
+    }
+
+    /// Called when the popup opened by the controller is closed, with the 
+    /// item chosen in it, or `None` when it was dismissed. Also called on the
+    /// controller drawn as a popup when it is closed.
+    fn on_popup_close(&mut self, _choice: Option<usize>, _theme: &Theme) {}
+
+    /// Whether the controller can have the keyboard focus. Disabled 
+    /// controllers never have it.
+    fn is_focusable(&self) -> bool {
+        false
+    }
+}
+
 /// Controllers wrap widgets in order to control what happen for them.
-pub trait Controller: Widget {
+pub trait Controller: Widget + Handler {
     /// Returns the controlled zone which is the the position and size of the 
     /// drawable corresponding to the controlled widget.
     fn zone(&self) -> &Zone; 
 
-    /// Updates the controlled zone.
-    fn update(&mut self, zone: Zone);
+    /// Returns the transformation of the drawable corresponding to the 
+    /// controlled widget.
+    fn matrix(&self) -> &Matrix;
+
+    /// Updates the controlled zone and its transformation.
+    fn update(&mut self, zone: Zone, matrix: Matrix);
+
+    /// Returns the controlled widget.
+    fn widget_mut(&mut self) -> &mut dyn Widget;
+
+    /// Changes the interaction state of the controlled widget. 
+    /// 
+    /// The current state is returned by [`Widget::state()`].
+    fn set_state(&mut self, state: State);
+
+    /// Updates the interaction state following a pointer at `pointer`, 
+    /// pressed or not. Returns whether the state changed.
+    /// 
+    /// A pointer over the controlled zone hovers or presses the widget. A 
+    /// focused widget stays focused when the pointer is not over it, and a 
+    /// disabled widget stays disabled.
+    fn track(&mut self, pointer: Point, pressed: bool) -> bool {
+        let over = tap::is_tapped(pointer, *self.zone(), *self.matrix());
+        self.hover(over, pressed)
+    }
+
+    /// Updates the interaction state following whether a pointer is over the 
+    /// controlled widget, pressed or not. Returns whether the state changed.
+    /// 
+    /// See [`Self::track()`].
+    fn hover(&mut self, over: bool, pressed: bool) -> bool {
+        let state = self.state();
+
+        if state == State::Disabled {
+            return false;
+        }
+
+        let next = match (over, pressed) {
+            (true, true) => State::Pressed,
+            (true, false) => State::Hovered,
+            (false, _) if matches!(state, State::Focused | State::FocusVisible) => state,
+            (false, _) => State::Normal,
+        };
+
+        self.set_state(next);
+        next != state
+    }
+
+    /// Updates the interaction state following whether the controller has 
+    /// the keyboard focus, and whether the focus is visible. Returns whether
+    /// the state changed.
+    /// 
+    /// A hovered or pressed widget stays so, and a disabled widget stays 
+    /// disabled.
+    fn focus(&mut self, focused: bool, visible: bool) -> bool {
+        let state = self.state();
+
+        let next = match state {
+            State::Normal | State::Focused | State::FocusVisible if focused => match visible {
+                true => State::FocusVisible,
+                false => State::Focused,
+            },
+            State::Focused | State::FocusVisible => State::Normal,
+            _ => state,
+        };
+
+        self.set_state(next);
+        next != state
+    }
 }
 
 /// Browses widgets in order to find controllers. When they are found, calls the 
@@ -34,51 +166,318 @@ pub trait Controller: Widget {
 /// 
 /// All the zones of the controllers are updated following the drawable they 
 /// correspond to.
-pub(crate) struct ControllersBrowser<T: Controller, C: Fn(&mut T)> {
-    /// Helps get the right drawable to update the current found controller.
-    i: usize,
+pub(crate) struct ControllersBrowser<'a, T: Controller, C: Fn(&mut T, &Theme)> {
     drawables: Vec<Drawable>,
+    /// The theme given to the callback.
+    theme: &'a Theme,
     /// The function to call when a controller is found.
     callback: C,
     /// Rust does not allow not using generic types.
     phantom: PhantomData<T>
 }
 
-impl<T: Controller, C: Fn(&mut T)> ControllersBrowser<T, C> {
+impl<'a, T: Controller, C: Fn(&mut T, &Theme)> ControllersBrowser<'a, T, C> {
     /// Creates a new browser for controllers.
-    pub(crate) fn new(drawables: Vec<Drawable>, callback: C) -> Self {
+    pub(crate) fn new(drawables: Vec<Drawable>, theme: &'a Theme, callback: C) -> Self {
         Self {
-            i: 1,
             drawables,
+            theme,
             callback,
             phantom: PhantomData,
         }
     }
 }
 
-impl<T: Controller + 'static, C: Fn(&mut T)> ControllersBrowser<T, C> {
-    /// Calls the callback with every controller found in the layout and 
-    /// sub-layouts.
-    pub(crate) fn browse_layout(&mut self, layout: &mut Layout) {
-        for widget in &mut layout.widgets {
-            // Encounters a layout, calls itself but with the retrieved layout 
-            // as parameter.
-            if let Some(layout) = widget.as_any_mut().downcast_mut::<Layout>() {
-                self.i += 1;
-                self.browse_layout(layout);
-                continue;
+impl<'a, T: Controller + 'static, C: Fn(&mut T, &Theme)> ControllersBrowser<'a, T, C> {
+    /// Calls the callback with every controller of type `T` found in the 
+    /// layout and sub-layouts, then in the popups of the overlay.
+    pub(crate) fn browse_layout(&mut self, layout: &mut Layout, overlay: &mut [Popup]) {
+        let theme = self.theme;
+        let callback = &self.callback;
+
+        browse_controllers(layout, overlay, &self.drawables, &mut |controller, _| {
+            // Calls the callback giving the controller as mutable reference.
+            if let Some(controller) = controller.as_any_mut().downcast_mut::<T>() {
+                callback(controller, theme);
+            }
+        });
+    }
+}
+
+/// Visits every controller found in the layout and sub-layouts, then in the 
+/// popups of the overlay, with the identifier of the drawables built for it.
+/// 
+/// The zones of the controllers are updated following the drawable they 
+/// correspond to, before being visited.
+pub(crate) fn browse_controllers(
+    layout: &mut Layout, 
+    overlay: &mut [Popup],
+    drawables: &[Drawable], 
+    visit: &mut dyn FnMut(&mut dyn Controller, usize),
+) {
+    walk_controllers(layout, overlay, drawables, &mut |controller, id, step| {
+        if step == Step::Enter {
+            visit(controller, id);
+        }
+    });
+}
+
+/// Whether a controller can take the keyboard focus, not being disabled.
+pub(crate) fn is_focusable(controller: &dyn Controller) -> bool {
+    controller.is_focusable() && controller.state() != State::Disabled
+}
+
+/// Controllers found in one walk through the widgets, with what dispatching 
+/// the events needs to know about the drawables built for them.
+/// 
+/// Created once for each input given to the view, so the identifiers, the 
+/// popups and the parents of the controllers are not searched for again in 
+/// the widgets and the drawables for each question.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Snapshot {
+    /// Identifiers of the controllers, to know quickly if a drawable is 
+    /// built for a controller.
+    controllers: HashSet<usize>,
+    /// Identifiers of the focusable controllers, in the order of the walk.
+    focusable: Vec<usize>,
+    /// Index of the first drawable built for each identifier.
+    first: HashMap<usize, usize>,
+    /// Index of the widget of each drawable in the layout of its parent.
+    children: Vec<usize>,
+    /// Index of the popup in which each drawable is built, `None` for the 
+    /// view's layout.
+    popups: Vec<Option<usize>>,
+}
+
+impl Snapshot {
+    /// Walks once through the controllers of the layout and of the overlay, 
+    /// updating their zones, then goes once through the drawables.
+    pub(crate) fn new(layout: &mut Layout, overlay: &mut [Popup], drawables: &[Drawable]) -> Self {
+        let mut controllers = HashSet::new();
+        let mut focusable = vec![];
+
+        browse_controllers(layout, overlay, drawables, &mut |controller, id| {
+            controllers.insert(id);
+
+            if is_focusable(controller) {
+                focusable.push(id);
+            }
+        });
+
+        let mut first = HashMap::new();
+        let mut children = vec![0; drawables.len()];
+        let mut counts = vec![0; drawables.len()];
+        let mut popups = vec![None; drawables.len()];
+        let mut roots = 0;
+
+        // A parent is built before its children. The drawables of each popup
+        // are in a drawable without parent, built after the view's layout.
+        for (i, drawable) in drawables.iter().enumerate() {
+            first.entry(drawable.group_id as usize).or_insert(i);
+
+            match drawable.parent {
+                Some(parent) => {
+                    children[i] = counts[parent];
+                    counts[parent] += 1;
+                    popups[i] = popups[parent];
+                }
+                None if i == 0 => (),
+                None => {
+                    popups[i] = Some(roots);
+                    roots += 1;
+                }
+            }
+        }
+
+        Self {
+            controllers,
+            focusable,
+            first,
+            children,
+            popups,
+        }
+    }
+
+    /// Returns the identifiers of the focusable controllers, which are not 
+    /// disabled, in the order of the walk.
+    pub(crate) fn focusable(&self) -> &[usize] {
+        &self.focusable
+    }
+
+    /// Whether the identifier is the one of a controller.
+    pub(crate) fn is_controller(&self, id: usize) -> bool {
+        self.controllers.contains(&id)
+    }
+
+    /// Returns the index of the first drawable built for an identifier.
+    pub(crate) fn first(&self, id: usize) -> Option<usize> {
+        self.first.get(&id).copied()
+    }
+
+    /// Returns the index of the widget of a drawable in the layout of its 
+    /// parent, each widget of a layout building one drawable in the layout.
+    pub(crate) fn child(&self, index: usize) -> usize {
+        self.children[index]
+    }
+
+    /// Returns the index of the popup in which a drawable is built, `None` 
+    /// when it is built in the view's layout.
+    pub(crate) fn popup_of_drawable(&self, index: usize) -> Option<usize> {
+        self.popups[index]
+    }
+
+    /// Returns the index of the popup in which the drawables of a controller
+    /// are built, `None` when they are built in the view's layout.
+    pub(crate) fn popup_of(&self, id: usize) -> Option<usize> {
+        self.popups[self.first(id)?]
+    }
+}
+
+/// Step of a walk through the controllers.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Step {
+    /// The controller is entered, before the controllers it contains.
+    Enter,
+    /// The controller is left, after the controllers it contains.
+    Leave,
+}
+
+/// Walks through the controllers found in the layout and sub-layouts, 
+/// including the controllers in the layouts wrapped by controllers, then 
+/// through the controllers of the popups of the overlay. Each controller is 
+/// visited when entered and when left, with the identifier of the drawables 
+/// built for it.
+/// 
+/// The zones of the controllers are updated following the drawable they 
+/// correspond to, before being entered.
+pub(crate) fn walk_controllers(
+    layout: &mut Layout, 
+    overlay: &mut [Popup],
+    drawables: &[Drawable], 
+    visit: &mut dyn FnMut(&mut dyn Controller, usize, Step),
+) {
+    // Identifiers start after the one of the view's layout.
+    let mut i = 1;
+    walk_layout(layout, Some(drawables), &mut i, visit);
+
+    // The popups are built after the view's layout, like its widgets, a 
+    // modal popup after its barrier.
+    for popup in overlay {
+        if popup.modal {
+            i += 1;
+        }
+
+        walk_widget(&mut popup.widget, Some(drawables), &mut i, visit);
+    }
+}
+
+/// Closure visiting a controller with its identifier and the index of the 
+/// popup containing it.
+type PopupVisit<'a> = dyn FnMut(&mut dyn Controller, usize, Option<usize>) + 'a;
+
+/// Visits every controller like [`browse_controllers()`], but before their 
+/// drawables are built, so their zones are not updated. Each controller is 
+/// visited with the index of the popup containing it, `None` when it is in 
+/// the view's layout.
+pub(crate) fn browse_unbuilt(
+    layout: &mut Layout, 
+    overlay: &mut [Popup],
+    visit: &mut PopupVisit,
+) {
+    let mut i = 1;
+    walk_layout(layout, None, &mut i, &mut |controller, id, step| {
+        if step == Step::Enter {
+            visit(controller, id, None);
+        }
+    });
+
+    for (index, popup) in overlay.iter_mut().enumerate() {
+        if popup.modal {
+            i += 1;
+        }
+
+        walk_widget(&mut popup.widget, None, &mut i, &mut |controller, id, step| {
+            if step == Step::Enter {
+                visit(controller, id, Some(index));
             }
+        });
+    }
+}
 
-            // Encounters a controller, calls the callback.
-            if let Some(controller) = widget.as_any_mut().downcast_mut::<T>() {
+/// Walks through the controllers of a layout, `i` being the identifier of 
+/// the drawables built for the current widget. Without drawables, every 
+/// controller is visited without updating its zone.
+fn walk_layout(
+    layout: &mut Layout, 
+    drawables: Option<&[Drawable]>, 
+    i: &mut usize, 
+    visit: &mut dyn FnMut(&mut dyn Controller, usize, Step),
+) {
+    for widget in &mut layout.widgets {
+        walk_widget(widget, drawables, i, visit);
+    }
+}
+
+/// Walks through the controllers of a widget of a layout, `i` being the 
+/// identifier of the drawables built for it. The next widget takes the 
+/// identifier following the ones [counted](Widget::id_count()) by the widget.
+fn walk_widget(
+    widget: &mut Box<dyn Widget>, 
+    drawables: Option<&[Drawable]>, 
+    i: &mut usize, 
+    visit: &mut dyn FnMut(&mut dyn Controller, usize, Step),
+) {
+    let id = *i;
+    let count = widget.id_count();
+
+    // Encounters a controller, even wrapped, visits it.
+    if let Some(controller) = widget.as_controller_mut() {
+        let found = match drawables.map(|drawables| drawables.at(id)) {
+            Some(Some(drawable)) => {
                 // Update the controller's zone.
-                controller.update(self.drawables.at(self.i).unwrap().zone);
-                // Calls the callback giving the controller as mutable reference.
-                let callback = &self.callback;
-                callback(controller);
+                controller.update(drawable.zone, drawable.transform);
+                visit(controller, id, Step::Enter);
+                true
+            }
+            Some(None) => false,
+            None => {
+                visit(controller, id, Step::Enter);
+                true
             }
+        };
+
+        // The controlled widget is walked through like it is built.
+        walk_children(controller.widget_mut(), drawables, id, visit);
 
-            self.i += 1;
+        if found {
+            visit(controller, id, Step::Leave);
         }
+    } else {
+        walk_children(widget.as_mut(), drawables, id, visit);
     }
+
+    *i = id + count;
+}
+
+/// Walks through the controllers contained by a widget, `id` being the 
+/// identifier of the drawables built for it: the widgets of a layout, even 
+/// wrapped, or the [children](Widget::children_mut()) of the widget.
+fn walk_children(
+    widget: &mut dyn Widget, 
+    drawables: Option<&[Drawable]>, 
+    id: usize, 
+    visit: &mut dyn FnMut(&mut dyn Controller, usize, Step),
+) {
+    // Walks through the wrapped widget, like it is built.
+    let widget = unwrapped_mut(widget);
+
+    // Encounters a layout, walks through the retrieved layout.
+    if let Some(layout) = widget.as_any_mut().downcast_mut::<Layout>() {
+        walk_layout(layout, drawables, &mut (id + 1), visit);
+        return;
+    }
+
+    widget.children_mut(&mut |offset, child| {
+        walk_widget(child, drawables, &mut (id + offset), visit);
+    });
 }
@@ -0,0 +1,484 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to pointer (mouse, touch, pen) events.
+//!
+//! The backend gives the raw [`Input`]s of the pointer to the
+//! [`View`](crate::widgets::View), which turns them into [`Event`]s thanks to
+//! its [`Dispatcher`] and routes them to the controllers under the pointer.
+
+use std::time::Duration;
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    themes::{State, Style, Theme},
+    widgets::Child,
+    DebugWidget,
+    Widget,
+    Zone
+};
+
+use super::{
+    propagation::{Phase, Propagation},
+    Controller,
+    Handler,
+};
+
+/// Button of the pointer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Button {
+    /// Left mouse button, or touch.
+    Primary,
+    /// Right mouse button.
+    Secondary,
+    /// Middle mouse button.
+    Middle,
+}
+
+/// Raw input of the pointer given by the backend, at a position in the
+/// coordinates of the view's zone.
+///
+/// The time is the duration since any moment chosen by the backend (its
+/// start for example), it only has to always be the same moment.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Input {
+    /// The pointer moved.
+    Move {
+        #[allow(missing_docs)]
+        position: Point,
+        #[allow(missing_docs)]
+        time: Duration,
+    },
+    /// A button was pressed.
+    Down {
+        #[allow(missing_docs)]
+        position: Point,
+        #[allow(missing_docs)]
+        button: Button,
+        #[allow(missing_docs)]
+        time: Duration,
+    },
+    /// A button was released.
+    Up {
+        #[allow(missing_docs)]
+        position: Point,
+        #[allow(missing_docs)]
+        button: Button,
+        #[allow(missing_docs)]
+        time: Duration,
+    },
+    /// The wheel was scrolled.
+    Wheel {
+        #[allow(missing_docs)]
+        position: Point,
+        /// Scrolled distance on the X and Y axes.
+        delta: [f32; 2],
+        #[allow(missing_docs)]
+        time: Duration,
+    },
+    /// The pointer left the view.
+    Leave {
+        #[allow(missing_docs)]
+        time: Duration,
+    },
+    /// Time passed without input. Must be given regularly (at each frame for
+    /// example) to detect long presses.
+    Tick {
+        #[allow(missing_docs)]
+        time: Duration,
+    },
+}
+
+impl Input {
+    /// Returns the position of the pointer, if the input has one.
+    pub fn position(&self) -> Option<Point> {
+        match self {
+            Self::Move { position, .. }
+            | Self::Down { position, .. }
+            | Self::Up { position, .. }
+            | Self::Wheel { position, .. } => Some(*position),
+            Self::Leave { .. } | Self::Tick { .. } => None,
+        }
+    }
+}
+
+/// Kind of a pointer [`Event`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Kind {
+    /// The pointer moved over the widget.
+    Move,
+    /// The pointer entered the widget.
+    Enter,
+    /// The pointer left the widget.
+    Leave,
+    /// A button was pressed over the widget.
+    Down,
+    /// A button was released over the widget.
+    Up,
+    /// A button was pressed and released over the widget, without dragging
+    /// nor long pressing.
+    Click,
+    /// Second click on the widget in a short delay. Follows the second
+    /// [`Kind::Click`].
+    DoubleClick,
+    /// A button was pressed over the widget for a long time without moving.
+    LongPress,
+    /// The pointer moved while pressing the widget.
+    DragStart,
+    /// The pointer moved while dragging the widget.
+    DragMove {
+        /// Moved distance since the last drag event.
+        delta: [isize; 2],
+    },
+    /// The button dragging the widget was released.
+    DragEnd,
+    /// The wheel was scrolled over the widget.
+    Wheel {
+        /// Scrolled distance on the X and Y axes.
+        delta: [f32; 2],
+    },
+}
+
+impl Kind {
+    /// Whether the event goes back up through the controllers containing its
+    /// target. The pointer entering and leaving a widget does not concern the
+    /// widgets containing it.
+    pub fn bubbles(&self) -> bool {
+        !matches!(self, Self::Enter | Self::Leave)
+    }
+}
+
+/// Pointer event dispatched to a controller.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Event {
+    #[allow(missing_docs)]
+    pub kind: Kind,
+    /// Position of the pointer.
+    pub position: Point,
+    /// The button concerned by the event, if any.
+    pub button: Option<Button>,
+    /// Time of the input which created the event.
+    pub time: Duration,
+}
+
+/// A button pressing the view.
+#[derive(Debug, Copy, Clone)]
+struct Press {
+    /// The pressed controller.
+    target: Option<usize>,
+    button: Button,
+    /// Where the button was pressed.
+    origin: Point,
+    /// When the button was pressed.
+    time: Duration,
+    /// Last position of the pointer while pressing.
+    position: Point,
+    dragging: bool,
+    long_pressed: bool,
+}
+
+/// Turns the raw inputs into events for the controllers.
+///
+/// The controllers are identified by the identifier of the drawables built
+/// for them. The dispatcher remembers the hovered and pressed controllers
+/// between the inputs.
+#[derive(Debug, Clone)]
+pub struct Dispatcher {
+    /// Maximum delay between two clicks of a double click.
+    pub double_click_delay: Duration,
+    /// Minimum delay for a press to be a long press.
+    pub long_press_delay: Duration,
+    /// Minimum distance in pixels for a pressing pointer to start a drag.
+    pub drag_threshold: usize,
+    /// The last position of the pointer.
+    position: Point,
+    /// The controller under the pointer.
+    hovered: Option<usize>,
+    /// The current press.
+    press: Option<Press>,
+    /// The last clicked controller and when it was clicked.
+    last_click: Option<(usize, Duration)>,
+}
+
+impl Default for Dispatcher {
+    fn default() -> Self {
+        Self {
+            double_click_delay: Duration::from_millis(500),
+            long_press_delay: Duration::from_millis(500),
+            drag_threshold: 4,
+            position: [0, 0],
+            hovered: None,
+            press: None,
+            last_click: None,
+        }
+    }
+}
+
+impl Dispatcher {
+    /// Returns the controller under the pointer.
+    pub fn hovered(&self) -> Option<usize> {
+        self.hovered
+    }
+
+    /// Whether a button is pressing the view.
+    pub fn is_pressed(&self) -> bool {
+        self.press.is_some()
+    }
+
+    /// Returns the events created by an input, with the identifiers of the
+    /// controllers to dispatch them to.
+    ///
+    /// `target` is the controller under the pointer, found by hit-testing the
+    /// position of the input.
+    pub fn dispatch(&mut self, input: Input, target: Option<usize>) -> Vec<(usize, Event)> {
+        let mut events = vec![];
+
+        if let Some(position) = input.position() {
+            self.position = position;
+        }
+
+        match input {
+            Input::Move { position, time } => {
+                self.hover(target, position, time, &mut events);
+
+                if let Some(target) = target {
+                    events.push((target, event(Kind::Move, position, None, time)));
+                }
+
+                self.drag(position, time, &mut events);
+            }
+            Input::Down { position, button, time } => {
+                self.hover(target, position, time, &mut events);
+
+                if let Some(target) = target {
+                    events.push((target, event(Kind::Down, position, Some(button), time)));
+                }
+
+                self.press = Some(Press {
+                    target,
+                    button,
+                    origin: position,
+                    time,
+                    position,
+                    dragging: false,
+                    long_pressed: false,
+                });
+            }
+            Input::Up { position, button, time } => {
+                self.hover(target, position, time, &mut events);
+
+                if let Some(target) = target {
+                    events.push((target, event(Kind::Up, position, Some(button), time)));
+                }
+
+                self.release(target, position, button, time, &mut events);
+            }
+            Input::Wheel { position, delta, time } => {
+                self.hover(target, position, time, &mut events);
+
+                if let Some(target) = target {
+                    events.push((target, event(Kind::Wheel { delta }, position, None, time)));
+                }
+            }
+            Input::Leave { time } => self.hover(None, self.position, time, &mut events),
+            Input::Tick { time } => self.long_press(time, &mut events),
+        }
+
+        events
+    }
+
+    /// Changes the hovered controller, the previous one is left and the new
+    /// one is entered.
+    fn hover(&mut self, target: Option<usize>, position: Point, time: Duration, events: &mut Vec<(usize, Event)>) {
+        if target == self.hovered {
+            return;
+        }
+
+        if let Some(hovered) = self.hovered {
+            events.push((hovered, event(Kind::Leave, position, None, time)));
+        }
+
+        if let Some(target) = target {
+            events.push((target, event(Kind::Enter, position, None, time)));
+        }
+
+        self.hovered = target;
+    }
+
+    /// Drags the pressed controller when the pointer moved far enough.
+    fn drag(&mut self, position: Point, time: Duration, events: &mut Vec<(usize, Event)>) {
+        let threshold = self.drag_threshold as isize;
+
+        let press = match &mut self.press {
+            Some(press) => press,
+            None => return,
+        };
+
+        if !press.dragging {
+            let moved = (position[0] - press.origin[0]).abs().max((position[1] - press.origin[1]).abs());
+
+            if moved < threshold || press.long_pressed {
+                return;
+            }
+
+            press.dragging = true;
+
+            if let Some(target) = press.target {
+                events.push((target, event(Kind::DragStart, press.origin, Some(press.button), time)));
+            }
+        }
+
+        let delta = [position[0] - press.position[0], position[1] - press.position[1]];
+        press.position = position;
+
+        if let Some(target) = press.target {
+            events.push((target, event(Kind::DragMove { delta }, position, Some(press.button), time)));
+        }
+    }
+
+    /// Ends the press of `button`, clicking or ending the drag.
+    fn release(
+        &mut self,
+        target: Option<usize>,
+        position: Point,
+        button: Button,
+        time: Duration,
+        events: &mut Vec<(usize, Event)>,
+    ) {
+        let press = match self.press {
+            Some(press) if press.button == button => press,
+            _ => return,
+        };
+
+        self.press = None;
+
+        if press.dragging {
+            if let Some(pressed) = press.target {
+                events.push((pressed, event(Kind::DragEnd, position, Some(button), time)));
+            }
+
+            return;
+        }
+
+        // Released over another controller, or after a long press.
+        let target = match target {
+            Some(target) if press.target == Some(target) && !press.long_pressed => target,
+            _ => return,
+        };
+
+        events.push((target, event(Kind::Click, position, Some(button), time)));
+
+        match self.last_click {
+            Some((clicked, at)) if clicked == target && time.saturating_sub(at) <= self.double_click_delay => {
+                events.push((target, event(Kind::DoubleClick, position, Some(button), time)));
+                self.last_click = None;
+            }
+            _ => self.last_click = Some((target, time)),
+        }
+    }
+
+    /// Long presses the pressed controller when it was pressed long enough.
+    fn long_press(&mut self, time: Duration, events: &mut Vec<(usize, Event)>) {
+        let delay = self.long_press_delay;
+
+        let press = match &mut self.press {
+            Some(press) if !press.dragging && !press.long_pressed => press,
+            _ => return,
+        };
+
+        if time.saturating_sub(press.time) < delay {
+            return;
+        }
+
+        press.long_pressed = true;
+
+        if let Some(target) = press.target {
+            events.push((target, event(Kind::LongPress, press.position, Some(press.button), time)));
+        }
+    }
+}
+
+/// Creates an event.
+fn event(kind: Kind, position: Point, button: Option<Button>, time: Duration) -> Event {
+    Event {
+        kind,
+        position,
+        button,
+        time,
+    }
+}
+
+/// Function to call when a pointer event is dispatched to a widget.
+type ListenerFn<T> = fn(widget: &mut T, event: &Event, propagation: &mut Propagation, theme: &Theme);
+
+/// Controller listening to the pointer events dispatched to a widget.
+/// 
+/// Wrapping a layout, it also listens to the events dispatched to the 
+/// controllers in the layout, when they bubble up. It listens to them when 
+/// they go down instead, with [`Self::with_capture()`].
+#[derive(Controller)]
+pub struct Listener<T: Widget> {
+    /// The wrapped widget.
+    pub widget: Box<T>,
+    /// The zone where the events are listened.
+    pub zone: Zone,
+    /// The transformation of the zone where the events are listened.
+    pub matrix: Matrix,
+    /// The interaction state of the widget.
+    pub state: State,
+    /// Function to call when an event is dispatched to the widget.
+    on_event: ListenerFn<T>,
+    /// Whether the events are listened in the capture phase rather than in
+    /// the bubble phase.
+    capture: bool,
+}
+
+impl<T: Widget> Listener<T> {
+    /// Creates a new pointer listener.
+    pub fn new(widget: T, on_event: ListenerFn<T>) -> Self {
+        Self {
+            widget: Box::new(widget),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            on_event,
+            capture: false,
+        }
+    }
+
+    /// Listens to the events in the capture phase rather than in the bubble 
+    /// phase, so before the controllers in the wrapped layout.
+    pub fn with_capture(mut self, capture: bool) -> Self {
+        self.capture = capture;
+        self
+    }
+}
+
+impl<T: Widget> Handler for Listener<T> {
+    /// Calls the function of the listener in the listened phases, unless the
+    /// widget is disabled.
+    fn on_pointer(&mut self, event: &Event, propagation: &mut Propagation, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let listened = match propagation.phase() {
+            Phase::Capture => self.capture,
+            Phase::Target => true,
+            Phase::Bubble => !self.capture,
+        };
+
+        if listened {
+            let on_event = self.on_event;
+            on_event(&mut self.widget, event, propagation, theme);
+        }
+    }
+}
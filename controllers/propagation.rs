@@ -0,0 +1,196 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to the propagation of events through the controllers.
+//!
+//! An event dispatched to a controller (the target) first goes down from the
+//! outermost controller containing the target to the target (capture phase),
+//! then is handled by the target (target phase), then goes back up to the
+//! outermost controller (bubble phase). A controller containing the target is
+//! a controller wrapping a layout in which the target is.
+
+use crate::{graphics::draw::Drawable, widgets::{Layout, Popup}};
+
+use super::{walk_controllers, Controller, Snapshot, Step};
+
+/// Phase of the propagation of an event.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Phase {
+    /// The event goes down to the target, through the controllers containing
+    /// it.
+    Capture,
+    /// The event is handled by the target.
+    Target,
+    /// The event goes up from the target, through the controllers containing
+    /// it.
+    Bubble,
+}
+
+/// Propagation of an event, given to the controllers handling the event.
+#[derive(Debug, Clone)]
+pub struct Propagation {
+    /// The current phase.
+    phase: Phase,
+    /// Identifiers of the controllers on the way of the event, from the
+    /// outermost one to the target.
+    path: Vec<usize>,
+    /// Indexes of the widgets containing the target in the layouts of the
+    /// controllers on the way of the event.
+    children: Vec<Option<usize>>,
+    /// Position of the current controller in the path.
+    current: usize,
+    /// Whether the event bubbles after the target phase.
+    bubbles: bool,
+    /// Whether a controller stopped the propagation.
+    stopped: bool,
+    /// Whether a controller prevented the default action.
+    default_prevented: bool,
+}
+
+impl Propagation {
+    /// Creates the propagation of an event dispatched to the controller
+    /// identified by `target`, among the controllers of a snapshot.
+    pub(crate) fn new(drawables: &[Drawable], snapshot: &Snapshot, target: usize, bubbles: bool) -> Self {
+        let (path, children) = path(drawables, snapshot, target);
+
+        Self {
+            phase: Phase::Capture,
+            current: 0,
+            path,
+            children,
+            bubbles,
+            stopped: false,
+            default_prevented: false,
+        }
+    }
+
+    /// Returns the current phase.
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Returns the identifier of the controller the event was dispatched to.
+    pub fn target(&self) -> usize {
+        *self.path.last().unwrap()
+    }
+
+    /// Returns the identifier of the controller handling the event.
+    pub fn current(&self) -> usize {
+        self.path[self.current]
+    }
+
+    /// Returns the identifiers of the controllers on the way of the event,
+    /// from the outermost one to the target.
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// Returns the index of the widget containing the target in the layout
+    /// wrapped by the controller handling the event.
+    ///
+    /// For a controller wrapping a list of rows, it is the index of the row
+    /// on which the event happened. `None` during the target phase.
+    pub fn child(&self) -> Option<usize> {
+        self.children[self.current]
+    }
+
+    /// Stops the propagation, the next controllers on the way of the event
+    /// will not handle it.
+    pub fn stop_propagation(&mut self) {
+        self.stopped = true;
+    }
+
+    /// Whether the propagation was stopped.
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// Prevents the default action of the event, done by the target after
+    /// the propagation.
+    ///
+    /// See [`Handler::on_pointer_default()`](super::Handler::on_pointer_default).
+    pub fn prevent_default(&mut self) {
+        self.default_prevented = true;
+    }
+
+    /// Whether the default action of the event was prevented.
+    pub fn is_default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+
+    /// Whether the event bubbles after the target phase.
+    pub fn bubbles(&self) -> bool {
+        self.bubbles
+    }
+}
+
+/// Propagates an event through the controllers of the layout and of the 
+/// overlay. `handle` is called with each controller on the way of the event,
+/// in the order of the phases, until the propagation is stopped.
+pub(crate) fn propagate(
+    layout: &mut Layout,
+    overlay: &mut [Popup],
+    drawables: &[Drawable],
+    propagation: &mut Propagation,
+    handle: &mut dyn FnMut(&mut dyn Controller, &mut Propagation),
+) {
+    walk_controllers(layout, overlay, drawables, &mut |controller, id, step| {
+        if propagation.stopped {
+            return;
+        }
+
+        let current = match propagation.path.iter().position(|&i| i == id) {
+            Some(current) => current,
+            None => return,
+        };
+
+        let target = current == propagation.path.len() - 1;
+
+        // A controller is entered before the controllers it contains and is
+        // left after them.
+        propagation.phase = match step {
+            Step::Enter if target => Phase::Target,
+            Step::Enter => Phase::Capture,
+            Step::Leave if !target && propagation.bubbles => Phase::Bubble,
+            Step::Leave => return,
+        };
+
+        propagation.current = current;
+        handle(controller, propagation);
+    });
+}
+
+/// Returns the identifiers of the controllers from the outermost one
+/// containing the target to the target, with the indexes of the widgets
+/// containing the target in their layouts.
+fn path(drawables: &[Drawable], snapshot: &Snapshot, target: usize) -> (Vec<usize>, Vec<Option<usize>>) {
+    let mut path = vec![target];
+    let mut children = vec![None];
+
+    // The first drawable built for the target.
+    let mut index = snapshot.first(target);
+
+    while let Some(i) = index {
+        let parent = match drawables[i].parent {
+            Some(parent) => parent,
+            None => break,
+        };
+
+        let id = drawables[parent].group_id as usize;
+
+        // The drawables built for a controller can be in a layout built for
+        // the same controller.
+        if id != *path.last().unwrap() && snapshot.is_controller(id) {
+            path.push(id);
+            children.push(Some(snapshot.child(i)));
+        }
+
+        index = Some(parent);
+    }
+
+    path.reverse();
+    children.reverse();
+
+    (path, children)
+}
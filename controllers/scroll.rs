@@ -0,0 +1,122 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to scrolling the rows of a [`ListView`].
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    themes::{State, Style, Theme},
+    widgets::{Child, ListView},
+    DebugWidget,
+    Widget,
+    Zone
+};
+
+use super::{
+    keyboard::{self, Key},
+    pointer::{self, Kind},
+    propagation::{Phase, Propagation},
+    Controller,
+    Handler,
+};
+
+/// Scrolled distance for a step of the wheel or an arrow, in pixels.
+pub const STEP: usize = 40;
+
+/// Focusable controller scrolling a [`ListView`].
+///
+/// The list is scrolled by the wheel, a positive vertical distance going up,
+/// and by dragging its rows, even when the pointer is over a controller of a
+/// row. The arrows scroll it by a step, page up and page
+/// down by its height, home and end go to its first and last rows.
+#[derive(Controller)]
+pub struct Scroller {
+    /// The list.
+    pub widget: Box<ListView>,
+    /// The zone of the list.
+    pub zone: Zone,
+    /// The transformation of the zone of the list.
+    pub matrix: Matrix,
+    /// The interaction state of the list.
+    pub state: State,
+}
+
+impl Scroller {
+    /// Creates a new scroller.
+    pub fn new(list: ListView) -> Self {
+        Self {
+            widget: Box::new(list),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+        }
+    }
+
+    /// Scrolls the list by `delta` pixels, down when positive. Returns whether
+    /// it was scrolled.
+    ///
+    /// Nothing happens when the list is disabled.
+    pub fn scroll_by(&mut self, delta: isize) -> bool {
+        if self.state == State::Disabled {
+            return false;
+        }
+
+        self.widget.scroll_by(delta, self.zone.height())
+    }
+
+    /// Scrolls the list with the wheel, or by dragging it.
+    fn scroll_with(&mut self, event: &pointer::Event) {
+        match event.kind {
+            Kind::Wheel { delta } => {
+                self.scroll_by((-delta[1] * STEP as f32) as isize);
+            }
+            Kind::DragMove { delta } => {
+                self.scroll_by(-delta[1]);
+            }
+            _ => (),
+        }
+    }
+}
+
+impl Handler for Scroller {
+    /// Scrolls the list with the wheel, or by dragging it, when the event 
+    /// goes up from a controller of a row.
+    fn on_pointer(&mut self, event: &pointer::Event, propagation: &mut Propagation, _: &Theme) {
+        if propagation.phase() == Phase::Bubble {
+            self.scroll_with(event);
+        }
+    }
+
+    /// Scrolls the list with the wheel, or by dragging it.
+    fn on_pointer_default(&mut self, event: &pointer::Event, _: &Theme) {
+        self.scroll_with(event);
+    }
+
+    /// Scrolls the list with the arrows, page up, page down, home and end.
+    fn on_key_default(&mut self, event: &keyboard::Event, _: &Theme) {
+        let page = self.zone.height() as isize;
+        let end = self.widget.content_height() as isize;
+
+        match event {
+            keyboard::Event::Down { key: Key::Up, .. } => self.scroll_by(-(STEP as isize)),
+            keyboard::Event::Down { key: Key::Down, .. } => self.scroll_by(STEP as isize),
+            keyboard::Event::Down { key: Key::PageUp, .. } => self.scroll_by(-page),
+            keyboard::Event::Down { key: Key::PageDown, .. } => self.scroll_by(page),
+            keyboard::Event::Down { key: Key::Home, .. } => self.scroll_by(-end),
+            keyboard::Event::Down { key: Key::End, .. } => self.scroll_by(end),
+            _ => false,
+        };
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,166 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to moving the thumbs of the
+//! [`Slider`](crate::widgets::Slider)s.
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    themes::{State, Style, Theme},
+    widgets::{Child, Slider},
+    Direction,
+    DebugWidget,
+    Widget,
+    Zone
+};
+
+use super::{
+    keyboard::{self, Key},
+    pointer::{self, Kind},
+    Controller,
+    ControllerFn,
+    Handler,
+};
+
+/// Number of keyboard increments moved by page up and page down.
+const PAGE: f32 = 10.0;
+
+/// Focusable controller moving the thumbs of a [`Slider`] with the pointer
+/// and the keyboard.
+///
+/// Pressing the slider moves the thumb the closest to the pointer under it,
+/// then dragging moves this thumb. The arrows move the last moved thumb by a
+/// step, or by a hundredth of the range for a continuous slider, page up and
+/// page down by ten times as much. Home and end move it to the bounds.
+#[derive(Controller)]
+pub struct Dragger {
+    /// The slider.
+    pub widget: Box<Slider>,
+    /// The zone of the slider.
+    pub zone: Zone,
+    /// The transformation of the zone of the slider.
+    pub matrix: Matrix,
+    /// The interaction state of the slider.
+    pub state: State,
+    /// Function to call when a value of the slider changed.
+    on_change: ControllerFn<Slider>,
+    /// The thumb moved by the pointer and the keyboard.
+    active: usize,
+}
+
+impl Dragger {
+    /// Creates a new dragger.
+    pub fn new(slider: Slider, on_change: ControllerFn<Slider>) -> Self {
+        Self {
+            widget: Box::new(slider),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            on_change,
+            active: 0,
+        }
+    }
+
+    /// Returns the thumb moved by the pointer and the keyboard.
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Sets the value of the active thumb, then calls the function of the
+    /// dragger when it changed.
+    pub fn slide(&mut self, value: f32, theme: &Theme) {
+        if self.widget.set_value(self.active, value) {
+            let on_change = self.on_change;
+            on_change(&mut self.widget, theme);
+        }
+    }
+
+    /// Returns the position of the pointer relative to the slider, with the
+    /// length of the slider.
+    fn relative(&self, position: Point) -> (Point, usize) {
+        let [x, y] = match self.matrix.inverse() {
+            Some(inverse) => inverse.apply([position[0] as f32, position[1] as f32]),
+            None => [position[0] as f32, position[1] as f32],
+        };
+
+        let length = match self.widget.direction {
+            Direction::Column => self.zone.width(),
+            Direction::Row => self.zone.height(),
+        };
+
+        ([x as isize - self.zone.x(), y as isize - self.zone.y()], length)
+    }
+
+    /// Returns the difference of value moved by an arrow key.
+    fn increment(&self) -> f32 {
+        match self.widget.step {
+            Some(step) if step > 0.0 => step,
+            _ => (self.widget.max - self.widget.min) / 100.0,
+        }
+    }
+}
+
+impl Handler for Dragger {
+    /// Moves the thumb the closest to the pointer when the slider is pressed,
+    /// then follows the pointer while it is dragged.
+    fn on_pointer_default(&mut self, event: &pointer::Event, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let (point, length) = self.relative(event.position);
+
+        match event.kind {
+            Kind::Down => {
+                let (thumb, value) = self.widget.closest_thumb_at(point, length);
+                self.active = thumb;
+                self.slide(value, theme);
+            }
+            Kind::DragStart | Kind::DragMove { .. } => {
+                let value = self.widget.value_at(point, length, self.active);
+                self.slide(value, theme);
+            }
+            _ => (),
+        }
+    }
+
+    /// Moves the active thumb with the arrows, page up, page down, home and
+    /// end.
+    fn on_key_default(&mut self, event: &keyboard::Event, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let key = match event {
+            keyboard::Event::Down { key, .. } => *key,
+            _ => return,
+        };
+
+        let value = self.widget.values()[self.active];
+        let increment = self.increment();
+
+        let value = match key {
+            Key::Left | Key::Down => value - increment,
+            Key::Right | Key::Up => value + increment,
+            Key::PageDown => value - increment * PAGE,
+            Key::PageUp => value + increment * PAGE,
+            Key::Home => self.widget.min,
+            Key::End => self.widget.max,
+            _ => return,
+        };
+
+        self.slide(value, theme);
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
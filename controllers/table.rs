@@ -0,0 +1,189 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to sorting, selecting and resizing a [`Table`].
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    themes::{State, Style, Theme},
+    widgets::{Child, Part, Sort, Table},
+    DebugWidget,
+    Widget,
+    Zone
+};
+
+use super::{
+    keyboard::{self, Key},
+    pointer::{self, Kind},
+    Controller,
+    Handler,
+};
+
+/// Closure to call when the rows of a table are sorted by another column or
+/// in another order, with the column and the order.
+type SortFn = Box<dyn FnMut(&mut Table, usize, Sort, &Theme)>;
+
+/// Closure to call when another row of a table is selected.
+type SelectFn = Box<dyn FnMut(&mut Table, &Theme)>;
+
+/// Focusable controller of a [`Table`].
+///
+/// Clicking a header sorts the rows by its column, the closure of the grid
+/// sorting the data shown by the cells. Clicking a cell selects its row,
+/// the arrows select the previous or next row. Dragging the right border of
+/// a header resizes its column.
+#[derive(Controller)]
+pub struct Grid {
+    /// The table.
+    pub widget: Box<Table>,
+    /// The zone of the table.
+    pub zone: Zone,
+    /// The transformation of the zone of the table.
+    pub matrix: Matrix,
+    /// The interaction state of the table.
+    pub state: State,
+    /// Closure to call when the rows are sorted.
+    on_sort: SortFn,
+    /// Closure to call when another row is selected.
+    on_select: SelectFn,
+    /// The column resized by dragging its border.
+    resized: Option<usize>,
+    /// The width of the resized column when the drag started, and the 
+    /// distance dragged since.
+    drag: Option<(isize, isize)>,
+}
+
+impl Grid {
+    /// Creates a new grid.
+    pub fn new(
+        table: Table, 
+        on_sort: impl FnMut(&mut Table, usize, Sort, &Theme) + 'static, 
+        on_select: impl FnMut(&mut Table, &Theme) + 'static,
+    ) -> Self {
+        Self {
+            widget: Box::new(table),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            on_sort: Box::new(on_sort),
+            on_select: Box::new(on_select),
+            resized: None,
+            drag: None,
+        }
+    }
+
+    /// Returns the column resized by dragging its border, if any.
+    pub fn resized(&self) -> Option<usize> {
+        self.resized
+    }
+
+    /// Returns the part of the table under the pointer.
+    fn part_at(&self, position: Point) -> Option<Part> {
+        let [x, y] = match self.matrix.inverse() {
+            Some(inverse) => inverse.apply([position[0] as f32, position[1] as f32]),
+            None => [position[0] as f32, position[1] as f32],
+        };
+
+        self.widget.part_at([x as isize - self.zone.x(), y as isize - self.zone.y()], self.zone.width())
+    }
+
+    /// Sorts the rows by a column, then calls the sort closure of the grid.
+    pub fn sort_by(&mut self, column: usize, theme: &Theme) {
+        if self.state == State::Disabled || column >= self.widget.columns.len() {
+            return;
+        }
+
+        let Some(sort) = self.widget.sort_by(column) else {
+            return;
+        };
+
+        (self.on_sort)(&mut self.widget, column, sort, theme);
+    }
+
+    /// Selects a row, then calls the select closure of the grid when it
+    /// changed.
+    pub fn select(&mut self, row: usize, theme: &Theme) {
+        if self.state == State::Disabled || row >= self.widget.rows || self.widget.selected == Some(row) {
+            return;
+        }
+
+        self.widget.selected = Some(row);
+
+        (self.on_select)(&mut self.widget, theme);
+    }
+}
+
+impl Handler for Grid {
+    /// Sorts by the clicked header, selects the row of the clicked cell, and
+    /// resizes the column of the dragged border.
+    fn on_pointer_default(&mut self, event: &pointer::Event, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        match event.kind {
+            Kind::Down => {
+                self.resized = match self.part_at(event.position) {
+                    Some(Part::Border(column)) => Some(column),
+                    _ => None,
+                };
+            }
+            Kind::DragStart => {
+                self.drag = self.resized
+                    .and_then(|column| self.widget.column_widths(self.zone.width()).get(column).copied())
+                    .map(|width| (width as isize, 0));
+            }
+            // Resized from its width when the drag started, so the border
+            // follows the pointer even after the column reached its minimum
+            // width.
+            Kind::DragMove { delta } => {
+                if let (Some(column), Some((width, dragged))) = (self.resized, &mut self.drag) {
+                    *dragged += delta[0];
+                    self.widget.set_width(column, *width + *dragged);
+                }
+            }
+            Kind::DragEnd => {
+                self.resized = None;
+                self.drag = None;
+            }
+            Kind::Click => match self.part_at(event.position) {
+                Some(Part::Header(column)) => self.sort_by(column, theme),
+                Some(Part::Cell(row, _)) => self.select(row, theme),
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+
+    /// Selects the previous or next row with the arrows, the first or last
+    /// row with home or end.
+    fn on_key_default(&mut self, event: &keyboard::Event, theme: &Theme) {
+        let rows = self.widget.rows;
+
+        if rows == 0 {
+            return;
+        }
+
+        let row = match event {
+            keyboard::Event::Down { key: Key::Up, .. } => self.widget.selected.map_or(rows - 1, |row| row.saturating_sub(1)),
+            keyboard::Event::Down { key: Key::Down, .. } => self.widget.selected.map_or(0, |row| (row + 1).min(rows - 1)),
+            keyboard::Event::Down { key: Key::Home, .. } => 0,
+            keyboard::Event::Down { key: Key::End, .. } => rows - 1,
+            _ => return,
+        };
+
+        self.select(row, theme);
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,186 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to switching the [`Tabs`] and the
+//! [`NavigationBar`]s.
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    themes::{State, Style, Theme},
+    widgets::{Child, NavigationBar, Tabs},
+    Direction,
+    DebugWidget,
+    Widget,
+    Zone
+};
+
+use super::{
+    keyboard::{self, Key},
+    pointer::{self, Kind},
+    Controller,
+    ControllerFn,
+    Handler,
+};
+
+/// Widget with a [`NavigationBar`] switched by a [`Switcher`].
+pub trait Navigation {
+    /// Returns the navigation bar of the widget.
+    fn bar(&self) -> &NavigationBar;
+
+    /// Same as [`Self::bar`] but the returned bar is mutable.
+    fn bar_mut(&mut self) -> &mut NavigationBar;
+
+    /// Returns the zone of the navigation bar in the widget of zone `zone`.
+    fn bar_zone(&self, zone: Zone) -> Zone;
+}
+
+impl Navigation for NavigationBar {
+    fn bar(&self) -> &NavigationBar {
+        self
+    }
+
+    fn bar_mut(&mut self) -> &mut NavigationBar {
+        self
+    }
+
+    fn bar_zone(&self, zone: Zone) -> Zone {
+        zone
+    }
+}
+
+impl Navigation for Tabs {
+    fn bar(&self) -> &NavigationBar {
+        &self.bar
+    }
+
+    fn bar_mut(&mut self) -> &mut NavigationBar {
+        &mut self.bar
+    }
+
+    fn bar_zone(&self, zone: Zone) -> Zone {
+        Tabs::bar_zone(self, zone)
+    }
+}
+
+/// Focusable controller selecting the tapped destination of a navigation bar,
+/// or the tapped page of tabs.
+///
+/// The arrows select the previous or next destination, left and right for a
+/// horizontal bar, up and down for a vertical one.
+#[derive(Controller)]
+pub struct Switcher<T: Widget> {
+    /// The switched widget.
+    pub widget: Box<T>,
+    /// The zone of the switched widget.
+    pub zone: Zone,
+    /// The transformation of the zone of the switched widget.
+    pub matrix: Matrix,
+    /// The interaction state of the switched widget.
+    pub state: State,
+    /// Returns the navigation bar of the widget.
+    bar: fn(&T) -> &NavigationBar,
+    /// Same as `bar` but the returned bar is mutable.
+    bar_mut: fn(&mut T) -> &mut NavigationBar,
+    /// Returns the zone of the navigation bar.
+    bar_zone: fn(&T, Zone) -> Zone,
+    /// Function to call when another destination is selected.
+    on_select: ControllerFn<T>,
+}
+
+impl<T: Widget + Navigation> Switcher<T> {
+    /// Creates a new switcher.
+    pub fn new(widget: T, on_select: ControllerFn<T>) -> Self {
+        Self {
+            widget: Box::new(widget),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            bar: T::bar,
+            bar_mut: T::bar_mut,
+            bar_zone: T::bar_zone,
+            on_select,
+        }
+    }
+}
+
+impl<T: Widget> Switcher<T> {
+    /// Returns the index of the selected destination.
+    pub fn selected(&self) -> usize {
+        (self.bar)(&self.widget).selected
+    }
+
+    /// Selects a destination, then calls the function of the switcher when
+    /// the selection changed.
+    ///
+    /// Nothing happens when the widget is disabled.
+    pub fn select(&mut self, item: usize, theme: &Theme) {
+        if self.state == State::Disabled || !(self.bar_mut)(&mut self.widget).select(item) {
+            return;
+        }
+
+        let on_select = self.on_select;
+        on_select(&mut self.widget, theme);
+    }
+
+    /// Returns the destination under the pointer, if any.
+    fn item_at(&self, position: Point) -> Option<usize> {
+        let [x, y] = match self.matrix.inverse() {
+            Some(inverse) => inverse.apply([position[0] as f32, position[1] as f32]),
+            None => [position[0] as f32, position[1] as f32],
+        };
+        let zone = (self.bar_zone)(&self.widget, self.zone);
+
+        (self.bar)(&self.widget).item_at([x as isize - zone.x(), y as isize - zone.y()], zone.size)
+    }
+}
+
+impl<T: Widget> Handler for Switcher<T> {
+    /// Selects the clicked destination.
+    fn on_pointer_default(&mut self, event: &pointer::Event, theme: &Theme) {
+        if event.kind != Kind::Click {
+            return;
+        }
+
+        if let Some(item) = self.item_at(event.position) {
+            self.select(item, theme);
+        }
+    }
+
+    /// Selects the previous or next destination with the arrows following
+    /// the direction of the bar, the first or last one with home or end.
+    fn on_key_default(&mut self, event: &keyboard::Event, theme: &Theme) {
+        let bar = (self.bar)(&self.widget);
+        let count = bar.items.len();
+
+        if count == 0 {
+            return;
+        }
+
+        let (previous, next) = match bar.direction {
+            Direction::Column => (Key::Left, Key::Right),
+            Direction::Row => (Key::Up, Key::Down),
+        };
+
+        let item = match event {
+            keyboard::Event::Down { key, .. } if *key == previous => bar.selected.saturating_sub(1),
+            keyboard::Event::Down { key, .. } if *key == next => (bar.selected + 1).min(count - 1),
+            keyboard::Event::Down { key: Key::Home, .. } => 0,
+            keyboard::Event::Down { key: Key::End, .. } => count - 1,
+            _ => return,
+        };
+
+        self.select(item, theme);
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
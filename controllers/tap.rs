@@ -9,24 +9,34 @@ use any::ToAny;
 use haussmann_dev::Controller;
 
 use crate::{
-    graphics::{Point, Size},
-    themes::{Theme, Style},
+    graphics::{Matrix, Point, Size},
+    themes::{State, Style, Theme},
+    widgets::Child,
     DebugWidget, 
     Widget, 
     Zone
 };
 
-use super::{Controller, ControllerFn};
+use super::{keyboard, pointer, Controller, ControllerFn, Handler};
 
-/// Whether a tap is over the tappable zone.
+/// Whether a tap is over the tappable zone transformed by `matrix`.
+/// 
+/// The tap is brought back in the untransformed space of the zone thanks to 
+/// the inverse of the matrix. A zone with a matrix that cannot be inverted (a
+/// scale of zero for example) is never tapped. The right and bottom edges are 
+/// not tapped, like in [`Zone::contains`], so a tap is over only one of two 
+/// zones side by side.
 /// 
 /// This function must be called after the widget is drawn, never before.
 #[inline]
-pub fn is_tapped(tap: Point, zone: Zone) -> bool {
-    let (x, y) = (tap[0], tap[1]);
+pub fn is_tapped(tap: Point, zone: Zone, matrix: Matrix) -> bool {
+    let [x, y] = match matrix.inverse() {
+        Some(inverse) => inverse.apply([tap[0] as f32, tap[1] as f32]),
+        None => return false,
+    };
 
-    x >= zone.x() && x <= zone.x() + zone.width() as isize 
-        && y >= zone.y() && y <= zone.y() + zone.height() as isize
+    x >= zone.x() as f32 && x < (zone.x() + zone.width() as isize) as f32 
+        && y >= zone.y() as f32 && y < (zone.y() + zone.height() as isize) as f32
 }
 
 /// Controller detecting taps on a widget.
@@ -36,6 +46,10 @@ pub struct Detector<T: Widget> {
     pub widget: Box<T>,
     /// The zone covered by the tap detection.
     pub zone: Zone,
+    /// The transformation of the zone covered by the tap detection.
+    pub matrix: Matrix,
+    /// The interaction state of the widget.
+    pub state: State,
     /// Function to call when the widget is tapped.
     tap: ControllerFn<T>,
 }
@@ -49,13 +63,42 @@ impl<T: Widget> Detector<T> {
                 position: Point::from([0, 0]),
                 size: Size::from([0, 0]),
             },
+            matrix: Matrix::identity(),
+            state: State::Normal,
             tap,
         }
     }
 
     /// Function to call when the wrapped widget was tapped.
+    /// 
+    /// Nothing is called when the widget is disabled.
     pub fn on_tap(&mut self, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
         let tap = self.tap;
         tap(&mut self.widget, theme);
     }
 }
+
+impl<T: Widget> Handler for Detector<T> {
+    /// Taps the widget when it is clicked, unless a controller containing it
+    /// prevented it.
+    fn on_pointer_default(&mut self, event: &pointer::Event, theme: &Theme) {
+        if event.kind == pointer::Kind::Click {
+            self.on_tap(theme);
+        }
+    }
+
+    /// Taps the widget when enter or space is pressed while it is focused.
+    fn on_key_default(&mut self, event: &keyboard::Event, theme: &Theme) {
+        if event.is_down(keyboard::Key::Enter) || event.is_down(keyboard::Key::Space) {
+            self.on_tap(theme);
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,220 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to editing the text of the
+//! [`TextField`](crate::widgets::TextField)s.
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    text::{Clipboard, MemoryClipboard, Motion},
+    themes::{State, Style, Theme},
+    widgets::{Child, TextField},
+    DebugWidget,
+    Widget,
+    Zone
+};
+
+use super::{
+    keyboard::{self, Key, Modifiers},
+    pointer::{self, Kind},
+    Controller,
+    ControllerFn,
+    Handler,
+};
+
+/// Focusable controller editing the text of a [`TextField`] with the keyboard
+/// and the pointer.
+///
+/// The typed characters and the text composed with an input method are
+/// inserted at the cursor. The arrows move the cursor, by word with control,
+/// and extend the selection with shift. Control (or meta) with `A`, `C`, `X`,
+/// `V`, `Z` and `Y` selects all, copies, cuts, pastes, undoes and redoes.
+/// Enter inserts a line break in a multi-line field, and submits a
+/// single-line field.
+///
+/// The pointer places the cursor, drags the selection and selects a word
+/// with a double click.
+#[derive(Controller)]
+pub struct Editor {
+    /// The edited field.
+    pub widget: Box<TextField>,
+    /// The zone of the field.
+    pub zone: Zone,
+    /// The transformation of the zone of the field.
+    pub matrix: Matrix,
+    /// The interaction state of the field.
+    pub state: State,
+    /// Function to call when the text changed.
+    on_change: ControllerFn<TextField>,
+    /// Function to call when enter is pressed in a single-line field.
+    on_submit: Option<ControllerFn<TextField>>,
+    /// Clipboard to copy, cut and paste text.
+    clipboard: Box<dyn Clipboard>,
+}
+
+impl Editor {
+    /// Creates a new editor, with a clipboard kept in memory.
+    pub fn new(field: TextField, on_change: ControllerFn<TextField>) -> Self {
+        Self {
+            widget: Box::new(field),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            on_change,
+            on_submit: None,
+            clipboard: Box::<MemoryClipboard>::default(),
+        }
+    }
+
+    /// Calls a function when enter is pressed in a single-line field.
+    pub fn with_submit(mut self, on_submit: ControllerFn<TextField>) -> Self {
+        self.on_submit = Some(on_submit);
+        self
+    }
+
+    /// Uses the clipboard of the backend, or one shared by several editors.
+    pub fn with_clipboard<C: Clipboard + 'static>(mut self, clipboard: C) -> Self {
+        self.clipboard = Box::new(clipboard);
+        self
+    }
+
+    /// Edits the text following a pressed key. Returns whether the text
+    /// changed.
+    fn press(&mut self, key: Key, modifiers: Modifiers, theme: &Theme) -> bool {
+        let buffer = &mut self.widget.buffer;
+        let shortcut = modifiers.control || modifiers.meta;
+        let extend = modifiers.shift;
+
+        // The cursor moves by words with control.
+        let (left, right) = match shortcut {
+            true => (Motion::WordLeft, Motion::WordRight),
+            false => (Motion::Left, Motion::Right),
+        };
+
+        match key {
+            Key::Character('a') if shortcut => buffer.select_all(),
+            Key::Character('c') if shortcut => {
+                if let Some(text) = buffer.selected_text() {
+                    self.clipboard.write(&text);
+                }
+            }
+            Key::Character('x') if shortcut => {
+                return match buffer.cut() {
+                    Some(text) => {
+                        self.clipboard.write(&text);
+                        true
+                    }
+                    None => false,
+                };
+            }
+            Key::Character('v') if shortcut => {
+                return match self.clipboard.read() {
+                    Some(text) => buffer.insert(&text),
+                    None => false,
+                };
+            }
+            Key::Character('z') if shortcut && extend => return buffer.redo(),
+            Key::Character('z') if shortcut => return buffer.undo(),
+            Key::Character('y') if shortcut => return buffer.redo(),
+            Key::Left => buffer.move_cursor(left, extend),
+            Key::Right => buffer.move_cursor(right, extend),
+            Key::Up => buffer.move_cursor(Motion::Up, extend),
+            Key::Down => buffer.move_cursor(Motion::Down, extend),
+            Key::Home if shortcut => buffer.move_cursor(Motion::Start, extend),
+            Key::Home => buffer.move_cursor(Motion::LineStart, extend),
+            Key::End if shortcut => buffer.move_cursor(Motion::End, extend),
+            Key::End => buffer.move_cursor(Motion::LineEnd, extend),
+            Key::Backspace => return buffer.delete(left),
+            Key::Delete => return buffer.delete(right),
+            Key::Enter if buffer.is_multi_line() => return buffer.insert("\n"),
+            Key::Enter => {
+                if let Some(on_submit) = self.on_submit {
+                    on_submit(&mut self.widget, theme);
+                }
+            }
+            _ => (),
+        }
+
+        false
+    }
+
+    /// Returns the position in the text under the pointer.
+    fn index_at(&self, position: Point, theme: &Theme) -> usize {
+        let [x, y] = match self.matrix.inverse() {
+            Some(inverse) => inverse.apply([position[0] as f32, position[1] as f32]),
+            None => [position[0] as f32, position[1] as f32],
+        };
+
+        let [top, _, _, left] = self.widget.padding.resolve(&theme.tokens.spacing);
+
+        self.widget.index_at([
+            x as isize - self.zone.x() - left as isize,
+            y as isize - self.zone.y() - top as isize,
+        ])
+    }
+}
+
+impl Handler for Editor {
+    /// Edits the text, unless the field is disabled. Calls the function of
+    /// the editor when the text changed.
+    fn on_key_default(&mut self, event: &keyboard::Event, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let changed = match event {
+            keyboard::Event::Char { character } if !character.is_control() => {
+                self.widget.buffer.insert(&character.to_string())
+            }
+            keyboard::Event::Composition { text, cursor } => {
+                self.widget.buffer.set_composition(text, *cursor);
+                false
+            }
+            keyboard::Event::Commit { text } => self.widget.buffer.commit(text),
+            // The keys are for the input method while composing.
+            keyboard::Event::Down { key, modifiers, .. } if self.widget.buffer.composition().is_none() => {
+                self.press(*key, *modifiers, theme)
+            }
+            _ => false,
+        };
+
+        if changed {
+            let on_change = self.on_change;
+            on_change(&mut self.widget, theme);
+        }
+    }
+
+    /// Places the cursor where the field is pressed, and extends the
+    /// selection while dragging.
+    fn on_pointer_default(&mut self, event: &pointer::Event, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let index = self.index_at(event.position, theme);
+
+        match event.kind {
+            Kind::Down => self.widget.buffer.set_cursor(index, false),
+            Kind::DragStart | Kind::DragMove { .. } => self.widget.buffer.set_cursor(index, true),
+            Kind::DoubleClick => self.widget.buffer.select_word(index),
+            _ => (),
+        }
+    }
+
+    /// Draws the caret while the field is focused.
+    fn on_focus_change(&mut self, focused: bool, _: &Theme) {
+        self.widget.focused = focused;
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
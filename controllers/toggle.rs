@@ -0,0 +1,217 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to toggling the [`Checkbox`](crate::widgets::Checkbox)es,
+//! the [`Radio`](crate::widgets::Radio)s and the
+//! [`Switch`](crate::widgets::Switch)es.
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    themes::{State, Style, Theme},
+    widgets::{Child, Container, Layout, Radio},
+    DebugWidget,
+    Widget,
+    Zone
+};
+
+use super::{
+    keyboard::{self, Key},
+    pointer,
+    propagation::{Phase, Propagation},
+    Controller,
+    ControllerFn,
+    Handler,
+};
+
+/// Widget with a value changed by a [`Toggler`].
+pub trait Toggle {
+    /// Changes the value of the widget, like a tap on it does. Returns
+    /// whether the value changed.
+    fn toggle(&mut self) -> bool;
+}
+
+/// Focusable controller toggling a widget when it is clicked or when space is
+/// pressed while it is focused.
+#[derive(Controller)]
+pub struct Toggler<T: Widget> {
+    /// The toggled widget.
+    pub widget: Box<T>,
+    /// The zone of the toggled widget.
+    pub zone: Zone,
+    /// The transformation of the zone of the toggled widget.
+    pub matrix: Matrix,
+    /// The interaction state of the widget.
+    pub state: State,
+    /// Changes the value of the widget, returns whether it changed.
+    toggle: fn(&mut T) -> bool,
+    /// Function to call when the value of the widget changed.
+    on_change: ControllerFn<T>,
+}
+
+impl<T: Widget + Toggle> Toggler<T> {
+    /// Creates a new toggler.
+    pub fn new(widget: T, on_change: ControllerFn<T>) -> Self {
+        Self {
+            widget: Box::new(widget),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            toggle: T::toggle,
+            on_change,
+        }
+    }
+}
+
+impl<T: Widget> Toggler<T> {
+    /// Toggles the wrapped widget, then calls the function of the toggler 
+    /// when its value changed.
+    ///
+    /// Nothing happens when the widget is disabled.
+    pub fn on_toggle(&mut self, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let toggle = self.toggle;
+        if !toggle(&mut self.widget) {
+            return;
+        }
+
+        let on_change = self.on_change;
+        on_change(&mut self.widget, theme);
+    }
+}
+
+impl<T: Widget> Handler for Toggler<T> {
+    /// Toggles the widget when it is clicked, unless a controller containing
+    /// it prevented it.
+    fn on_pointer_default(&mut self, event: &pointer::Event, theme: &Theme) {
+        if event.kind == pointer::Kind::Click {
+            self.on_toggle(theme);
+        }
+    }
+
+    /// Toggles the widget when space is pressed while it is focused.
+    fn on_key_default(&mut self, event: &keyboard::Event, theme: &Theme) {
+        if event.is_down(Key::Space) {
+            self.on_toggle(theme);
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
+
+/// Function to call when a radio of a group is selected, with the index of
+/// its row in the layout of the group.
+type SelectFn = fn(layout: &mut Layout, selected: usize, theme: &Theme);
+
+/// Controller grouping the radios of a layout, so only one radio is selected.
+///
+/// The radios are toggled by [`Toggler`]s in the rows of the layout, directly
+/// or in a layout or a container making the row. When a radio is toggled,
+/// the radios of the other rows are deselected.
+#[derive(Controller)]
+pub struct Group {
+    /// The layout containing the radios.
+    pub widget: Box<Layout>,
+    /// The zone of the layout.
+    pub zone: Zone,
+    /// The transformation of the zone of the layout.
+    pub matrix: Matrix,
+    /// The interaction state of the layout.
+    pub state: State,
+    /// Function to call when another radio is selected.
+    on_select: SelectFn,
+}
+
+impl Group {
+    /// Creates a new radio group.
+    pub fn new(layout: Layout, on_select: SelectFn) -> Self {
+        Self {
+            widget: Box::new(layout),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            on_select,
+        }
+    }
+
+    /// Returns the index of the row of the selected radio, if any.
+    pub fn selected(&mut self) -> Option<usize> {
+        self.widget.widgets.iter_mut().position(|row| {
+            let mut selected = false;
+            radios(row, &mut |radio| selected |= radio.widget.selected);
+            selected
+        })
+    }
+
+    /// Selects the radio of a row and deselects the others. The function of
+    /// the group is called when the selected radio changed.
+    pub fn select(&mut self, row: usize, theme: &Theme) {
+        if self.state == State::Disabled || self.selected() == Some(row) {
+            return;
+        }
+
+        for (i, widget) in self.widget.widgets.iter_mut().enumerate() {
+            radios(widget, &mut |radio| radio.widget.selected = i == row);
+        }
+
+        let on_select = self.on_select;
+        on_select(&mut self.widget, row, theme);
+    }
+
+    /// Selects the row of the radio toggled by an event going up from it.
+    fn on_event(&mut self, toggles: bool, propagation: &Propagation, theme: &Theme) {
+        if !toggles || propagation.phase() != Phase::Bubble {
+            return;
+        }
+
+        if let Some(row) = propagation.child() {
+            let mut radio = false;
+            radios(&mut self.widget.widgets[row], &mut |_| radio = true);
+
+            if radio {
+                self.select(row, theme);
+            }
+        }
+    }
+}
+
+impl Handler for Group {
+    /// Selects the clicked radio.
+    fn on_pointer(&mut self, event: &pointer::Event, propagation: &mut Propagation, theme: &Theme) {
+        self.on_event(event.kind == pointer::Kind::Click, propagation, theme);
+    }
+
+    /// Selects the radio focused when space is pressed.
+    fn on_key(&mut self, event: &keyboard::Event, propagation: &mut Propagation, theme: &Theme) {
+        self.on_event(event.is_down(Key::Space), propagation, theme);
+    }
+}
+
+/// Calls `visit` with the radio togglers of a widget: the widget itself, or
+/// the ones in the widget when it is a layout or a container.
+fn radios(widget: &mut Box<dyn Widget>, visit: &mut dyn FnMut(&mut Toggler<Radio>)) {
+    if let Some(radio) = widget.as_any_mut().downcast_mut::<Toggler<Radio>>() {
+        visit(radio);
+    } else if let Some(layout) = widget.as_any_mut().downcast_mut::<Layout>() {
+        for widget in &mut layout.widgets {
+            radios(widget, visit);
+        }
+    } else if let Some(container) = widget.as_any_mut().downcast_mut::<Container>() {
+        radios(&mut container.widget, visit);
+    }
+}
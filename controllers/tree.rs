@@ -0,0 +1,351 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to expanding, loading and selecting the nodes of a
+//! [`TreeView`].
+
+use any::ToAny;
+
+use haussmann_dev::Controller;
+
+use crate::{
+    graphics::{Matrix, Point, Size},
+    themes::{State, Style, Theme},
+    widgets::{Child, Label, Layout, NodePath, TreeNode, TreeView},
+    DebugWidget,
+    Widget,
+    Zone
+};
+
+use super::{
+    keyboard::{self, Key},
+    pointer::{self, Kind},
+    propagation::{Phase, Propagation},
+    Controller,
+    Handler,
+};
+
+/// Closure to call the first time a node whose children are not loaded is
+/// expanded, with the node and its path, returning its children.
+type LoadFn = Box<dyn FnMut(&TreeNode, &[usize]) -> Vec<TreeNode>>;
+
+/// Closure to call when another node of a tree view is selected.
+type SelectFn = Box<dyn FnMut(&mut TreeView, &Theme)>;
+
+/// What a [`Row`] or an [`Expander`] asks the [`Navigator`] of its tree to do
+/// with the node of the row.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Action {
+    Select,
+    Toggle,
+}
+
+/// Controller of the row of a node, built by a [`TreeView`] around the layout
+/// of the row.
+///
+/// Clicking the row selects its node, double-clicking it expands or collapses
+/// its node. The [`Navigator`] of the tree does it when the event goes up to 
+/// it.
+#[derive(Controller)]
+pub struct Row {
+    /// The layout of the row.
+    pub widget: Box<Layout>,
+    /// The zone of the row.
+    pub zone: Zone,
+    /// The transformation of the zone of the row.
+    pub matrix: Matrix,
+    /// The interaction state of the row.
+    pub state: State,
+    /// The path to the node of the row.
+    pub path: NodePath,
+    /// What the row asks the navigator to do, until it does it.
+    action: Option<Action>,
+}
+
+impl Row {
+    /// Creates a new row for the node at a path.
+    pub fn new(path: NodePath, layout: Layout) -> Self {
+        Self {
+            widget: Box::new(layout),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            path,
+            action: None,
+        }
+    }
+}
+
+impl Handler for Row {
+    /// Asks to select the node of the clicked row, or to toggle the node of 
+    /// the double-clicked row.
+    fn on_pointer(&mut self, event: &pointer::Event, propagation: &mut Propagation, _: &Theme) {
+        if propagation.phase() != Phase::Target {
+            return;
+        }
+
+        match event.kind {
+            Kind::Click => self.action = Some(Action::Select),
+            Kind::DoubleClick => self.action = Some(Action::Toggle),
+            _ => (),
+        }
+    }
+}
+
+/// Controller of the toggle of a [`Row`], built by a [`TreeView`] around the
+/// label of the toggle.
+///
+/// Clicking the toggle expands or collapses the node of its row. The 
+/// [`Navigator`] of the tree does it when the event goes up to it.
+#[derive(Controller)]
+pub struct Expander {
+    /// The label of the toggle.
+    pub widget: Box<Label>,
+    /// The zone of the toggle.
+    pub zone: Zone,
+    /// The transformation of the zone of the toggle.
+    pub matrix: Matrix,
+    /// The interaction state of the toggle.
+    pub state: State,
+    /// Whether the toggle asks the navigator to toggle the node, until it 
+    /// does it.
+    toggled: bool,
+}
+
+impl Expander {
+    /// Creates a new toggle.
+    pub fn new(label: Label) -> Self {
+        Self {
+            widget: Box::new(label),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            toggled: false,
+        }
+    }
+}
+
+impl Handler for Expander {
+    /// Asks to toggle the node of the row of the clicked toggle.
+    fn on_pointer(&mut self, event: &pointer::Event, propagation: &mut Propagation, _: &Theme) {
+        if propagation.phase() == Phase::Target && event.kind == Kind::Click {
+            self.toggled = true;
+        }
+    }
+}
+
+/// Takes the action asked by a row, or by its toggle, in a widget made of the
+/// rows of a tree, with the path to the node of the row. `row` is the path of
+/// the row containing the widget.
+fn take_action(widget: &mut Box<dyn Widget>, row: Option<&NodePath>) -> Option<(NodePath, Action)> {
+    if let Some(expander) = widget.as_any_mut().downcast_mut::<Expander>() {
+        let toggled = std::mem::take(&mut expander.toggled);
+        return row.filter(|_| toggled).map(|row| (row.clone(), Action::Toggle));
+    }
+
+    if let Some(row) = widget.as_any_mut().downcast_mut::<Row>() {
+        if let Some(action) = row.action.take() {
+            return Some((row.path.clone(), action));
+        }
+
+        let path = row.path.clone();
+        return row.widget.widgets.iter_mut().find_map(|widget| take_action(widget, Some(&path)));
+    }
+
+    if let Some(layout) = widget.as_any_mut().downcast_mut::<Layout>() {
+        return layout.widgets.iter_mut().find_map(|widget| take_action(widget, row));
+    }
+
+    widget.wrapped_mut().and_then(|widget| take_action(widget, row))
+}
+
+/// Focusable controller of a [`TreeView`].
+///
+/// Clicking the toggle of a node, or double-clicking the node, expands or
+/// collapses it. Clicking a node selects it, through the [`Row`] and the 
+/// [`Expander`] of the node. The arrows up and down select
+/// the previous or next row, the arrow right expands the selected node then
+/// selects its first child, the arrow left collapses it then selects its
+/// parent.
+#[derive(Controller)]
+pub struct Navigator {
+    /// The tree view.
+    pub widget: Box<TreeView>,
+    /// The zone of the tree view.
+    pub zone: Zone,
+    /// The transformation of the zone of the tree view.
+    pub matrix: Matrix,
+    /// The interaction state of the tree view.
+    pub state: State,
+    /// Closure to call to load the children of a node.
+    on_load: LoadFn,
+    /// Closure to call when another node is selected.
+    on_select: SelectFn,
+}
+
+impl Navigator {
+    /// Creates a new navigator.
+    pub fn new(
+        tree: TreeView, 
+        on_load: impl FnMut(&TreeNode, &[usize]) -> Vec<TreeNode> + 'static, 
+        on_select: impl FnMut(&mut TreeView, &Theme) + 'static,
+    ) -> Self {
+        Self {
+            widget: Box::new(tree),
+            zone: Zone {
+                position: Point::from([0, 0]),
+                size: Size::from([0, 0]),
+            },
+            matrix: Matrix::identity(),
+            state: State::Normal,
+            on_load: Box::new(on_load),
+            on_select: Box::new(on_select),
+        }
+    }
+
+    /// Expands a node, loading its children first if they are not loaded.
+    pub fn expand(&mut self, path: &[usize]) {
+        let Some(node) = self.widget.node(path) else {
+            return;
+        };
+
+        if node.expanded || !node.has_children() {
+            return;
+        }
+
+        let loaded = match node.children {
+            None => Some((self.on_load)(node, path)),
+            Some(_) => None,
+        };
+
+        let node = self.widget.node_mut(path).unwrap();
+        if let Some(children) = loaded {
+            node.children = Some(children);
+        }
+        node.expanded = true;
+    }
+
+    /// Collapses a node. The selection moves to the node when it was inside.
+    pub fn collapse(&mut self, path: &[usize], theme: &Theme) {
+        match self.widget.node_mut(path) {
+            Some(node) if node.expanded => node.expanded = false,
+            _ => return,
+        }
+
+        let hidden = self.widget.selected
+            .as_ref()
+            .is_some_and(|selected| selected.len() > path.len() && selected.starts_with(path));
+
+        if hidden {
+            self.select(path.to_vec(), theme);
+        }
+    }
+
+    /// Expands a collapsed node, collapses an expanded one.
+    pub fn toggle(&mut self, path: &[usize], theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        match self.widget.node(path).map(|node| node.expanded) {
+            Some(true) => self.collapse(path, theme),
+            Some(false) => self.expand(path),
+            None => (),
+        }
+    }
+
+    /// Selects a node, then calls the select closure of the navigator when
+    /// it changed.
+    pub fn select(&mut self, path: NodePath, theme: &Theme) {
+        if self.state == State::Disabled
+            || self.widget.node(&path).is_none()
+            || self.widget.selected.as_ref() == Some(&path)
+        {
+            return;
+        }
+
+        self.widget.selected = Some(path);
+
+        (self.on_select)(&mut self.widget, theme);
+    }
+}
+
+impl Handler for Navigator {
+    /// Toggles the node of the clicked toggle or of the double-clicked row,
+    /// selects the node of the clicked row, when the event goes up from them.
+    fn on_pointer(&mut self, _: &pointer::Event, propagation: &mut Propagation, theme: &Theme) {
+        if propagation.phase() != Phase::Bubble {
+            return;
+        }
+
+        let mut action = None;
+        self.widget.children_mut(&mut |_, rows| action = take_action(rows, None));
+
+        match action {
+            Some((path, Action::Select)) => self.select(path, theme),
+            Some((path, Action::Toggle)) => self.toggle(&path, theme),
+            None => (),
+        }
+    }
+
+    /// Moves the selection through the rows, expands or collapses the
+    /// selected node.
+    fn on_key_default(&mut self, event: &keyboard::Event, theme: &Theme) {
+        if self.state == State::Disabled {
+            return;
+        }
+
+        let rows = self.widget.rows();
+        let (Some(first), Some(last)) = (rows.first(), rows.last()) else {
+            return;
+        };
+
+        let keyboard::Event::Down { key, .. } = event else {
+            return;
+        };
+
+        let Some(selected) = self.widget.selected.clone() else {
+            match key {
+                Key::Up | Key::End => self.select(last.clone(), theme),
+                Key::Down | Key::Home => self.select(first.clone(), theme),
+                _ => (),
+            }
+            return;
+        };
+
+        // The selected path may not lead to a node anymore.
+        let Some(node) = self.widget.node(&selected) else {
+            return;
+        };
+        let row = rows.iter().position(|path| *path == selected).unwrap_or(0);
+
+        match key {
+            Key::Up => self.select(rows[row.saturating_sub(1)].clone(), theme),
+            Key::Down => self.select(rows[(row + 1).min(rows.len() - 1)].clone(), theme),
+            Key::Home => self.select(first.clone(), theme),
+            Key::End => self.select(last.clone(), theme),
+            Key::Right if !node.has_children() => (),
+            Key::Right if !node.expanded => self.expand(&selected),
+            Key::Right => {
+                let mut child = selected;
+                child.push(0);
+                self.select(child, theme);
+            }
+            Key::Left if node.expanded => self.collapse(&selected, theme),
+            Key::Left if selected.len() > 1 => self.select(selected[..selected.len() - 1].to_vec(), theme),
+            Key::Enter | Key::Space => self.toggle(&selected, theme),
+            _ => (),
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
@@ -39,18 +39,38 @@ pub fn controller(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
     let struct_ident = ast.ident;
 
+    // The controllers are generic over the wrapped widget, unless they are 
+    // made for one type of widget.
+    let (generics, ty) = match ast.generics.params.is_empty() {
+        true => (quote! {}, quote! {}),
+        false => (quote! { <T: Widget + 'static> }, quote! { <T> }),
+    };
+
     let gen = quote! {
-        impl<T: Widget + 'static> Controller for #struct_ident<T> {
+        impl #generics Controller for #struct_ident #ty {
             fn zone(&self) -> &Zone {
                 &self.zone
             }
 
-            fn update(&mut self, zone: Zone) {
+            fn matrix(&self) -> &Matrix {
+                &self.matrix
+            }
+
+            fn update(&mut self, zone: Zone, matrix: Matrix) {
                 self.zone = zone;
+                self.matrix = matrix;
+            }
+
+            fn set_state(&mut self, state: State) {
+                self.state = state;
+            }
+
+            fn widget_mut(&mut self) -> &mut dyn Widget {
+                &mut *self.widget
             }
         }
         
-        impl<T: Widget + 'static> Widget for #struct_ident<T> {
+        impl #generics Widget for #struct_ident #ty {
             fn build(&self) -> Box<dyn Widget> {
                 self.widget.build()
             }
@@ -62,9 +82,41 @@ pub fn controller(input: TokenStream) -> TokenStream {
             fn style_mut(&mut self, theme: &Theme) -> &mut Style {
                 self.widget.style_mut(theme)
             }
+
+            fn transform(&self, zone: Zone) -> Matrix {
+                self.widget.transform(zone)
+            }
+
+            fn state(&self) -> State {
+                self.state
+            }
+
+            fn as_controller_mut(&mut self) -> Option<&mut dyn Controller> {
+                Some(self)
+            }
+
+            fn wrapped(&self) -> Option<&dyn Widget> {
+                self.widget.wrapped()
+            }
+
+            fn wrapped_mut(&mut self) -> Option<&mut Box<dyn Widget>> {
+                self.widget.wrapped_mut()
+            }
+
+            fn id_count(&self) -> usize {
+                self.widget.id_count()
+            }
+
+            fn children(&self, size: Size, theme: &Theme, child: &mut dyn FnMut(Child)) {
+                self.widget.children(size, theme, child)
+            }
+
+            fn children_mut(&mut self, child: &mut dyn FnMut(usize, &mut Box<dyn Widget>)) {
+                self.widget.children_mut(child)
+            }
         }
 
-        impl<T: Widget + 'static> ToAny for #struct_ident<T> {
+        impl #generics ToAny for #struct_ident #ty {
             fn as_any(&self) -> &dyn std::any::Any {
                 self.widget.as_any()
             }
@@ -74,15 +126,15 @@ pub fn controller(input: TokenStream) -> TokenStream {
             }
         }
         
-        impl<T: Widget + 'static> From<#struct_ident<T>> for Box<dyn Widget> {
-            fn from(value: #struct_ident<T>) -> Self {
+        impl #generics From<#struct_ident #ty> for Box<dyn Widget> {
+            fn from(value: #struct_ident #ty) -> Self {
                 Box::new(value)
             }
         }
         
-        impl<T: Widget> DebugWidget for #struct_ident<T> {}      
+        impl #generics DebugWidget for #struct_ident #ty {}      
     
-        impl<T: Widget> std::fmt::Debug for #struct_ident<T> {
+        impl #generics std::fmt::Debug for #struct_ident #ty {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 write!(f, "[instance of a type of controller]")
             }
@@ -4,6 +4,7 @@
 
 use crate::{
     graphics::{ colours::RGBA, Point, Size, }, 
+    themes::{Spacing, SpacingScale},
     Zone, 
     Side
 };
@@ -13,9 +14,11 @@ use crate::{
 /// Borders are more commonly use in arrays like `[Border; 4]` to define a
 /// border on each side of a shape.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Border {
-    /// The width of the border.
-    pub width: usize,
+    /// The width of the border, in pixels or referencing the spacing scale of 
+    /// the theme.
+    pub width: Spacing,
     /// The colour of the border.
     pub colour: RGBA,
     /// On which side is this border.
@@ -24,12 +27,30 @@ pub struct Border {
 
 impl Border {
     /// Creates a new sized and coloured border.
-    pub fn new(width: usize, colour: RGBA, side: Side) -> Self {
-        Self { width, colour, side }
+    pub fn new<T: Into<Spacing>>(width: T, colour: RGBA, side: Side) -> Self {
+        Self { 
+            width: width.into(), 
+            colour, 
+            side,
+        }
+    }
+
+    /// Returns the border with a width in pixels, following the spacing scale
+    /// when the width is a token.
+    pub fn resolve(&self, scale: &SpacingScale) -> Border {
+        Border {
+            width: Spacing::Pixels(self.width.resolve(scale)),
+            ..self.clone()
+        }
     }
 
     /// Creates a zone of the border's size and width or height of the parent.
-    pub fn as_zone(&self, parent_zone: &Zone) -> Zone {
+    /// 
+    /// A width referencing a token follows the spacing scale, which should be
+    /// the one of the theme.
+    pub fn as_zone(&self, parent_zone: &Zone, scale: &SpacingScale) -> Zone {
+        let width = self.width.resolve(scale);
+
         Zone {
             position: Point::from(match self.side {
                 Side::Left => [parent_zone.x(), parent_zone.y()],
@@ -38,8 +59,8 @@ impl Border {
                 Side::Top => [parent_zone.x(), parent_zone.y()],
             }),
             size: Size::from(match self.side {
-                Side::Left | Side::Right => [self.width, parent_zone.height()],
-                Side::Top | Side::Bottom => [parent_zone.width(), self.width],
+                Side::Left | Side::Right => [width, parent_zone.height()],
+                Side::Top | Side::Bottom => [parent_zone.width(), width],
             }),
         }
     }
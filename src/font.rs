@@ -6,6 +6,7 @@ use std::collections::HashMap;
 
 /// Local path for a font file, to be associated to a name.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Font {
     /// Name for the font. Used to identify it.
     pub name: String,
@@ -16,6 +17,7 @@ pub struct Font {
 /// Named collection of [`Font`s](Font) where each font is associated to a 
 /// [`FontWeight`].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FontFamily {
     /// Name for the font family. Used to identify it.
     pub name: String,
@@ -25,7 +27,8 @@ pub struct FontFamily {
 
 /// Font weight to be associated to a [`Font`] in a [`FontFamily`].
 #[allow(missing_docs)]
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontWeight {
     Black = 900,
     ExtraBold = 800,
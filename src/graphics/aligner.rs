@@ -2,7 +2,7 @@
 // Under the MIT License
 // Copyright (c) 2023 Antonin Hérault
 
-use crate::{widgets::Layout, Align, Direction};
+use crate::{themes::SpacingScale, widgets::Layout, Align, Direction};
 
 use super::{Point, Size};
 
@@ -11,10 +11,15 @@ use super::{Point, Size};
 /// 
 /// Does not calculate the positions of the sub-widgets (widgets of the 
 /// layout's widgets) !
+/// 
+/// The widgets are aligned inside the padding of the layout, and separated by
+/// its gap.
 #[derive(Debug)]
 pub struct Aligner<'a> {
     layout: &'a Layout,
     sizes: Vec<Size>,
+    /// Resolves the padding and the gap of the layout referencing tokens.
+    spacing: SpacingScale,
 }
 
 impl<'a> Aligner<'a> {
@@ -26,6 +31,16 @@ impl<'a> Aligner<'a> {
         Self {
             layout,
             sizes,
+            spacing: SpacingScale::default(),
+        }
+    }
+
+    /// Returns the aligner resolving the padding and the gap of the layout 
+    /// with the spacing scale of a theme, instead of the default one.
+    pub fn with_spacing(self, spacing: SpacingScale) -> Self {
+        Self {
+            spacing,
+            ..self
         }
     }
 
@@ -36,8 +51,15 @@ impl<'a> Aligner<'a> {
             return vec![];
         }
 
+        // The widgets are placed inside the padding.
+        let [top, right, bottom, left] = self.layout.padding.resolve(&self.spacing);
+        let gap = self.layout.gap.resolve(&self.spacing) as isize;
+
         // The first size is always the layout's size.
-        let zone_size: Size = self.sizes[0];
+        let zone_size: Size = [
+            self.sizes[0][0].saturating_sub(left + right),
+            self.sizes[0][1].saturating_sub(top + bottom),
+        ];
 
         let widths: Vec<usize> = self.sizes[1..]
             .iter()
@@ -54,16 +76,26 @@ impl<'a> Aligner<'a> {
         // the zone.
         let mut positions: Vec<Point> = vec![zone];
 
+        // The widgets are placed from the inside of the padding.
+        let origin: Point = [zone[0] + left as isize, zone[1] + top as isize];
+
+        // Number of widgets to place.
+        let count = widths.len() as isize;
+        // The space taken by the gaps between the widgets.
+        let gaps = gap * (count - 1);
+
         for (i, size) in self.sizes[1..].iter().enumerate() {
-            // Total of widths of the widgets already placed.
-            let offset_width = widths[..i].iter().sum::<usize>() as isize;
-            // Total of heights of the widgets already placed.
-            let offset_height = heights[..i].iter().sum::<usize>() as isize;
+            // Total of widths of the widgets already placed, with their gaps.
+            let offset_width = widths[..i].iter().sum::<usize>() as isize + gap * i as isize;
+            // Total of heights of the widgets already placed, with their gaps.
+            let offset_height = heights[..i].iter().sum::<usize>() as isize + gap * i as isize;
 
-            // Total of widths of the widgets which are not already placed.
-            let setoff_width = widths[i..].iter().sum::<usize>() as isize;
-            // Total of heights of the widgets which are already placed.
-            let setoff_height = heights[i..].iter().sum::<usize>() as isize;
+            // Total of widths of the widgets which are not already placed, 
+            // with their gaps.
+            let setoff_width = widths[i..].iter().sum::<usize>() as isize + gap * (count - 1 - i as isize);
+            // Total of heights of the widgets which are already placed, with 
+            // their gaps.
+            let setoff_height = heights[i..].iter().sum::<usize>() as isize + gap * (count - 1 - i as isize);
 
             let (x, y) = match self.layout.direction {
                 Direction::Column => (
@@ -71,7 +103,7 @@ impl<'a> Aligner<'a> {
                         Align::Left => offset_width,
                         Align::Center => {
                             // Both the width of the remaining space at left and right. 
-                            let space: isize = (zone_size[0] as isize - widths.iter().sum::<usize>() as isize) / 2;
+                            let space: isize = (zone_size[0] as isize - widths.iter().sum::<usize>() as isize - gaps) / 2;
 
                             if i == 0 {
                                 // First widget placed
@@ -101,7 +133,7 @@ impl<'a> Aligner<'a> {
                         Align::Top => offset_height,
                         Align::Center => {
                             // Both the height of the remaining space at top and bottom. 
-                            let space: isize = (zone_size[1] as isize - heights.iter().sum::<usize>() as isize) / 2;
+                            let space: isize = (zone_size[1] as isize - heights.iter().sum::<usize>() as isize - gaps) / 2;
 
                             if i == 0 {
                                 // First widget placed
@@ -116,7 +148,7 @@ impl<'a> Aligner<'a> {
                 )
             };
 
-            positions.push(Point::from([zone[0] + x, zone[1] + y]));
+            positions.push(Point::from([origin[0] + x, origin[1] + y]));
         }
 
         positions
@@ -2,9 +2,33 @@
 // Under the MIT License
 // Copyright (c) 2023 Antonin Hérault
 
-//! Structures to store and convert hexadecimal to RGBA colour values, and
-//! colour constants.
+//! Structures to store, parse, convert and blend colours, and colour
+//! constants.
+//!
+//! Colours are stored as [`RGBA`] with 8-bit channels. They can be converted
+//! to float channels with [`RGBAf`], to [`HSL`] and to [`HSV`] in order to do
+//! calculations.
+//!
+//! ## Example
+//! ```rust
+//! use haussmann::graphics::colours::{RGBA, HSL};
+//!
+//! let colour: RGBA = "#ff8000".parse().unwrap();
+//!
+//! assert_eq!(colour, "rgb(255, 128, 0)".parse().unwrap());
+//! assert_eq!(colour.to_hex(), 0xff8000ff);
+//! assert_eq!(RGBA::from(HSL::from(colour)), colour);
+//! ```
 
+use std::{fmt, str::FromStr};
+
+/// Black colour constant (alpha = 255).
+pub const BLACK: RGBA = RGBA {
+    r: 0,
+    g: 0,
+    b: 0,
+    a: 255,
+};
 /// Blue colour constant (alpha = 255).
 pub const BLUE: RGBA = RGBA {
     r: 0,
@@ -33,30 +57,53 @@ pub const TRANSPARENT: RGBA = RGBA {
     b: 0,
     a: 0,
 };
+/// White colour constant (alpha = 255).
+pub const WHITE: RGBA = RGBA {
+    r: 255,
+    g: 255,
+    b: 255,
+    a: 255,
+};
+
+/// Named colours understood when parsing a colour string.
+///
+/// These are the colours from the CSS level 2 specification.
+const NAMED: [(&str, u32); 18] = [
+    ("aqua", 0x00ffffff),
+    ("black", 0x000000ff),
+    ("blue", 0x0000ffff),
+    ("fuchsia", 0xff00ffff),
+    ("gray", 0x808080ff),
+    ("green", 0x008000ff),
+    ("lime", 0x00ff00ff),
+    ("maroon", 0x800000ff),
+    ("navy", 0x000080ff),
+    ("olive", 0x808000ff),
+    ("orange", 0xffa500ff),
+    ("purple", 0x800080ff),
+    ("red", 0xff0000ff),
+    ("silver", 0xc0c0c0ff),
+    ("teal", 0x008080ff),
+    ("transparent", 0x00000000),
+    ("white", 0xffffffff),
+    ("yellow", 0xffff00ff),
+];
 
 /// Colour with red, green and blue values + an alpha channel.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
 pub struct RGBA {
-    /// Red part of the colour corresponding to the first two bytes of an
+    /// Red part of the colour corresponding to the first two digits of an
     /// hexadecimal colour value.
-    ///
-    /// Must be in range from 0 to 255.
-    pub r: u32,
-    /// Green part of the colour corresponding to the third and fourth bytes of
+    pub r: u8,
+    /// Green part of the colour corresponding to the third and fourth digits
+    /// of an hexadecimal colour value.
+    pub g: u8,
+    /// Blue part of the colour corresponding to the fifth and sixth digits of
     /// an hexadecimal colour value.
-    ///
-    /// Must be in range from 0 to 255.
-    pub g: u32,
-    /// Blue part of the colour corresponding to the fifth and sixth bytes of an
-    /// hexadecimal colour value.
-    ///
-    /// Must be in range from 0 to 255.
-    pub b: u32,
-    /// Alpha part of the colour corresponding to the seventh and eighth bytes
+    pub b: u8,
+    /// Alpha part of the colour corresponding to the seventh and eighth digits
     /// of an hexadecimal colour value.
-    ///
-    /// Must be in range from 0 to 255.
-    pub a: u32,
+    pub a: u8,
 }
 
 impl RGBA {
@@ -65,30 +112,446 @@ impl RGBA {
     /// `r`, `g`, `b` and `a` are in range 0 to 255, where 0 is the non-colour
     /// (black) and 255 the full colour (red, green or blue). For `a` being the
     /// alpha channel, 0 transparent and 255 is completely visible.
-    pub fn new(r: u32, g: u32, b: u32, a: u32) -> Self {
+    pub fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
         Self { r, g, b, a }
     }
 
     /// Creates a new `RGBA` object from an hexadecimal value having an alpha
-    /// channel.
-    ///
-    /// Converts the hexadecimal `value` into a "RGBA" structure.
+    /// channel, written `0xRRGGBBAA`.
     pub fn from_hex(value: u32) -> Self {
         Self {
-            r: ((value >> 24) & 0xFF) / 255, // RR byte
-            g: ((value >> 16) & 0xFF) / 255, // GG byte
-            b: ((value >> 8) & 0xFF) / 255,  // BB byte
-            a: ((value) & 0xFF) / 255,       // AA byte
+            r: (value >> 24) as u8, // RR byte
+            g: (value >> 16) as u8, // GG byte
+            b: (value >> 8) as u8,  // BB byte
+            a: value as u8,         // AA byte
         }
     }
 
-    /// Converts the `RGBA` values into an hexadecimal value.
+    /// Converts the `RGBA` values into an hexadecimal value written
+    /// `0xRRGGBBAA`, the same way [`Self::from_hex`] reads it.
     pub fn to_hex(&self) -> u32 {
-        (self.a << 24) | (self.b << 16) | (self.g << 8) | self.r
+        (self.r as u32) << 24 | (self.g as u32) << 16 | (self.b as u32) << 8 | self.a as u32
+    }
+
+    /// Returns the same colour with another alpha channel.
+    pub fn with_alpha(&self, a: u8) -> Self {
+        Self { a, ..*self }
+    }
+
+    /// Lightens the colour by increasing its lightness by `amount`, in range
+    /// 0 to 1.
+    pub fn lighten(&self, amount: f32) -> Self {
+        let mut hsl = HSL::from(*self);
+        hsl.l = (hsl.l + amount).clamp(0.0, 1.0);
+        hsl.into()
+    }
+
+    /// Darkens the colour by decreasing its lightness by `amount`, in range
+    /// 0 to 1.
+    pub fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Mixes the colour with another one, channel by channel. A `weight` of 0
+    /// returns this colour, a `weight` of 1 returns the other one.
+    pub fn mix(&self, other: RGBA, weight: f32) -> Self {
+        let (from, to) = (RGBAf::from(*self), RGBAf::from(other));
+        let weight = weight.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + (b - a) * weight;
+
+        RGBAf {
+            r: mix(from.r, to.r),
+            g: mix(from.g, to.g),
+            b: mix(from.b, to.b),
+            a: mix(from.a, to.a),
+        }
+        .into()
+    }
+
+    /// Draws this colour over the `background` colour, following its alpha
+    /// channel ("source over" compositing).
+    pub fn over(&self, background: RGBA) -> Self {
+        let (src, dst) = (RGBAf::from(*self), RGBAf::from(background));
+        let a = src.a + dst.a * (1.0 - src.a);
+
+        if a <= 0.0 {
+            return TRANSPARENT;
+        }
+
+        let blend = |s: f32, d: f32| (s * src.a + d * dst.a * (1.0 - src.a)) / a;
+
+        RGBAf {
+            r: blend(src.r, dst.r),
+            g: blend(src.g, dst.g),
+            b: blend(src.b, dst.b),
+            a,
+        }
+        .into()
+    }
+
+    /// Returns the relative luminance of the colour as defined by the WCAG,
+    /// from 0 (black) to 1 (white). The alpha channel is ignored.
+    pub fn luminance(&self) -> f32 {
+        let linear = |channel: u8| {
+            let c = channel as f32 / 255.0;
+
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// Returns the contrast ratio between two colours as defined by the WCAG,
+    /// from 1 (no contrast) to 21 (black on white).
+    pub fn contrast_ratio(&self, other: RGBA) -> f32 {
+        let (l1, l2) = (self.luminance(), other.luminance());
+        let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Whether the contrast between the two colours is enough for normal
+    /// text, following the WCAG AA level (ratio of at least 4.5).
+    pub fn is_readable_on(&self, background: RGBA) -> bool {
+        self.contrast_ratio(background) >= 4.5
+    }
+}
+
+impl fmt::Display for RGBA {
+    /// Writes the colour as `#rrggbbaa`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:08x}", self.to_hex())
+    }
+}
+
+/// Colours are serialized as `#rrggbbaa` strings, and deserialized from any 
+/// string understood by [`RGBA::from_str`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for RGBA {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RGBA {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl FromStr for RGBA {
+    type Err = ParseColourError;
+
+    /// Parses a colour written like in CSS: `#rgb`, `#rgba`, `#rrggbb`,
+    /// `#rrggbbaa`, `rgb(r, g, b)`, `rgba(r, g, b, a)`, `hsl(h, s%, l%)`,
+    /// `hsla(h, s%, l%, a)` or a named colour such as `red`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+
+        if let Some(digits) = s.strip_prefix('#') {
+            return parse_hex(digits);
+        }
+
+        if let Some((name, args)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+            let args: Vec<&str> = args
+                .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+                .filter(|arg| !arg.is_empty())
+                .collect();
+
+            return match name.trim() {
+                "rgb" | "rgba" => parse_rgb(&args),
+                "hsl" | "hsla" => parse_hsl(&args),
+                name => Err(ParseColourError::UnknownFunction(name.to_string())),
+            };
+        }
+
+        NAMED
+            .iter()
+            .find(|(name, _)| *name == s)
+            .map(|(_, hex)| RGBA::from_hex(*hex))
+            .ok_or(ParseColourError::UnknownName(s))
+    }
+}
+
+/// Parses the digits of an hexadecimal colour written without `#`.
+fn parse_hex(digits: &str) -> Result<RGBA, ParseColourError> {
+    let invalid = || ParseColourError::InvalidHex(digits.to_string());
+
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+
+    let value = u32::from_str_radix(digits, 16).map_err(|_| invalid())?;
+    // Doubles a digit, as `#f80` is `#ff8800`.
+    let double = |digit: u32| (digit << 4 | digit) as u8;
+
+    match digits.len() {
+        3 => Ok(RGBA::new(double(value >> 8 & 0xf), double(value >> 4 & 0xf), double(value & 0xf), 255)),
+        4 => Ok(RGBA::new(double(value >> 12 & 0xf), double(value >> 8 & 0xf), double(value >> 4 & 0xf), double(value & 0xf))),
+        6 => Ok(RGBA::from_hex(value << 8 | 0xff)),
+        8 => Ok(RGBA::from_hex(value)),
+        _ => Err(invalid()),
+    }
+}
+
+/// Parses the arguments of `rgb()` and `rgba()`.
+fn parse_rgb(args: &[&str]) -> Result<RGBA, ParseColourError> {
+    if args.len() != 3 && args.len() != 4 {
+        return Err(ParseColourError::ArgumentCount(args.len()));
+    }
+
+    let channel = |arg: &str| -> Result<u8, ParseColourError> {
+        let value = match arg.strip_suffix('%') {
+            Some(percentage) => parse_number(percentage)? * 255.0 / 100.0,
+            None => parse_number(arg)?,
+        };
+
+        Ok(value.round().clamp(0.0, 255.0) as u8)
+    };
+
+    Ok(RGBA::new(
+        channel(args[0])?,
+        channel(args[1])?,
+        channel(args[2])?,
+        parse_alpha(args.get(3))?,
+    ))
+}
+
+/// Parses the arguments of `hsl()` and `hsla()`.
+fn parse_hsl(args: &[&str]) -> Result<RGBA, ParseColourError> {
+    if args.len() != 3 && args.len() != 4 {
+        return Err(ParseColourError::ArgumentCount(args.len()));
+    }
+
+    let percentage = |arg: &str| -> Result<f32, ParseColourError> {
+        let value = parse_number(arg.strip_suffix('%').unwrap_or(arg))?;
+        Ok((value / 100.0).clamp(0.0, 1.0))
+    };
+
+    let hue = parse_number(args[0].strip_suffix("deg").unwrap_or(args[0]))?;
+
+    Ok(HSL {
+        h: hue,
+        s: percentage(args[1])?,
+        l: percentage(args[2])?,
+        a: parse_alpha(args.get(3))? as f32 / 255.0,
+    }
+    .into())
+}
+
+/// Parses an alpha channel written as a number from 0 to 1 or as a
+/// percentage. No alpha channel means a fully visible colour.
+fn parse_alpha(arg: Option<&&str>) -> Result<u8, ParseColourError> {
+    let alpha = match arg {
+        Some(arg) => match arg.strip_suffix('%') {
+            Some(percentage) => parse_number(percentage)? / 100.0,
+            None => parse_number(arg)?,
+        },
+        None => 1.0,
+    };
+
+    Ok((alpha.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+/// Parses a number argument of a colour function.
+fn parse_number(arg: &str) -> Result<f32, ParseColourError> {
+    arg.parse::<f32>()
+        .map_err(|_| ParseColourError::InvalidNumber(arg.to_string()))
+}
+
+/// Error returned when a colour string cannot be parsed into a [`RGBA`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseColourError {
+    /// The hexadecimal colour does not have 3, 4, 6 or 8 hexadecimal digits.
+    InvalidHex(String),
+    /// A colour function argument is not a number.
+    InvalidNumber(String),
+    /// A colour function does not have 3 or 4 arguments.
+    ArgumentCount(usize),
+    /// The colour function is not `rgb`, `rgba`, `hsl` or `hsla`.
+    UnknownFunction(String),
+    /// The colour name is not known.
+    UnknownName(String),
+}
+
+impl fmt::Display for ParseColourError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHex(digits) => write!(f, "invalid hexadecimal colour `#{}`", digits),
+            Self::InvalidNumber(arg) => write!(f, "invalid number `{}` in colour function", arg),
+            Self::ArgumentCount(count) => write!(f, "colour function takes 3 or 4 arguments but {} were given", count),
+            Self::UnknownFunction(name) => write!(f, "unknown colour function `{}`", name),
+            Self::UnknownName(name) => write!(f, "unknown colour name `{}`", name),
+        }
+    }
+}
+
+impl std::error::Error for ParseColourError {}
+
+/// Colour with float channels in range 0 to 1, used for calculations.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct RGBAf {
+    /// Red channel.
+    pub r: f32,
+    /// Green channel.
+    pub g: f32,
+    /// Blue channel.
+    pub b: f32,
+    /// Alpha channel, 0 is transparent and 1 is completely visible.
+    pub a: f32,
+}
+
+impl From<RGBA> for RGBAf {
+    fn from(value: RGBA) -> Self {
+        Self {
+            r: value.r as f32 / 255.0,
+            g: value.g as f32 / 255.0,
+            b: value.b as f32 / 255.0,
+            a: value.a as f32 / 255.0,
+        }
+    }
+}
+
+impl From<RGBAf> for RGBA {
+    fn from(value: RGBAf) -> Self {
+        let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        Self {
+            r: channel(value.r),
+            g: channel(value.g),
+            b: channel(value.b),
+            a: channel(value.a),
+        }
+    }
+}
+
+/// Colour defined by its hue, saturation and lightness + an alpha channel.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct HSL {
+    /// Hue in degrees, from 0 to 360.
+    pub h: f32,
+    /// Saturation from 0 (grey) to 1 (full colour).
+    pub s: f32,
+    /// Lightness from 0 (black) to 1 (white).
+    pub l: f32,
+    /// Alpha channel, 0 is transparent and 1 is completely visible.
+    pub a: f32,
+}
+
+impl From<RGBA> for HSL {
+    fn from(value: RGBA) -> Self {
+        let RGBAf { r, g, b, a } = value.into();
+        let (h, max, delta) = hue(r, g, b);
+        let min = max - delta;
+        let l = (max + min) / 2.0;
+
+        let s = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+
+        Self { h, s, l, a }
+    }
+}
+
+impl From<HSL> for RGBA {
+    fn from(value: HSL) -> Self {
+        let chroma = (1.0 - (2.0 * value.l - 1.0).abs()) * value.s;
+        let (r, g, b) = from_hue(value.h, chroma);
+        let m = value.l - chroma / 2.0;
+
+        RGBAf {
+            r: r + m,
+            g: g + m,
+            b: b + m,
+            a: value.a,
+        }
+        .into()
+    }
+}
+
+/// Colour defined by its hue, saturation and value + an alpha channel.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct HSV {
+    /// Hue in degrees, from 0 to 360.
+    pub h: f32,
+    /// Saturation from 0 (grey) to 1 (full colour).
+    pub s: f32,
+    /// Value from 0 (black) to 1 (full brightness).
+    pub v: f32,
+    /// Alpha channel, 0 is transparent and 1 is completely visible.
+    pub a: f32,
+}
+
+impl From<RGBA> for HSV {
+    fn from(value: RGBA) -> Self {
+        let RGBAf { r, g, b, a } = value.into();
+        let (h, max, delta) = hue(r, g, b);
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+
+        Self { h, s, v: max, a }
+    }
+}
+
+impl From<HSV> for RGBA {
+    fn from(value: HSV) -> Self {
+        let chroma = value.v * value.s;
+        let (r, g, b) = from_hue(value.h, chroma);
+        let m = value.v - chroma;
+
+        RGBAf {
+            r: r + m,
+            g: g + m,
+            b: b + m,
+            a: value.a,
+        }
+        .into()
+    }
+}
+
+/// Returns the hue in degrees, the maximum channel and the difference between
+/// the maximum and minimum channels of a colour.
+fn hue(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    (h, max, delta)
+}
+
+/// Returns the red, green and blue channels for a hue in degrees and a chroma,
+/// without the lightness or value adjustment.
+fn from_hue(h: f32, chroma: f32) -> (f32, f32, f32) {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+
+    match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
     }
 }
 
-/// Creates a new [`RGBA`] object. The alpha channel is explicitly written with 
+/// Creates a new [`RGBA`] object. The alpha channel is explicitly written with
 /// `a:`.
 #[macro_export]
 macro_rules! rgba {
@@ -97,7 +560,8 @@ macro_rules! rgba {
     };
 }
 
-/// Converts an hexadecimal colour value into a [`RGBA`] object.
+/// Converts an hexadecimal colour value written `0xRRGGBBAA` into a [`RGBA`]
+/// object.
 #[macro_export]
 macro_rules! hex {
     ($colour:expr) => {
@@ -4,28 +4,53 @@
 
 //! Everything related to drawing of widgets.
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use crate::{
     graphics::{Aligner, Sizer},
-    widgets::{Image, Label, Layout, Surface , View}, 
+    themes::{Component, LabelStyle, State, Style, Theme},
+    widgets::{Canvas, DisplayList, Icon, Image, Label, Layout, Path, Popup, Stack, Surface, View, unwrapped}, 
+    Overflow,
     Widget, 
     Zone, 
 };
 
-use super::{Size, Point};
+use super::{Matrix, Size, Point};
+
+/// Generation of the last created [`Builder`].
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
 
 /// The object to draw from a [`Drawable`].
 #[derive(Debug)]
 pub enum Object {
+    /// Wraps the commands recorded by a [`Canvas`] in order to draw them.
+    Canvas(DisplayList),
+    /// Wraps an [`Icon`] in order to draw it.
+    Icon(Icon),
     /// Wraps an [`Image`] in order to draw it.
     Image(Image),
     /// Wraps an [`Label`] in order to draw it.
     Label(Label),
+    /// Wraps a [`Path`] in order to draw it.
+    Path(Path),
     /// Wraps an [`Surface`] in order to draw it.
     Surface(Surface),
     /// Wraps a boxed dynamic [`Widget`] in order to draw it.
     Unknown(Box<dyn Widget>),
 }
 
+impl From<DisplayList> for Object {
+    fn from(value: DisplayList) -> Self {
+        Self::Canvas(value)
+    }
+}
+
+impl From<Icon> for Object {
+    fn from(value: Icon) -> Self {
+        Self::Icon(value)
+    }
+}
+
 impl From<Image> for Object {
     fn from(value: Image) -> Self {
         Self::Image(value)
@@ -38,6 +63,12 @@ impl From<Label> for Object {
     }
 }
 
+impl From<Path> for Object {
+    fn from(value: Path) -> Self {
+        Self::Path(value)
+    }
+}
+
 impl From<Surface> for Object {
     fn from(value: Surface) -> Self {
         Self::Surface(value)
@@ -61,6 +92,27 @@ pub struct Drawable {
     pub group_id: i32,
     /// The zone covered by the drawable.
     pub zone: Zone,
+    /// The transformation to apply on the zone when drawing, composed from 
+    /// the transformations of the widget and its parents.
+    pub transform: Matrix,
+    /// The interaction state of the widget which built the object, tracked 
+    /// by its controller.
+    pub state: State,
+    /// Index of the drawable of the layout containing this drawable, in the 
+    /// built drawables. `None` for the drawable of the view's layout.
+    pub parent: Option<usize>,
+    /// The zone out of which the drawable is hidden, following the layouts 
+    /// hiding their overflowing widgets. `None` when it is never hidden.
+    pub clip: Option<Zone>,
+    /// The style of the object resolved with the theme of the view, when the 
+    /// object is an [`Image`] or a [`Surface`].
+    pub style: Option<Style>,
+    /// The style of the object resolved with the theme of the view, when the 
+    /// object is a [`Label`] or an [`Icon`].
+    pub label_style: Option<LabelStyle>,
+    /// The [generation](Builder::generation) of the builder which created the
+    /// drawable. `0` when the drawable was not created by a builder.
+    pub generation: usize,
 }
 
 impl Drawable {
@@ -70,8 +122,47 @@ impl Drawable {
             object: object.into(),
             group_id,
             zone,
+            transform: Matrix::identity(),
+            state: State::Normal,
+            parent: None,
+            clip: None,
+            style: None,
+            label_style: None,
+            generation: 0,
         }
     }
+
+    /// Creates a new drawable like [`Self::new`] but transformed.
+    pub fn transformed<T: Into<Object>>(
+        object: T, 
+        zone: Zone, 
+        group_id: i32, 
+        transform: Matrix
+    ) -> Self {
+        Self {
+            transform,
+            ..Self::new(object, zone, group_id)
+        }
+    }
+
+    /// Resolves the style of the drawn object with a theme, for the 
+    /// interaction state of the drawable.
+    /// 
+    /// Widgets without independent style take their style from the theme, so
+    /// the drawable has to be resolved again when the theme changes.
+    pub fn resolve(&mut self, theme: &Theme) {
+        self.style = match &self.object {
+            Object::Image(image) => Some(image.style_in(theme, self.state)),
+            Object::Surface(surface) => Some(surface.style_in(theme, self.state)),
+            _ => None,
+        };
+
+        self.label_style = match &self.object {
+            Object::Label(label) => Some(label.label_style_in(theme, self.state)),
+            Object::Icon(icon) => Some(icon.label_style_in(theme, self.state)),
+            _ => None,
+        };
+    }
 }
 
 /// Implements a function to retrieve a drawable in a data structure of 
@@ -82,7 +173,7 @@ pub trait DrawableAt {
     fn at(&self, i: usize) -> Option<&Drawable>;
 }
 
-impl DrawableAt for Vec<Drawable> {
+impl DrawableAt for [Drawable] {
     fn at(&self, i: usize) -> Option<&Drawable> {
         self.iter().find(|&drawable| drawable.group_id as usize == i)
     }
@@ -91,38 +182,65 @@ impl DrawableAt for Vec<Drawable> {
 /// Builds drawables for a vector of widgets.
 /// 
 /// The widgets of the layouts found in the vector of widgets are also built.
+/// The styles of the drawables are resolved with the given theme.
 #[derive(Debug)]
-pub struct Builder {
+pub struct Builder<'a> {
     /// Current created drawables.
     pub drawables: Vec<Drawable>,
+    /// Number given to the drawables created by the builder, different for
+    /// each builder.
+    pub generation: usize,
     /// The zone where to build the drawables.
     pub zone: Zone,
+    /// The theme to resolve the styles of the drawables.
+    theme: &'a Theme,
     /// The identifier of the current manipulated widget.
     /// 
     /// It is given to the drawable built by the widget.
     current_id: i32,
     /// The current manipulated zone.
     current_zone: Zone,
+    /// The transformation of the current manipulated widget, composed with 
+    /// the ones of its parents.
+    current_matrix: Matrix,
+    /// The interaction state of the current manipulated widget, inherited by
+    /// the widgets it contains.
+    current_state: State,
+    /// Index of the drawable of the current manipulated layout.
+    current_parent: Option<usize>,
+    /// The zone out of which the current manipulated widget is hidden.
+    current_clip: Option<Zone>,
 }
 
-impl Builder {
+impl<'a> Builder<'a> {
     /// Creates a new drawables builder for a vector of widgets. 
-    pub fn new(zone: Zone) -> Self {
+    pub fn new(zone: Zone, theme: &'a Theme) -> Self {
         Self {
             zone,
+            theme,
             drawables: vec![],
+            generation: GENERATION.fetch_add(1, Ordering::Relaxed) + 1,
             current_id: 0,
             current_zone: zone,
+            current_matrix: Matrix::identity(),
+            current_state: State::Normal,
+            current_parent: None,
+            current_clip: None,
         }
     }
 
-    /// Builds the widgets contained in the view's layout.
+    /// Builds the widgets contained in the view's layout, then the popups of
+    /// its overlay over them, then its toast over everything.
     pub fn build_view(&mut self, view: &View) {
         self.build_layout(&view.layout, false);
+
+        for popup in view.overlay().iter().chain(view.toast()) {
+            self.build_popup(popup);
+        }
     }
 }
 
-impl Builder {
+impl<'a> Builder<'a> {
     /// Builds drawables for a layout. Adds the layout's drawable.
     /// 
     /// Recursive when another layout is encountered is the layout's widgets.
@@ -134,12 +252,25 @@ impl Builder {
         // Pushes it to the drawables.
         self.drawables.push(layout_drawable);
 
+        // The layout contains the drawables of its widgets, and hides them 
+        // out of its zone if it has to.
+        let parent = self.current_parent;
+        let clip = self.current_clip;
+
+        self.current_parent = Some(self.drawables.len() - 1);
+
+        if layout.overflow == Overflow::Hide {
+            self.hide_overflow();
+        }
+
         // The size of every widget.
         let sizes: Vec<Size> = Sizer::new(layout)
+            .with_spacing(self.theme.tokens.spacing)
             .size_in(self.current_zone.size);
 
         // The position of every widget.
         let positions: Vec<Point> = Aligner::new(layout, sizes.clone())
+            .with_spacing(self.theme.tokens.spacing)
             .align_at(self.current_zone.position);
 
         // There must be the same number of sizes than positions. 
@@ -155,21 +286,114 @@ impl Builder {
             // Updates the current drawable zone.
             self.current_zone = (positions[i + 1], sizes[i + 1]).into();
 
-            // Checks if it is a layout or a normal widget.
-            if let Some(layout) = widget.as_any().downcast_ref::<Layout>() {
-                // Builds the layout.
-                self.build_layout(layout, false);
-            } else {
-                // Builds drawables for the widget.
-                self.build_widget(widget);
-            }
+            // The layouts nested in a layout built by a widget are part of 
+            // the widget too: they keep its identifier, otherwise the 
+            // widgets after it would not have the identifiers of their 
+            // controllers.
+            self.build_placed(widget.as_ref(), from_built);
         }
+
+        self.current_parent = parent;
+        self.current_clip = clip;
     }
     
+    /// Builds drawables for a popup of the overlay, in its zone in the zone
+    /// of the builder. 
+    /// 
+    /// The popup is built like a widget of the view's layout, but its 
+    /// drawable has no parent and it is never hidden. A modal popup is built
+    /// over a barrier covering the builder's zone, which is the parent of its
+    /// drawable.
+    fn build_popup(&mut self, popup: &Popup) {
+        self.current_parent = None;
+        self.current_clip = None;
+
+        if popup.modal {
+            self.current_id += 1;
+            self.current_zone = self.zone;
+            self.current_matrix = Matrix::identity();
+            self.current_state = State::Normal;
+
+            let barrier = Surface {
+                style: None,
+                component: Component::Barrier,
+            };
+            let barrier_drawable = self.create_drawable(Box::new(barrier));
+            self.drawables.push(barrier_drawable);
+
+            self.current_parent = Some(self.drawables.len() - 1);
+        }
+
+        self.current_id += 1;
+        self.current_zone = popup.zone_in(self.zone);
+        self.current_matrix = popup.widget.transform(self.current_zone);
+        self.current_state = popup.widget.state();
+
+        match unwrapped(popup.widget.as_ref()).as_any().downcast_ref::<Layout>() {
+            Some(layout) => self.build_layout(layout, false),
+            None => self.build_widget(popup.widget.as_ref(), false),
+        }
+    }
+
+    /// Hides the next drawables out of the current zone, and out of the 
+    /// current clip zone.
+    fn hide_overflow(&mut self) {
+        let bounds = self.current_matrix.bounds(self.current_zone);
+
+        self.current_clip = Some(match self.current_clip {
+            Some(clip) => clip.intersection(bounds).unwrap_or(Zone {
+                position: bounds.position,
+                size: [0, 0],
+            }),
+            None => bounds,
+        });
+    }
+
+    /// Builds drawables for a widget placed in the current zone, which can be
+    /// a layout. Its transformation and its state are composed with the ones
+    /// of the widgets containing it.
+    fn build_placed(&mut self, widget: &dyn Widget, from_built: bool) {
+        // Composes the widget's transformation with the layout's one.
+        let parent_matrix = self.current_matrix;
+        self.current_matrix = parent_matrix * widget.transform(self.current_zone);
+
+        // The widget's state replaces the layout's one, unless the widget
+        // is not interacted with.
+        let parent_state = self.current_state;
+        if widget.state() != State::Normal {
+            self.current_state = widget.state();
+        }
+
+        // Checks if it is a layout, a wrapped layout or a normal widget,
+        // the transformation of a wrapped widget is already applied.
+        match unwrapped(widget).as_any().downcast_ref::<Layout>() {
+            Some(layout) => self.build_layout(layout, from_built),
+            None => self.build_widget(widget, from_built),
+        }
+
+        // The next widgets are not affected by this transformation and 
+        // this state.
+        self.current_matrix = parent_matrix;
+        self.current_state = parent_state;
+    }
+
     /// Builds drawables for a widget. Adds new drawables to the `drawables`.
-    fn build_widget(&mut self, widget: &Box<dyn Widget>) {
+    /// 
+    /// When the widget is part of a built widget or of a stack, the widgets it
+    /// contains share its identifier.
+    fn build_widget(&mut self, widget: &dyn Widget, from_built: bool) {
+        // The special widgets are found through the widgets wrapping them.
+        let widget = unwrapped(widget);
+
+        // Checks for the widget to be a stack, its widgets are built over its 
+        // surface.
+        if let Some(stack) = widget.as_any().downcast_ref::<Stack>() {
+            self.build_stack(stack);
+            return;
+        }
+
         // Builds the widget.
-        // The built widget can be a layout.
+        // The built widget can be a layout or a stack.
         let built = widget.build();
 
         // Checks for built widget to be a layout.
@@ -178,6 +402,12 @@ impl Builder {
             self.build_layout(layout, true);
             return;
         }
+
+        // Checks for built widget to be a stack.
+        if let Some(stack) = built.as_any().downcast_ref::<Stack>() {
+            self.build_stack(stack);
+            return;
+        }
         
         // The built widget is not a layout.
 
@@ -185,38 +415,166 @@ impl Builder {
         let drawable = self.create_drawable(built);
         // Adds the drawable of the built widget to the created drawables.
         self.drawables.push(drawable);
+
+        // Builds the children of the widget over its drawable.
+        self.build_children(widget, from_built);
     }
 
-    /// Creates a drawable for the returned widget by [`Widget::build()`].
+    /// Builds drawables for a stack. Adds the stack's drawable, then the 
+    /// drawables of its widgets in their zones.
+    /// 
+    /// The widgets of the stack share the identifier of the stack.
+    fn build_stack(&mut self, stack: &Stack) {
+        let stack_drawable = self.create_drawable(stack.build());
+        self.drawables.push(stack_drawable);
+
+        let parent = self.current_parent;
+        let zone = self.current_zone;
+
+        self.current_parent = Some(self.drawables.len() - 1);
+
+        for (widget_zone, widget) in &stack.widgets {
+            // The zone of the widget is relative to the stack.
+            self.current_zone = match widget_zone {
+                Some(widget_zone) => Zone {
+                    position: [
+                        zone.position[0] + widget_zone.position[0],
+                        zone.position[1] + widget_zone.position[1],
+                    ],
+                    size: widget_zone.size,
+                },
+                None => zone,
+            };
+
+            self.build_placed(widget.as_ref(), true);
+        }
+
+        self.current_zone = zone;
+        self.current_parent = parent;
+    }
+
+    /// Builds drawables for the [children](Widget::children()) of a widget, 
+    /// over the last drawable which is the one of the widget, hidden out of 
+    /// the zone of the widget.
+    /// 
+    /// Each child takes the identifier given by the widget, counted from the
+    /// identifier of the widget, unless the widget is part of a built widget
+    /// or of a stack. The next widgets take the identifiers following the 
+    /// ones counted by the widget.
+    fn build_children(&mut self, widget: &dyn Widget, from_built: bool) {
+        let parent = self.current_parent;
+        let clip = self.current_clip;
+        let zone = self.current_zone;
+        let id = self.current_id;
+        let theme = self.theme;
+
+        self.current_parent = Some(self.drawables.len() - 1);
+        self.hide_overflow();
+
+        widget.children(zone.size, theme, &mut |child| {
+            if !from_built {
+                self.current_id = id + child.id as i32;
+            }
+
+            // The zone of the child is relative to the widget.
+            self.current_zone = Zone {
+                position: [zone.x() + child.zone.x(), zone.y() + child.zone.y()],
+                size: child.zone.size,
+            };
+
+            self.build_placed(child.widget, from_built || child.shared);
+        });
+
+        if !from_built {
+            self.current_id = id + widget.id_count() as i32 - 1;
+        }
+
+        self.current_zone = zone;
+        self.current_parent = parent;
+        self.current_clip = clip;
+    }
+
+    /// Creates a drawable for the returned widget by [`Widget::build()`], with
+    /// a resolved style.
     fn create_drawable(&self, built: Box<dyn Widget>) -> Drawable {
+        let mut drawable = self.create_object_drawable(built);
+        drawable.state = self.current_state;
+        drawable.parent = self.current_parent;
+        drawable.clip = self.current_clip;
+        drawable.generation = self.generation;
+        drawable.resolve(self.theme);
+        drawable
+    }
+
+    /// Creates a drawable for the returned widget by [`Widget::build()`].
+    fn create_object_drawable(&self, built: Box<dyn Widget>) -> Drawable {
+        // The built widget is a canvas, drawn in its zone.
+        if let Some(canvas) = built.as_any().downcast_ref::<Canvas>() {
+            return Drawable::transformed(
+                canvas.record(self.current_zone, self.theme), 
+                self.current_zone, 
+                self.current_id,
+                self.current_matrix,
+            );
+        }
+
+        // The built widget is an icon, as large as its text size.
+        if let Some(icon) = built.as_any().downcast_ref::<Icon>() {
+            return Drawable::transformed(
+                icon.clone(), 
+                Icon::zone_in(self.current_zone, icon.size(self.theme)), 
+                self.current_id,
+                self.current_matrix,
+            );
+        }
+
         // The built widget is an image.
         if let Some(image) = built.as_any().downcast_ref::<Image>() {
-            return Drawable::new(
+            return Drawable::transformed(
                 image.clone(), 
                 self.current_zone, 
-                self.current_id
+                self.current_id,
+                self.current_matrix,
             );
         }
 
         // The built widget is a label.
         if let Some(label) = built.as_any().downcast_ref::<Label>() {
-            return Drawable::new(
+            return Drawable::transformed(
                 label.clone(), 
                 self.current_zone, 
-                self.current_id
+                self.current_id,
+                self.current_matrix,
+            );
+        }
+
+        // The built widget is a path.
+        if let Some(path) = built.as_any().downcast_ref::<Path>() {
+            return Drawable::transformed(
+                path.clone(), 
+                self.current_zone, 
+                self.current_id,
+                self.current_matrix,
             );
         }
 
         // The built widget is a surface.
         if let Some(surface) = built.as_any().downcast_ref::<Surface>() {
-            return Drawable::new(
+            return Drawable::transformed(
                 surface.clone(), 
                 self.current_zone, 
-                self.current_id
+                self.current_id,
+                self.current_matrix,
             );
         }
         
-        // The built widget is not an image, a label nor a surface.
-        Drawable::new(built, self.current_zone, self.current_id)
+        // The built widget is not a canvas, an icon, an image, a label, a path nor
+        // a surface.
+        Drawable::transformed(
+            built, 
+            self.current_zone, 
+            self.current_id, 
+            self.current_matrix
+        )
     }
 }
@@ -0,0 +1,237 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to finding the drawables under a point.
+
+use crate::Zone;
+
+use super::{draw::Drawable, Point, Size};
+
+/// Drawable found under a point, with the drawables of the layouts
+/// containing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hit {
+    /// Index of the drawable in the built drawables.
+    pub index: usize,
+    /// Indexes of the drawables of the layouts containing the drawable, from
+    /// the closest one to the drawable of the view's layout.
+    pub ancestors: Vec<usize>,
+}
+
+impl Hit {
+    /// Returns the index of the drawable followed by the indexes of its
+    /// ancestors.
+    pub fn chain(&self) -> impl Iterator<Item = usize> + '_ {
+        std::iter::once(self.index).chain(self.ancestors.iter().copied())
+    }
+}
+
+/// Whether the point is over the drawable, following its transformation and
+/// its clip zone. The right and bottom edges are not over the drawable, like 
+/// in [`Zone::contains`].
+pub fn is_under(drawable: &Drawable, point: Point) -> bool {
+    if let Some(clip) = drawable.clip {
+        if !clip.contains(point) {
+            return false;
+        }
+    }
+
+    // The point is brought back in the untransformed space of the zone.
+    let [x, y] = match drawable.transform.inverse() {
+        Some(inverse) => inverse.apply([point[0] as f32, point[1] as f32]),
+        None => return false,
+    };
+
+    let zone = drawable.zone;
+
+    x >= zone.x() as f32 && x < (zone.x() + zone.width() as isize) as f32
+        && y >= zone.y() as f32 && y < (zone.y() + zone.height() as isize) as f32
+}
+
+/// Returns the indexes of the drawables of the layouts containing a drawable,
+/// from the closest one.
+pub fn ancestors(drawables: &[Drawable], index: usize) -> Vec<usize> {
+    let mut ancestors = vec![];
+    let mut parent = drawables[index].parent;
+
+    while let Some(index) = parent {
+        ancestors.push(index);
+        parent = drawables[index].parent;
+    }
+
+    ancestors
+}
+
+/// Returns the generation of the builder which created the first and the last
+/// drawables, `0` when they were not created by the same builder.
+fn generation(drawables: &[Drawable]) -> usize {
+    match (drawables.first(), drawables.last()) {
+        (Some(first), Some(last)) if first.generation == last.generation => first.generation,
+        _ => 0,
+    }
+}
+
+/// Spatial index of built drawables, to find the drawables under a point
+/// without checking all of them.
+///
+/// The zone covered by the drawables in the zone of the view is divided in a
+/// grid of square cells, each cell knows the drawables covering it. The index
+/// must be created again when the drawables are built again.
+///
+/// The index knows the drawables it was created for by the
+/// [generation](super::draw::Builder::generation) of their builder and their
+/// number, without going through the drawables again. Drawables changed after
+/// the index was created are not indexed again.
+#[derive(Debug, Clone)]
+pub struct Index {
+    /// Generation of the indexed drawables, `0` when they were not created by
+    /// the same builder.
+    generation: usize,
+    /// Number of indexed drawables.
+    len: usize,
+    /// Top-left position of the grid.
+    origin: Point,
+    /// Size of a cell, in pixels.
+    cell_size: usize,
+    /// Number of cells on the X axis.
+    columns: usize,
+    /// Number of cells on the Y axis.
+    rows: usize,
+    /// Indexes of the drawables covering each cell, in the drawing order.
+    cells: Vec<Vec<usize>>,
+}
+
+impl Index {
+    /// Size of a cell used by [`Index::new`], in pixels.
+    pub const CELL_SIZE: usize = 64;
+
+    /// Creates the index of the drawables in the zone `zone`, the one of the 
+    /// view. Nothing is found out of it.
+    pub fn new(drawables: &[Drawable], zone: Zone) -> Self {
+        Self::with_cell_size(drawables, zone, Self::CELL_SIZE)
+    }
+
+    /// Creates the index of the drawables in the zone `zone` with cells of a 
+    /// given size.
+    ///
+    /// Small cells give less drawables to check for a point, but take more
+    /// time and memory to be created.
+    pub fn with_cell_size(drawables: &[Drawable], zone: Zone, cell_size: usize) -> Self {
+        let cell_size = cell_size.max(1);
+
+        // The visible bounds of each drawable, the grid does not go out of 
+        // the zone however far the drawables are moved.
+        let bounds: Vec<Option<Zone>> = drawables
+            .iter()
+            .map(|drawable| {
+                let bounds = zone.intersection(drawable.transform.bounds(drawable.zone))?;
+
+                match drawable.clip {
+                    Some(clip) => clip.intersection(bounds),
+                    None => Some(bounds),
+                }
+            })
+            .collect();
+
+        // The zone covered by all the drawables.
+        let visible = bounds.iter().flatten();
+        let left = visible.clone().map(|zone| zone.x()).min().unwrap_or(0);
+        let top = visible.clone().map(|zone| zone.y()).min().unwrap_or(0);
+        let right = visible.clone().map(|zone| zone.x() + zone.width() as isize).max().unwrap_or(0);
+        let bottom = visible.map(|zone| zone.y() + zone.height() as isize).max().unwrap_or(0);
+
+        let columns = ((right - left) as usize).div_ceil(cell_size);
+        let rows = ((bottom - top) as usize).div_ceil(cell_size);
+
+        let mut index = Self {
+            generation: generation(drawables),
+            len: drawables.len(),
+            origin: Point::from([left, top]),
+            cell_size,
+            columns,
+            rows,
+            cells: vec![vec![]; columns * rows],
+        };
+
+        for (i, zone) in bounds.iter().enumerate() {
+            let zone = match zone {
+                Some(zone) if zone.width() > 0 && zone.height() > 0 => zone,
+                _ => continue,
+            };
+
+            let [first_column, first_row] = index.cell_at(zone.position);
+            let [last_column, last_row] = index.cell_at([
+                zone.x() + zone.width() as isize - 1,
+                zone.y() + zone.height() as isize - 1,
+            ]);
+
+            for row in first_row..=last_row {
+                for column in first_column..=last_column {
+                    index.cells[row * columns + column].push(i);
+                }
+            }
+        }
+
+        index
+    }
+
+    /// Returns the column and the row of the cell at a point of the grid.
+    fn cell_at(&self, point: Point) -> Size {
+        Size::from([
+            (point[0] - self.origin[0]) as usize / self.cell_size,
+            (point[1] - self.origin[1]) as usize / self.cell_size,
+        ])
+    }
+
+    /// Whether the index was created for the drawables: they were created by
+    /// the same builder, with as many drawables.
+    pub fn is_for(&self, drawables: &[Drawable]) -> bool {
+        self.generation != 0
+            && self.len == drawables.len()
+            && generation(drawables) == self.generation
+    }
+
+    /// Returns the indexes of the drawables which may be under the point, in
+    /// the drawing order.
+    pub fn candidates(&self, point: Point) -> &[usize] {
+        if point[0] < self.origin[0] || point[1] < self.origin[1] {
+            return &[];
+        }
+
+        let [column, row] = self.cell_at(point);
+
+        if column >= self.columns || row >= self.rows {
+            return &[];
+        }
+
+        &self.cells[row * self.columns + column]
+    }
+
+    /// Returns the indexes of all the drawables under the point, from the
+    /// topmost one.
+    ///
+    /// A drawable built after another one is drawn over it.
+    pub fn hits(&self, drawables: &[Drawable], point: Point) -> Vec<usize> {
+        self.candidates(point)
+            .iter()
+            .rev()
+            .copied()
+            .filter(|&i| is_under(&drawables[i], point))
+            .collect()
+    }
+
+    /// Returns the topmost drawable under the point, with its ancestors.
+    pub fn hit(&self, drawables: &[Drawable], point: Point) -> Option<Hit> {
+        let index = self.candidates(point)
+            .iter()
+            .rev()
+            .copied()
+            .find(|&i| is_under(&drawables[i], point))?;
+
+        Some(Hit {
+            index,
+            ancestors: ancestors(drawables, index),
+        })
+    }
+}
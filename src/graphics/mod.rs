@@ -10,10 +10,13 @@ use linbra::vector::Vector;
 mod aligner;
 pub mod colours;
 pub mod draw;
+pub mod hit;
 mod sizer;
+pub mod transform;
 
 pub use aligner::Aligner;
 pub use sizer::Sizer;
+pub use transform::{Matrix, Transform};
 
 /// Point on a 2D surface of coordinates `[x, y]` being `isize` values.
 pub type Point = Vector<isize, 2>;
@@ -2,7 +2,7 @@
 // Under the MIT License
 // Copyright (c) 2023 Antonin Hérault
 
-use crate::{widgets::{Container, Layout}, Direction};
+use crate::{themes::SpacingScale, widgets::{Container, Layout, Weighted}, Direction};
 
 use super::Size;
 
@@ -11,9 +11,14 @@ use super::Size;
 /// 
 /// Does not calculate the sizes of the sub-widgets (widgets of the layout's 
 /// widgets) !
+/// 
+/// The padding and the gap of the layout are taken off the space shared by 
+/// the widgets.
 #[derive(Debug)]
 pub struct Sizer<'a> {
     layout: &'a Layout,
+    /// Resolves the padding and the gap of the layout referencing tokens.
+    spacing: SpacingScale,
 }
 
 impl<'a> Sizer<'a> {
@@ -21,6 +26,16 @@ impl<'a> Sizer<'a> {
     pub fn new(layout: &'a Layout) -> Self {
         Self {
             layout,
+            spacing: SpacingScale::default(),
+        }
+    }
+
+    /// Returns the sizer resolving the padding and the gap of the layout with
+    /// the spacing scale of a theme, instead of the default one.
+    pub fn with_spacing(self, spacing: SpacingScale) -> Self {
+        Self {
+            spacing,
+            ..self
         }
     }
 
@@ -47,37 +62,43 @@ impl<'a> Sizer<'a> {
         
         // The layout only contains containers.
         if not_containers.is_empty() {
-            // Returns the sizes of the containers.
-            return containers.iter().map(|c| c.size).collect();
+            // Returns the size of the layout, then the sizes of the
+            // containers.
+            return std::iter::once(zone).chain(containers.iter().map(|c| c.size)).collect();
         }
 
         // Checked by the previous condition.
         assert_ne!(not_containers.len(), 0);
 
-        // Each widget which is not a container has the same size.
-        let size_not_container = match self.layout.direction {
-            Direction::Column => [
-                // Shares the width with the other widgets.
-                if size_containers[0] > zone[0] {
-                    // Avoids 'subtract with overflow'
-                    0
-                } else {
-                    (zone[0] - size_containers[0]) / not_containers.len()
-                },
-                // Takes the whole height.
-                zone[1]
-            ],
-            Direction::Row => [
-                // Takes the whole width.
-                zone[0], 
-                // Shares the height with the other widgets.
-                if size_containers[1] > zone[1] {
-                    // Avoids 'subtract with overflow'
-                    0
-                } else {
-                    (zone[1] - size_containers[1]) / not_containers.len()
-                }
-            ],
+        // The zone inside the padding.
+        let [top, right, bottom, left] = self.layout.padding.resolve(&self.spacing);
+        let inner: Size = [
+            zone[0].saturating_sub(left + right), 
+            zone[1].saturating_sub(top + bottom),
+        ];
+
+        // The space taken by the gaps between the widgets.
+        let gaps = self.layout.gap.resolve(&self.spacing) * (self.layout.widgets.len() - 1);
+
+        // The widgets which are not containers share the space left by the 
+        // containers following their weights.
+        let weights: f32 = not_containers.iter().map(|widget| Weighted::of(widget.as_ref())).sum();
+
+        // The shared length, with the whole width or height.
+        // Saturating subtractions avoid 'subtract with overflow'.
+        let (shared, whole) = match self.layout.direction {
+            // Shares the width with the other widgets, takes the whole 
+            // height.
+            Direction::Column => (
+                inner[0].saturating_sub(size_containers[0]).saturating_sub(gaps),
+                inner[1],
+            ),
+            // Shares the height with the other widgets, takes the whole 
+            // width.
+            Direction::Row => (
+                inner[1].saturating_sub(size_containers[1]).saturating_sub(gaps),
+                inner[0],
+            ),
         };
         
         // Creates the sizes in the correct order.
@@ -85,15 +106,22 @@ impl<'a> Sizer<'a> {
         let mut sizes: Vec<Size> = vec![zone];
 
         for widget in &self.layout.widgets {
-            // Containers have their own sizes, other widgets all have the same 
-            // size.
-            match widget.as_any().downcast_ref::<Container>() {
-                Some(container) => {
-                    sizes.push(container.size);
-                    continue;
-                },
-                None => sizes.push(Size::from(size_not_container))
+            // Containers have their own sizes, other widgets have a share of
+            // the space.
+            if let Some(container) = widget.as_any().downcast_ref::<Container>() {
+                sizes.push(container.size);
+                continue;
+            }
+
+            let length = match weights > 0.0 {
+                true => (shared as f32 * Weighted::of(widget.as_ref()) / weights) as usize,
+                false => 0,
             };
+
+            sizes.push(match self.layout.direction {
+                Direction::Column => [length, whole],
+                Direction::Row => [whole, length],
+            });
         }
 
         sizes
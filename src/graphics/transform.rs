@@ -0,0 +1,245 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! 2D transformations (translation, scale and rotation) applied on widgets
+//! after they were sized and aligned.
+
+use std::ops::Mul;
+
+use crate::Zone;
+
+use super::{Point, Size};
+
+/// 2D affine transformation matrix.
+///
+/// Only the six meaningful values of the 3x3 matrix are stored:
+/// ```text
+/// | a c e |
+/// | b d f |
+/// | 0 0 1 |
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Matrix {
+    /// Horizontal scaling.
+    pub a: f32,
+    /// Vertical skewing.
+    pub b: f32,
+    /// Horizontal skewing.
+    pub c: f32,
+    /// Vertical scaling.
+    pub d: f32,
+    /// Horizontal translation.
+    pub e: f32,
+    /// Vertical translation.
+    pub f: f32,
+}
+
+impl Default for Matrix {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Mul for Matrix {
+    type Output = Matrix;
+
+    /// Composes two matrices. The right-hand side matrix is applied first.
+    fn mul(self, rhs: Matrix) -> Self::Output {
+        Matrix {
+            a: self.a * rhs.a + self.c * rhs.b,
+            b: self.b * rhs.a + self.d * rhs.b,
+            c: self.a * rhs.c + self.c * rhs.d,
+            d: self.b * rhs.c + self.d * rhs.d,
+            e: self.a * rhs.e + self.c * rhs.f + self.e,
+            f: self.b * rhs.e + self.d * rhs.f + self.f,
+        }
+    }
+}
+
+impl Matrix {
+    /// Matrix which does not transform anything.
+    pub fn identity() -> Self {
+        Self {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    /// Creates a matrix moving points by `x` and `y`.
+    pub fn translation(x: f32, y: f32) -> Self {
+        Self {
+            e: x,
+            f: y,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a matrix scaling points by `x` and `y` from the origin.
+    pub fn scaling(x: f32, y: f32) -> Self {
+        Self {
+            a: x,
+            d: y,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a matrix rotating points around the origin.
+    ///
+    /// Since the y axis goes down, a positive angle in degrees rotates
+    /// clockwise.
+    pub fn rotation(degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+
+        Self {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    /// Whether the matrix does not transform anything.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+
+    /// Returns the inverse matrix, or `None` when the matrix cannot be
+    /// inverted (a scale of zero for example).
+    pub fn inverse(&self) -> Option<Matrix> {
+        let determinant = self.a * self.d - self.b * self.c;
+
+        if determinant.abs() <= f32::EPSILON {
+            return None;
+        }
+
+        Some(Matrix {
+            a: self.d / determinant,
+            b: -self.b / determinant,
+            c: -self.c / determinant,
+            d: self.a / determinant,
+            e: (self.c * self.f - self.d * self.e) / determinant,
+            f: (self.b * self.e - self.a * self.f) / determinant,
+        })
+    }
+
+    /// Applies the matrix on a point.
+    pub fn apply(&self, point: [f32; 2]) -> [f32; 2] {
+        [
+            self.a * point[0] + self.c * point[1] + self.e,
+            self.b * point[0] + self.d * point[1] + self.f,
+        ]
+    }
+
+    /// Returns the smallest zone containing the transformed zone.
+    ///
+    /// Useful for backends which are not able to draw rotated shapes.
+    pub fn bounds(&self, zone: Zone) -> Zone {
+        let (x, y) = (zone.x() as f32, zone.y() as f32);
+        let (w, h) = (zone.width() as f32, zone.height() as f32);
+
+        let corners = [
+            self.apply([x, y]),
+            self.apply([x + w, y]),
+            self.apply([x, y + h]),
+            self.apply([x + w, y + h]),
+        ];
+
+        let min_x = corners.iter().map(|c| c[0]).fold(f32::INFINITY, f32::min);
+        let min_y = corners.iter().map(|c| c[1]).fold(f32::INFINITY, f32::min);
+        let max_x = corners.iter().map(|c| c[0]).fold(f32::NEG_INFINITY, f32::max);
+        let max_y = corners.iter().map(|c| c[1]).fold(f32::NEG_INFINITY, f32::max);
+
+        Zone {
+            position: Point::from([min_x.round() as isize, min_y.round() as isize]),
+            size: Size::from([
+                (max_x - min_x).round() as usize,
+                (max_y - min_y).round() as usize,
+            ]),
+        }
+    }
+}
+
+/// Transformation of a widget, applied after it was sized and aligned. The
+/// layout is not affected by the transformation.
+///
+/// The operations are done in this order: scale, rotation and translation.
+/// Scale and rotation are done around the `origin`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform {
+    /// Translation on the x and y axes.
+    pub translate: [f32; 2],
+    /// Scale factor on the x and y axes.
+    pub scale: [f32; 2],
+    /// Rotation angle in degrees, clockwise.
+    pub rotate: f32,
+    /// Origin of the scale and rotation, relative to the widget's zone where
+    /// `[0.0, 0.0]` is the top-left corner and `[1.0, 1.0]` the bottom-right
+    /// corner.
+    pub origin: [f32; 2],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            translate: [0.0, 0.0],
+            scale: [1.0, 1.0],
+            rotate: 0.0,
+            origin: [0.5, 0.5],
+        }
+    }
+}
+
+impl Transform {
+    /// Creates a new transformation around the centre of the widget.
+    pub fn new(translate: [f32; 2], scale: [f32; 2], rotate: f32) -> Self {
+        Self {
+            translate,
+            scale,
+            rotate,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a transformation only moving the widget.
+    pub fn translated(x: f32, y: f32) -> Self {
+        Self {
+            translate: [x, y],
+            ..Self::default()
+        }
+    }
+
+    /// Creates a transformation only scaling the widget from its centre.
+    pub fn scaled(x: f32, y: f32) -> Self {
+        Self {
+            scale: [x, y],
+            ..Self::default()
+        }
+    }
+
+    /// Creates a transformation only rotating the widget around its centre.
+    pub fn rotated(degrees: f32) -> Self {
+        Self {
+            rotate: degrees,
+            ..Self::default()
+        }
+    }
+
+    /// Creates the matrix of the transformation for a widget in this zone.
+    pub fn matrix(&self, zone: Zone) -> Matrix {
+        let origin_x = zone.x() as f32 + self.origin[0] * zone.width() as f32;
+        let origin_y = zone.y() as f32 + self.origin[1] * zone.height() as f32;
+
+        Matrix::translation(self.translate[0], self.translate[1])
+            * Matrix::translation(origin_x, origin_y)
+            * Matrix::rotation(self.rotate)
+            * Matrix::scaling(self.scale[0], self.scale[1])
+            * Matrix::translation(-origin_x, -origin_y)
+    }
+}
@@ -55,6 +55,7 @@ pub mod controllers;
 pub mod widgets;
 
 pub mod graphics;
+pub mod text;
 pub mod themes;
 
 mod align;
@@ -62,6 +63,7 @@ mod border;
 mod direction;
 mod font;
 mod overflow;
+mod padding;
 mod radius;
 mod side;
 mod zone;
@@ -71,6 +73,7 @@ pub use border::Border;
 pub use direction::Direction;
 pub use font::*;
 pub use overflow::Overflow;
+pub use padding::Padding;
 pub use radius::Radius;
 pub use side::Side;
 pub use zone::Zone;
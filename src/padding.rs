@@ -0,0 +1,52 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use crate::themes::{Spacing, SpacingScale};
+
+/// Space between the sides of a layout and its widgets.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Padding {
+    #[allow(missing_docs)]
+    pub top: Spacing,
+    #[allow(missing_docs)]
+    pub right: Spacing,
+    #[allow(missing_docs)]
+    pub bottom: Spacing,
+    #[allow(missing_docs)]
+    pub left: Spacing,
+}
+
+impl Padding {
+    /// Creates a padding of the same spacing on each side.
+    pub fn all<T: Into<Spacing>>(spacing: T) -> Self {
+        let spacing = spacing.into();
+        Self::symmetric(spacing, spacing)
+    }
+
+    /// Creates a padding with a spacing for the top and bottom sides and
+    /// another one for the left and right sides.
+    pub fn symmetric<T: Into<Spacing>>(vertical: T, horizontal: T) -> Self {
+        let vertical = vertical.into();
+        let horizontal = horizontal.into();
+
+        Self {
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+            left: horizontal,
+        }
+    }
+
+    /// Returns the padding in pixels as `[top, right, bottom, left]`,
+    /// following the spacing scale for the sides referencing a token.
+    pub fn resolve(&self, scale: &SpacingScale) -> [usize; 4] {
+        [
+            self.top.resolve(scale),
+            self.right.resolve(scale),
+            self.bottom.resolve(scale),
+            self.left.resolve(scale),
+        ]
+    }
+}
@@ -6,6 +6,8 @@ use std::f32::consts;
 
 /// Radius definition for a shape.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Radius(f32);
 
 impl Default for Radius {
@@ -20,6 +22,11 @@ impl Radius {
         Self(degrees)
     }
 
+    /// Returns the value in degrees.
+    pub fn degrees(&self) -> f32 {
+        self.0
+    }
+
     /// Converts the value given in radians to degrees.
     pub fn from_radians(value: f32) -> Self {
         Self(value * consts::PI / 180.0)
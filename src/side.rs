@@ -4,6 +4,7 @@
 
 /// Sides of a rectangle.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub enum Side {
     Bottom,
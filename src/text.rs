@@ -0,0 +1,521 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Everything related to editing text, for the
+//! [`TextField`](crate::widgets::TextField)s.
+//!
+//! The positions in the text are indexes of characters, not of bytes.
+
+use std::{cell::RefCell, ops::Range, rc::Rc};
+
+/// Maximum number of edits which can be undone.
+const HISTORY_LENGTH: usize = 100;
+
+/// Text being composed with an input method (IME), not yet in the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Composition {
+    /// The composed text, shown at the cursor.
+    pub text: String,
+    /// Position of the cursor in the composed text.
+    pub cursor: usize,
+}
+
+/// Movement of the cursor.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Motion {
+    /// To the previous character.
+    Left,
+    /// To the next character.
+    Right,
+    /// To the start of the word, or of the previous word.
+    WordLeft,
+    /// To the end of the word, or of the next word.
+    WordRight,
+    /// To the same column on the previous line.
+    Up,
+    /// To the same column on the next line.
+    Down,
+    /// To the start of the line.
+    LineStart,
+    /// To the end of the line.
+    LineEnd,
+    /// To the start of the text.
+    Start,
+    /// To the end of the text.
+    End,
+}
+
+/// State of the buffer saved to be restored by undo and redo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Snapshot {
+    text: String,
+    cursor: usize,
+    anchor: usize,
+}
+
+/// Editable text with a cursor, a selection, a composition and an history of
+/// the edits.
+///
+/// The selection goes from an anchor to the cursor, there is no selection
+/// when they are at the same position. In single-line mode, the line breaks
+/// of the inserted text are replaced by spaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Buffer {
+    text: String,
+    /// Position of the cursor.
+    cursor: usize,
+    /// Position where the selection started.
+    anchor: usize,
+    composition: Option<Composition>,
+    multi_line: bool,
+    /// States before the edits, the last one is restored by undo.
+    undo: Vec<Snapshot>,
+    /// States before the undone edits, the last one is restored by redo.
+    redo: Vec<Snapshot>,
+    /// Whether the last edit was typing, so the next typed text is undone
+    /// with it.
+    typing: bool,
+}
+
+/// Returns the text with its line breaks, `\r\n`, `\r` or `\n`, written as
+/// `\n` for a multi-line text, otherwise replaced by spaces.
+fn line_breaks(text: &str, multi_line: bool) -> String {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+
+    match multi_line {
+        true => text,
+        false => text.replace('\n', " "),
+    }
+}
+
+impl Buffer {
+    /// Creates a buffer with the cursor at the end of the text.
+    pub fn new(text: &str, multi_line: bool) -> Self {
+        let text = line_breaks(text, multi_line);
+        let end = text.chars().count();
+
+        Self {
+            text,
+            cursor: end,
+            anchor: end,
+            composition: None,
+            multi_line,
+            undo: vec![],
+            redo: vec![],
+            typing: false,
+        }
+    }
+
+    /// Returns the text, without the composition.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the number of characters of the text.
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Whether there is no text.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Whether the text can have several lines.
+    pub fn is_multi_line(&self) -> bool {
+        self.multi_line
+    }
+
+    /// Returns the position of the cursor.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the selected range, if any.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        match self.anchor.cmp(&self.cursor) {
+            std::cmp::Ordering::Less => Some(self.anchor..self.cursor),
+            std::cmp::Ordering::Greater => Some(self.cursor..self.anchor),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Returns the selected text, if any.
+    pub fn selected_text(&self) -> Option<String> {
+        self.selection().map(|range| self.slice(range).to_string())
+    }
+
+    /// Returns the text being composed, if any.
+    pub fn composition(&self) -> Option<&Composition> {
+        self.composition.as_ref()
+    }
+
+    /// Returns the lines of the text.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.split('\n')
+    }
+
+    /// Returns the line and the column of a position.
+    pub fn line_column(&self, index: usize) -> (usize, usize) {
+        let mut line = 0;
+        let mut column = 0;
+
+        for character in self.text.chars().take(index) {
+            if character == '\n' {
+                line += 1;
+                column = 0;
+            } else {
+                column += 1;
+            }
+        }
+
+        (line, column)
+    }
+
+    /// Returns the position at a line and a column. The line and the column
+    /// are kept in the text.
+    pub fn index(&self, line: usize, column: usize) -> usize {
+        let mut index = 0;
+
+        for (i, text) in self.lines().enumerate() {
+            let length = text.chars().count();
+
+            if i == line {
+                return index + column.min(length);
+            }
+
+            index += length + 1;
+        }
+
+        self.len()
+    }
+
+    /// Moves the cursor to a position, extending the selection or not.
+    pub fn set_cursor(&mut self, index: usize, extend: bool) {
+        self.cursor = index.min(self.len());
+
+        if !extend {
+            self.anchor = self.cursor;
+        }
+
+        self.typing = false;
+    }
+
+    /// Selects a range of the text.
+    pub fn select(&mut self, range: Range<usize>) {
+        self.anchor = range.start.min(self.len());
+        self.set_cursor(range.end, true);
+    }
+
+    /// Selects all the text.
+    pub fn select_all(&mut self) {
+        self.select(0..self.len());
+    }
+
+    /// Selects the word at a position.
+    pub fn select_word(&mut self, index: usize) {
+        let characters: Vec<char> = self.text.chars().collect();
+        let index = index.min(characters.len());
+
+        let mut start = index;
+        while start > 0 && is_word(characters[start - 1]) {
+            start -= 1;
+        }
+
+        let mut end = index;
+        while end < characters.len() && is_word(characters[end]) {
+            end += 1;
+        }
+
+        self.select(start..end);
+    }
+
+    /// Moves the cursor, extending the selection or not.
+    ///
+    /// Moving left or right without extending a selection goes to its start
+    /// or its end.
+    pub fn move_cursor(&mut self, motion: Motion, extend: bool) {
+        if let (Some(selection), false) = (self.selection(), extend) {
+            match motion {
+                Motion::Left => return self.set_cursor(selection.start, false),
+                Motion::Right => return self.set_cursor(selection.end, false),
+                _ => (),
+            }
+        }
+
+        let characters: Vec<char> = self.text.chars().collect();
+        let (line, column) = self.line_column(self.cursor);
+        let mut index = self.cursor;
+
+        match motion {
+            Motion::Left => index = index.saturating_sub(1),
+            Motion::Right => index = (index + 1).min(characters.len()),
+            Motion::WordLeft => {
+                while index > 0 && !is_word(characters[index - 1]) {
+                    index -= 1;
+                }
+                while index > 0 && is_word(characters[index - 1]) {
+                    index -= 1;
+                }
+            }
+            Motion::WordRight => {
+                while index < characters.len() && !is_word(characters[index]) {
+                    index += 1;
+                }
+                while index < characters.len() && is_word(characters[index]) {
+                    index += 1;
+                }
+            }
+            Motion::Up if line == 0 => index = 0,
+            Motion::Up => index = self.index(line - 1, column),
+            Motion::Down if line + 1 == self.lines().count() => index = characters.len(),
+            Motion::Down => index = self.index(line + 1, column),
+            Motion::LineStart => index = self.index(line, 0),
+            Motion::LineEnd => index = self.index(line, usize::MAX),
+            Motion::Start => index = 0,
+            Motion::End => index = characters.len(),
+        }
+
+        self.set_cursor(index, extend);
+    }
+
+    /// Replaces the selection by the text, or inserts it at the cursor.
+    /// Returns whether the text changed.
+    ///
+    /// The text typed successively is undone at once.
+    pub fn insert(&mut self, text: &str) -> bool {
+        let text = line_breaks(text, self.multi_line);
+
+        if text.is_empty() && self.selection().is_none() {
+            return false;
+        }
+
+        let typing = self.selection().is_none() && text.chars().count() == 1;
+        self.save(typing);
+
+        let range = self.selection().unwrap_or(self.cursor..self.cursor);
+        self.replace(range.clone(), &text);
+        self.set_cursor(range.start + text.chars().count(), false);
+        self.typing = typing;
+
+        true
+    }
+
+    /// Deletes the selection, or the character before the cursor. Returns
+    /// whether the text changed.
+    pub fn delete_backward(&mut self) -> bool {
+        self.delete(Motion::Left)
+    }
+
+    /// Deletes the selection, or the character after the cursor. Returns
+    /// whether the text changed.
+    pub fn delete_forward(&mut self) -> bool {
+        self.delete(Motion::Right)
+    }
+
+    /// Deletes the selection, or the text between the cursor and where the
+    /// motion would move it. Returns whether the text changed.
+    pub fn delete(&mut self, motion: Motion) -> bool {
+        let range = match self.selection() {
+            Some(selection) => selection,
+            None => {
+                let cursor = self.cursor;
+                self.move_cursor(motion, false);
+                let moved = self.cursor;
+                self.cursor = cursor;
+                self.anchor = cursor;
+
+                cursor.min(moved)..cursor.max(moved)
+            }
+        };
+
+        if range.is_empty() {
+            return false;
+        }
+
+        self.save(false);
+        self.replace(range.clone(), "");
+        self.set_cursor(range.start, false);
+
+        true
+    }
+
+    /// Deletes the selected text and returns it, to be written in the
+    /// clipboard.
+    pub fn cut(&mut self) -> Option<String> {
+        let text = self.selected_text()?;
+        self.delete_backward();
+        Some(text)
+    }
+
+    /// Replaces the whole text. Returns whether the text changed.
+    pub fn set_text(&mut self, text: &str) -> bool {
+        self.select_all();
+        let changed = self.insert(text);
+        self.typing = false;
+        changed
+    }
+
+    /// Undoes the last edit. Returns whether there was an edit to undo.
+    pub fn undo(&mut self) -> bool {
+        let snapshot = match self.undo.pop() {
+            Some(snapshot) => snapshot,
+            None => return false,
+        };
+
+        self.redo.push(self.snapshot());
+        self.restore(snapshot);
+
+        true
+    }
+
+    /// Redoes the last undone edit. Returns whether there was an undone edit.
+    pub fn redo(&mut self) -> bool {
+        let snapshot = match self.redo.pop() {
+            Some(snapshot) => snapshot,
+            None => return false,
+        };
+
+        self.undo.push(self.snapshot());
+        self.restore(snapshot);
+
+        true
+    }
+
+    /// Whether there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Whether there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Updates the text being composed at the cursor. An empty text ends the
+    /// composition without changing the text.
+    pub fn set_composition(&mut self, text: &str, cursor: usize) {
+        self.composition = match text.is_empty() {
+            true => None,
+            false => Some(Composition {
+                text: text.to_string(),
+                cursor: cursor.min(text.chars().count()),
+            }),
+        };
+    }
+
+    /// Ends the composition, inserting the composed text. Returns whether the
+    /// text changed.
+    pub fn commit(&mut self, text: &str) -> bool {
+        self.composition = None;
+        self.insert(text)
+    }
+
+    /// Returns the text with the composition at the cursor, and the position
+    /// of the cursor in this text.
+    pub fn composed(&self) -> (String, usize) {
+        let composition = match &self.composition {
+            Some(composition) => composition,
+            None => return (self.text.clone(), self.cursor),
+        };
+
+        let byte = self.byte(self.cursor);
+        let mut text = self.text.clone();
+        text.insert_str(byte, &composition.text);
+
+        (text, self.cursor + composition.cursor)
+    }
+
+    /// Returns the text of a range of positions.
+    fn slice(&self, range: Range<usize>) -> &str {
+        &self.text[self.byte(range.start)..self.byte(range.end)]
+    }
+
+    /// Returns the index of the byte of a position.
+    fn byte(&self, index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(index)
+            .map_or(self.text.len(), |(byte, _)| byte)
+    }
+
+    /// Replaces a range of the text.
+    fn replace(&mut self, range: Range<usize>, text: &str) {
+        let bytes = self.byte(range.start)..self.byte(range.end);
+        self.text.replace_range(bytes, text);
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            text: self.text.clone(),
+            cursor: self.cursor,
+            anchor: self.anchor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.text = snapshot.text;
+        self.cursor = snapshot.cursor;
+        self.anchor = snapshot.anchor;
+        self.composition = None;
+        self.typing = false;
+    }
+
+    /// Saves the state before an edit, unless the edit is typing following
+    /// typing.
+    fn save(&mut self, typing: bool) {
+        if typing && self.typing {
+            return;
+        }
+
+        self.undo.push(self.snapshot());
+        self.redo.clear();
+
+        if self.undo.len() > HISTORY_LENGTH {
+            self.undo.remove(0);
+        }
+    }
+}
+
+/// Whether the character is part of a word.
+fn is_word(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+/// Clipboard of the system, implemented by the backend to copy, cut and
+/// paste text.
+pub trait Clipboard {
+    /// Returns the text in the clipboard, if any.
+    fn read(&mut self) -> Option<String>;
+
+    /// Writes text in the clipboard.
+    fn write(&mut self, text: &str);
+}
+
+/// Clipboard shared by several editors.
+impl<C: Clipboard> Clipboard for Rc<RefCell<C>> {
+    fn read(&mut self) -> Option<String> {
+        self.borrow_mut().read()
+    }
+
+    fn write(&mut self, text: &str) {
+        self.borrow_mut().write(text)
+    }
+}
+
+/// Clipboard kept in memory, for backends without system clipboard.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryClipboard {
+    text: Option<String>,
+}
+
+impl Clipboard for MemoryClipboard {
+    fn read(&mut self) -> Option<String> {
+        self.text.clone()
+    }
+
+    fn write(&mut self, text: &str) {
+        self.text = Some(text.to_string());
+    }
+}
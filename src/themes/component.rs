@@ -0,0 +1,537 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use crate::{
+    graphics::colours::{self, RGBA},
+    Border,
+    Radius,
+    Side,
+};
+
+use super::{ColourScheme, LabelStyle, Style, StyleTokens, TextStyle};
+
+/// Interaction state of a widget, changing its style.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum State {
+    /// The widget is not interacted with.
+    #[default]
+    Normal,
+    /// A pointer is over the widget.
+    Hovered,
+    /// The widget is being pressed.
+    Pressed,
+    /// The widget has the keyboard focus.
+    Focused,
+    /// The widget has the keyboard focus, given with the keyboard. The focus 
+    /// must be visible so the user knows where the keyboard input goes.
+    FocusVisible,
+    /// The widget cannot be interacted with.
+    Disabled,
+}
+
+/// Style for each [`State`] of a widget.
+///
+/// Only the style for the normal state is required, the style for another
+/// state falls back to it when it is not defined. The undefined properties of
+/// a state style are also taken from the normal style.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateStyles {
+    /// Style when the widget is not interacted with.
+    pub normal: Style,
+    /// Style when a pointer is over the widget.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub hovered: Option<Style>,
+    /// Style when the widget is being pressed.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub pressed: Option<Style>,
+    /// Style when the widget has the keyboard focus.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub focused: Option<Style>,
+    /// Style when the widget has the keyboard focus given with the keyboard. 
+    /// Falls back to the focused style when it is not defined.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub focus_visible: Option<Style>,
+    /// Style when the widget cannot be interacted with.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub disabled: Option<Style>,
+}
+
+impl StateStyles {
+    /// Creates state styles with only a style for the normal state.
+    pub fn new(normal: Style) -> Self {
+        Self {
+            normal,
+            hovered: None,
+            pressed: None,
+            focused: None,
+            focus_visible: None,
+            disabled: None,
+        }
+    }
+
+    /// Returns the style for a state, completed with the normal style.
+    pub fn get(&self, state: State) -> Style {
+        let style = match state {
+            State::Normal => None,
+            State::Hovered => self.hovered.as_ref(),
+            State::Pressed => self.pressed.as_ref(),
+            State::Focused => self.focused.as_ref(),
+            State::FocusVisible => self.focus_visible.as_ref().or(self.focused.as_ref()),
+            State::Disabled => self.disabled.as_ref(),
+        };
+
+        match style {
+            Some(style) => style.merged(&self.normal),
+            None => self.normal.clone(),
+        }
+    }
+
+    /// Creates state styles for an interactive widget from its normal style,
+    /// with content of colour `on_colour`.
+    ///
+    /// The hovered, pressed and focused states are layered with the content
+    /// colour, the visible focus is also outlined with the secondary colour 
+    /// and the disabled state is greyed.
+    pub fn layered(normal: Style, on_colour: RGBA, scheme: &ColourScheme) -> Self {
+        let layered = |opacity: f32| Style {
+            colour: normal.colour.map(|colour| colour.mix(on_colour, opacity)),
+            borders: None,
+            radius: None,
+            tokens: StyleTokens::default(),
+        };
+
+        Self {
+            hovered: Some(layered(0.08)),
+            pressed: Some(layered(0.12)),
+            focused: Some(layered(0.12)),
+            focus_visible: Some(Style {
+                borders: Some([Side::Top, Side::Right, Side::Bottom, Side::Left]
+                    .map(|side| Some(Border::new(2, scheme.secondary, side)))),
+                ..layered(0.12)
+            }),
+            disabled: Some(Style {
+                colour: Some(scheme.on_surface.with_alpha(31)),
+                borders: None,
+                radius: None,
+                tokens: StyleTokens::default(),
+            }),
+            normal,
+        }
+    }
+
+    /// Creates state styles for an interactive widget of colour `colour`.
+    fn interactive(colour: RGBA, on_colour: RGBA, scheme: &ColourScheme) -> Self {
+        let normal = Style {
+            colour: Some(colour),
+            borders: None,
+            radius: None,
+            tokens: StyleTokens::default(),
+        };
+
+        Self::layered(normal, on_colour, scheme)
+    }
+
+    /// Creates state styles with the same colour for every state.
+    pub(crate) fn constant(colour: RGBA) -> Self {
+        Self::new(Style {
+            colour: Some(colour),
+            borders: None,
+            radius: None,
+            tokens: StyleTokens::default(),
+        })
+    }
+}
+
+/// Theme for the [`Button`](crate::widgets::Button)s.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ButtonTheme {
+    /// Styles of the button's surface.
+    pub styles: StateStyles,
+    /// Style of the button's label.
+    pub label_style: LabelStyle,
+}
+
+impl ButtonTheme {
+    /// Creates the button theme from a colour scheme. Buttons are coloured
+    /// with the primary colour.
+    pub fn from_scheme(scheme: &ColourScheme) -> Self {
+        Self {
+            styles: StateStyles::interactive(scheme.primary, scheme.on_primary, scheme),
+            label_style: LabelStyle {
+                colour: scheme.on_primary,
+                text_style: None,
+            },
+        }
+    }
+}
+
+/// Theme for the [`Layout`](crate::widgets::Layout)s.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LayoutTheme {
+    /// Styles of the layout's surface.
+    pub styles: StateStyles,
+}
+
+impl LayoutTheme {
+    /// Creates the layout theme from a colour scheme. Layouts are transparent
+    /// so only their widgets are seen.
+    pub fn from_scheme(_scheme: &ColourScheme) -> Self {
+        Self {
+            styles: StateStyles::constant(colours::TRANSPARENT),
+        }
+    }
+}
+
+/// Theme for the [`Surface`](crate::widgets::Surface)s.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SurfaceTheme {
+    /// Styles of the surface.
+    pub styles: StateStyles,
+    /// Style of the labels over a surface.
+    pub label_style: LabelStyle,
+}
+
+impl SurfaceTheme {
+    /// Creates the surface theme from a colour scheme. Surfaces are coloured
+    /// with the surface colour.
+    pub fn from_scheme(scheme: &ColourScheme) -> Self {
+        Self {
+            styles: StateStyles::constant(scheme.surface),
+            label_style: LabelStyle {
+                colour: scheme.on_surface,
+                text_style: None,
+            },
+        }
+    }
+}
+
+/// Theme for the [`Image`](crate::widgets::Image)s.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImageTheme {
+    /// Styles of the image's zone, seen while the image is not drawn.
+    pub styles: StateStyles,
+}
+
+impl ImageTheme {
+    /// Creates the image theme from a colour scheme. The zones of images are
+    /// coloured with the surface variant colour.
+    pub fn from_scheme(scheme: &ColourScheme) -> Self {
+        Self {
+            styles: StateStyles::constant(scheme.surface_variant),
+        }
+    }
+}
+
+/// Theme for the input widgets, where the user writes or selects values.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputTheme {
+    /// Styles of the input's surface.
+    pub styles: StateStyles,
+    /// Style of the input's text.
+    pub label_style: LabelStyle,
+    /// Colour of the text shown while the input is empty.
+    pub placeholder_colour: RGBA,
+    /// Colour of the text cursor.
+    pub caret_colour: RGBA,
+    /// Colour behind the selected text.
+    pub selection_colour: RGBA,
+}
+
+impl InputTheme {
+    /// Creates the input theme from a colour scheme. Inputs are coloured with
+    /// the surface variant colour, and the primary colour shows where the
+    /// user is writing.
+    pub fn from_scheme(scheme: &ColourScheme) -> Self {
+        Self {
+            styles: StateStyles::interactive(scheme.surface_variant, scheme.on_surface_variant, scheme),
+            label_style: LabelStyle {
+                colour: scheme.on_surface,
+                text_style: None,
+            },
+            placeholder_colour: scheme.on_surface_variant,
+            caret_colour: scheme.primary,
+            selection_colour: scheme.primary_container,
+        }
+    }
+}
+
+/// Theme for the toggle widgets: the [`Checkbox`](crate::widgets::Checkbox)es,
+/// the [`Radio`](crate::widgets::Radio)s and the 
+/// [`Switch`](crate::widgets::Switch)es.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ToggleTheme {
+    /// Styles of the toggle's surface when it is off.
+    pub styles: StateStyles,
+    /// Styles of the toggle's surface when it is on.
+    pub selected_styles: StateStyles,
+    /// Colour of the check mark, the dot and the thumb.
+    pub mark_colour: RGBA,
+}
+
+impl ToggleTheme {
+    /// Creates the toggle theme from a colour scheme. Toggles are coloured 
+    /// with the surface variant colour when they are off, and with the 
+    /// primary colour when they are on.
+    pub fn from_scheme(scheme: &ColourScheme) -> Self {
+        Self {
+            styles: StateStyles::interactive(scheme.surface_variant, scheme.on_surface_variant, scheme),
+            selected_styles: StateStyles::interactive(scheme.primary, scheme.on_primary, scheme),
+            mark_colour: scheme.on_primary,
+        }
+    }
+}
+
+/// Theme for the widgets showing a value in a range: the 
+/// [`Slider`](crate::widgets::Slider)s, the 
+/// [`ProgressBar`](crate::widgets::ProgressBar)s and the 
+/// [`Spinner`](crate::widgets::Spinner)s.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RangeTheme {
+    /// Colour of the track.
+    pub track_colour: RGBA,
+    /// Colour of the selected range and of the progress.
+    pub indicator_colour: RGBA,
+    /// Styles of the slider's thumbs.
+    pub thumb_styles: StateStyles,
+}
+
+impl RangeTheme {
+    /// Creates the range theme from a colour scheme. The progress is coloured
+    /// with the primary colour over a track of the surface variant colour.
+    pub fn from_scheme(scheme: &ColourScheme) -> Self {
+        Self {
+            track_colour: scheme.surface_variant,
+            indicator_colour: scheme.primary,
+            thumb_styles: StateStyles::interactive(scheme.primary, scheme.on_primary, scheme),
+        }
+    }
+}
+
+/// Theme for the [`Menu`](crate::widgets::Menu)s, opened over the other 
+/// widgets.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MenuTheme {
+    /// Styles of the menu's surface.
+    pub styles: StateStyles,
+    /// Style of the menu's items.
+    pub label_style: LabelStyle,
+    /// Colour behind the highlighted item.
+    pub highlight_colour: RGBA,
+    /// Colour of the highlighted item.
+    pub on_highlight_colour: RGBA,
+}
+
+impl MenuTheme {
+    /// Creates the menu theme from a colour scheme. Menus are coloured with
+    /// the surface colour and outlined, so they stand out from the widgets 
+    /// below them. The highlighted item is coloured with the secondary 
+    /// container colour.
+    pub fn from_scheme(scheme: &ColourScheme) -> Self {
+        Self {
+            styles: StateStyles::new(Style {
+                colour: Some(scheme.surface),
+                borders: Some([Side::Top, Side::Right, Side::Bottom, Side::Left]
+                    .map(|side| Some(Border::new(1, scheme.outline, side)))),
+                radius: None,
+                tokens: StyleTokens::default(),
+            }),
+            label_style: LabelStyle {
+                colour: scheme.on_surface,
+                text_style: None,
+            },
+            highlight_colour: scheme.secondary_container,
+            on_highlight_colour: scheme.on_secondary_container,
+        }
+    }
+}
+
+/// Theme for the [`Table`](crate::widgets::Table)s.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableTheme {
+    /// Styles of the headers' surface.
+    pub header_styles: StateStyles,
+    /// Style of the headers' titles.
+    pub header_label_style: LabelStyle,
+    /// Colour behind the cells of the selected row.
+    pub selected_colour: RGBA,
+    /// Colour of the texts on the selected row.
+    pub on_selected_colour: RGBA,
+}
+
+impl TableTheme {
+    /// Creates the table theme from a colour scheme. Headers are coloured 
+    /// with the surface variant colour, the selected row with the secondary
+    /// container colour.
+    pub fn from_scheme(scheme: &ColourScheme) -> Self {
+        Self {
+            header_styles: StateStyles::constant(scheme.surface_variant),
+            header_label_style: LabelStyle {
+                colour: scheme.on_surface_variant,
+                text_style: None,
+            },
+            selected_colour: scheme.secondary_container,
+            on_selected_colour: scheme.on_secondary_container,
+        }
+    }
+}
+
+/// Theme for the [`NavigationBar`](crate::widgets::NavigationBar)s and the
+/// bars of the [`Tabs`](crate::widgets::Tabs).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NavigationTheme {
+    /// Styles of the bar's surface.
+    pub styles: StateStyles,
+    /// Style of the bar's items.
+    pub label_style: LabelStyle,
+    /// Colour behind the selected item.
+    pub selected_colour: RGBA,
+    /// Colour of the selected item.
+    pub on_selected_colour: RGBA,
+}
+
+impl NavigationTheme {
+    /// Creates the navigation theme from a colour scheme. Bars are coloured
+    /// with the surface colour, the selected item with the secondary 
+    /// container colour.
+    pub fn from_scheme(scheme: &ColourScheme) -> Self {
+        Self {
+            styles: StateStyles::constant(scheme.surface),
+            label_style: LabelStyle {
+                colour: scheme.on_surface_variant,
+                text_style: None,
+            },
+            selected_colour: scheme.secondary_container,
+            on_selected_colour: scheme.on_secondary_container,
+        }
+    }
+}
+
+/// Theme for the [`Dialog`](crate::controllers::dialog::Dialog)s and the
+/// [`Toast`](crate::controllers::dialog::Toast)s, shown over the other
+/// widgets by the view.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DialogTheme {
+    /// Styles of the dialog's surface.
+    pub styles: StateStyles,
+    /// Style of the dialog's texts.
+    pub label_style: LabelStyle,
+    /// Colour of the barrier covering the view under a modal dialog.
+    pub barrier_colour: RGBA,
+    /// Styles of the toast's surface.
+    pub toast_styles: StateStyles,
+    /// Style of the toast's text.
+    pub toast_label_style: LabelStyle,
+}
+
+impl DialogTheme {
+    /// Creates the dialog theme from a colour scheme. Dialogs are coloured 
+    /// with the surface colour over a translucent barrier of the colour on
+    /// the background. Toasts are coloured the other way round, with the 
+    /// colour on the surface, so they stand out from any widget.
+    pub fn from_scheme(scheme: &ColourScheme) -> Self {
+        Self {
+            styles: StateStyles::constant(scheme.surface),
+            label_style: LabelStyle {
+                colour: scheme.on_surface,
+                text_style: None,
+            },
+            barrier_colour: scheme.on_background.with_alpha(82),
+            toast_styles: StateStyles::constant(scheme.on_surface),
+            toast_label_style: LabelStyle {
+                colour: scheme.surface,
+                text_style: None,
+            },
+        }
+    }
+}
+
+/// Theme generated from a colour scheme, which can be generated again field by
+/// field when the scheme changes.
+pub(crate) trait Regenerate {
+    /// Takes the fields of `next` which are still the ones of `previous`, the
+    /// other fields were overridden and are kept.
+    fn regenerate(&mut self, previous: &Self, next: &Self);
+}
+
+/// Implements [`Regenerate`] for types taken as a whole.
+macro_rules! regenerate_whole {
+    ($($kind:ty),* $(,)?) => {
+        $(
+            impl Regenerate for $kind {
+                fn regenerate(&mut self, previous: &Self, next: &Self) {
+                    if self == previous {
+                        *self = next.clone();
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Implements [`Regenerate`] for structures, field by field. All the fields
+/// have to be given.
+macro_rules! regenerate_fields {
+    ($($kind:ty { $($field:ident),* $(,)? })*) => {
+        $(
+            impl Regenerate for $kind {
+                fn regenerate(&mut self, previous: &Self, next: &Self) {
+                    let Self { $($field),* } = self;
+                    $($field.regenerate(&previous.$field, &next.$field);)*
+                }
+            }
+        )*
+    };
+}
+
+regenerate_whole!(
+    RGBA,
+    Option<RGBA>,
+    Option<[Option<Border>; 4]>,
+    Option<Radius>,
+    Option<TextStyle>,
+    StyleTokens,
+);
+
+regenerate_fields! {
+    Style { colour, borders, radius, tokens }
+    LabelStyle { colour, text_style }
+    StateStyles { normal, hovered, pressed, focused, focus_visible, disabled }
+    ButtonTheme { styles, label_style }
+    LayoutTheme { styles }
+    SurfaceTheme { styles, label_style }
+    ImageTheme { styles }
+    InputTheme { styles, label_style, placeholder_colour, caret_colour, selection_colour }
+    ToggleTheme { styles, selected_styles, mark_colour }
+    RangeTheme { track_colour, indicator_colour, thumb_styles }
+    MenuTheme { styles, label_style, highlight_colour, on_highlight_colour }
+    TableTheme { header_styles, header_label_style, selected_colour, on_selected_colour }
+    NavigationTheme { styles, label_style, selected_colour, on_selected_colour }
+    DialogTheme { styles, label_style, barrier_colour, toast_styles, toast_label_style }
+}
+
+impl Regenerate for Option<Style> {
+    /// Regenerates the style field by field when it is defined before and
+    /// after, otherwise as a whole.
+    fn regenerate(&mut self, previous: &Self, next: &Self) {
+        match (self.as_mut(), previous, next) {
+            (Some(style), Some(previous), Some(next)) => style.regenerate(previous, next),
+            _ => if self == previous {
+                *self = next.clone();
+            },
+        }
+    }
+}
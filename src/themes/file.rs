@@ -0,0 +1,338 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Loading and saving of themes from/to TOML and JSON files.
+
+use std::{collections::HashSet, fmt, fs, io, path::Path};
+
+use crate::{Border, FontFamily, Radius};
+
+use super::{LabelStyle, StateStyles, Style, TextStyle, Theme, Tokens};
+
+/// Format of a theme file.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    /// [TOML](https://toml.io) format, for files ending with `.toml`.
+    Toml,
+    /// [JSON](https://www.json.org) format, for files ending with `.json`.
+    Json,
+}
+
+impl Format {
+    /// Returns the format following the extension of the file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        match path.as_ref().extension()?.to_str()? {
+            "toml" => Some(Self::Toml),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Error returned when a theme cannot be loaded.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The theme file cannot be read.
+    Io(io::Error),
+    /// The extension of the theme file is neither `.toml` nor `.json`.
+    UnknownFormat(String),
+    /// The TOML theme is malformed or has wrong field types. The error gives
+    /// the line, the column and the key.
+    Toml(toml::de::Error),
+    /// The JSON theme is malformed or has wrong field types. The error gives
+    /// the line and the column.
+    Json(serde_json::Error),
+    /// A field has an invalid value.
+    Invalid {
+        /// Path to the field, like `text_theme.heading1.size`.
+        field: String,
+        /// Why the value is invalid.
+        reason: String,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "cannot read theme file: {}", error),
+            Self::UnknownFormat(path) => write!(f, "unknown theme file format for `{}`, expected `.toml` or `.json`", path),
+            Self::Toml(error) => write!(f, "invalid TOML theme: {}", error),
+            Self::Json(error) => write!(f, "invalid JSON theme: {}", error),
+            Self::Invalid { field, reason } => write!(f, "invalid theme field `{}`: {}", field, reason),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Error returned when a theme cannot be saved.
+#[derive(Debug)]
+pub enum SaveError {
+    /// The theme file cannot be written.
+    Io(io::Error),
+    /// The extension of the theme file is neither `.toml` nor `.json`.
+    UnknownFormat(String),
+    /// The theme cannot be written as TOML.
+    Toml(toml::ser::Error),
+    /// The theme cannot be written as JSON.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "cannot write theme file: {}", error),
+            Self::UnknownFormat(path) => write!(f, "unknown theme file format for `{}`, expected `.toml` or `.json`", path),
+            Self::Toml(error) => write!(f, "cannot write theme as TOML: {}", error),
+            Self::Json(error) => write!(f, "cannot write theme as JSON: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl Theme {
+    /// Loads a theme from a TOML or JSON file, following its extension.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Theme, LoadError> {
+        let path = path.as_ref();
+
+        let format = Format::from_path(path)
+            .ok_or_else(|| LoadError::UnknownFormat(path.display().to_string()))?;
+
+        let content = fs::read_to_string(path).map_err(LoadError::Io)?;
+
+        Self::parse(&content, format)
+    }
+
+    /// Saves the theme in a TOML or JSON file, following its extension.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SaveError> {
+        let path = path.as_ref();
+
+        let content = match Format::from_path(path) {
+            Some(Format::Toml) => self.to_toml()?,
+            Some(Format::Json) => self.to_json()?,
+            None => return Err(SaveError::UnknownFormat(path.display().to_string())),
+        };
+
+        fs::write(path, content).map_err(SaveError::Io)
+    }
+
+    /// Parses and validates a theme written in the given format.
+    pub fn parse(content: &str, format: Format) -> Result<Theme, LoadError> {
+        let theme: Theme = match format {
+            Format::Toml => toml::from_str(content).map_err(LoadError::Toml)?,
+            Format::Json => serde_json::from_str(content).map_err(LoadError::Json)?,
+        };
+
+        theme.validate()?;
+        Ok(theme)
+    }
+
+    /// Parses and validates a theme written in TOML.
+    pub fn from_toml(content: &str) -> Result<Theme, LoadError> {
+        Self::parse(content, Format::Toml)
+    }
+
+    /// Parses and validates a theme written in JSON.
+    pub fn from_json(content: &str) -> Result<Theme, LoadError> {
+        Self::parse(content, Format::Json)
+    }
+
+    /// Writes the theme in TOML.
+    pub fn to_toml(&self) -> Result<String, SaveError> {
+        toml::to_string_pretty(self).map_err(SaveError::Toml)
+    }
+
+    /// Writes the theme in JSON.
+    pub fn to_json(&self) -> Result<String, SaveError> {
+        serde_json::to_string_pretty(self).map_err(SaveError::Json)
+    }
+
+    /// Checks the values of the fields which cannot be checked by their
+    /// types, like sizes which must be positive.
+    pub fn validate(&self) -> Result<(), LoadError> {
+        validate_fonts(&self.fonts)?;
+
+        let text_theme = &self.text_theme;
+
+        for (name, text_style) in [
+            ("code", &text_theme.code),
+            ("heading1", &text_theme.heading1),
+            ("heading2", &text_theme.heading2),
+            ("heading3", &text_theme.heading3),
+            ("heading4", &text_theme.heading4),
+            ("heading5", &text_theme.heading5),
+            ("heading6", &text_theme.heading6),
+            ("paragraph1", &text_theme.paragraph1),
+            ("paragraph2", &text_theme.paragraph2),
+            ("paragraph3", &text_theme.paragraph3),
+        ] {
+            validate_text_style(&format!("text_theme.{}", name), text_style)?;
+        }
+
+        validate_label_style("label_style", &self.label_style)?;
+        validate_style("style", &self.style)?;
+
+        validate_state_styles("button_theme.styles", &self.button_theme.styles)?;
+        validate_label_style("button_theme.label_style", &self.button_theme.label_style)?;
+        validate_state_styles("layout_theme.styles", &self.layout_theme.styles)?;
+        validate_state_styles("surface_theme.styles", &self.surface_theme.styles)?;
+        validate_label_style("surface_theme.label_style", &self.surface_theme.label_style)?;
+        validate_state_styles("image_theme.styles", &self.image_theme.styles)?;
+        validate_state_styles("input_theme.styles", &self.input_theme.styles)?;
+        validate_label_style("input_theme.label_style", &self.input_theme.label_style)?;
+        validate_state_styles("toggle_theme.styles", &self.toggle_theme.styles)?;
+        validate_state_styles("toggle_theme.selected_styles", &self.toggle_theme.selected_styles)?;
+        validate_state_styles("range_theme.thumb_styles", &self.range_theme.thumb_styles)?;
+        validate_state_styles("menu_theme.styles", &self.menu_theme.styles)?;
+        validate_label_style("menu_theme.label_style", &self.menu_theme.label_style)?;
+        validate_state_styles("table_theme.header_styles", &self.table_theme.header_styles)?;
+        validate_label_style("table_theme.header_label_style", &self.table_theme.header_label_style)?;
+        validate_state_styles("navigation_theme.styles", &self.navigation_theme.styles)?;
+        validate_label_style("navigation_theme.label_style", &self.navigation_theme.label_style)?;
+        validate_state_styles("dialog_theme.styles", &self.dialog_theme.styles)?;
+        validate_label_style("dialog_theme.label_style", &self.dialog_theme.label_style)?;
+        validate_state_styles("dialog_theme.toast_styles", &self.dialog_theme.toast_styles)?;
+        validate_label_style("dialog_theme.toast_label_style", &self.dialog_theme.toast_label_style)?;
+
+        validate_tokens("tokens", &self.tokens)
+    }
+}
+
+/// Creates an error for an invalid field.
+fn invalid(field: &str, reason: &str) -> LoadError {
+    LoadError::Invalid {
+        field: field.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+fn validate_fonts(fonts: &[FontFamily]) -> Result<(), LoadError> {
+    let mut names = HashSet::new();
+
+    for (i, family) in fonts.iter().enumerate() {
+        let field = format!("fonts[{}]", i);
+
+        if family.name.is_empty() {
+            return Err(invalid(&format!("{}.name", field), "font family name is empty"));
+        }
+
+        if !names.insert(&family.name) {
+            return Err(invalid(&format!("{}.name", field), &format!("font family `{}` is defined twice", family.name)));
+        }
+
+        for (weight, font) in &family.fonts {
+            if font.path.is_empty() {
+                return Err(invalid(&format!("{}.fonts.{:?}.path", field, weight), "font path is empty"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_text_style(field: &str, text_style: &TextStyle) -> Result<(), LoadError> {
+    if text_style.size <= 0 {
+        return Err(invalid(&format!("{}.size", field), &format!("text size must be positive, got {}", text_style.size)));
+    }
+
+    if !text_style.spacing.is_finite() || text_style.spacing < 0.0 {
+        return Err(invalid(&format!("{}.spacing", field), &format!("letter spacing must be a positive number, got {}", text_style.spacing)));
+    }
+
+    Ok(())
+}
+
+fn validate_label_style(field: &str, label_style: &LabelStyle) -> Result<(), LoadError> {
+    match &label_style.text_style {
+        Some(text_style) => validate_text_style(&format!("{}.text_style", field), text_style),
+        None => Ok(()),
+    }
+}
+
+fn validate_style(field: &str, style: &Style) -> Result<(), LoadError> {
+    if let Some(radius) = style.radius {
+        validate_radius(&format!("{}.radius", field), radius)?;
+    }
+
+    if let Some(borders) = &style.borders {
+        validate_borders(&format!("{}.borders", field), borders)?;
+    }
+
+    Ok(())
+}
+
+fn validate_state_styles(field: &str, styles: &StateStyles) -> Result<(), LoadError> {
+    validate_style(&format!("{}.normal", field), &styles.normal)?;
+
+    for (state, style) in [
+        ("hovered", &styles.hovered),
+        ("pressed", &styles.pressed),
+        ("focused", &styles.focused),
+        ("focus_visible", &styles.focus_visible),
+        ("disabled", &styles.disabled),
+    ] {
+        if let Some(style) = style {
+            validate_style(&format!("{}.{}", field, state), style)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_tokens(field: &str, tokens: &Tokens) -> Result<(), LoadError> {
+    let radius = &tokens.radius;
+
+    for (name, degrees) in [
+        ("none", radius.none),
+        ("extra_small", radius.extra_small),
+        ("small", radius.small),
+        ("medium", radius.medium),
+        ("large", radius.large),
+        ("extra_large", radius.extra_large),
+    ] {
+        validate_radius(&format!("{}.radius.{}", field, name), Radius::new(degrees))?;
+    }
+
+    let elevation = &tokens.elevation;
+
+    for (name, opacity) in [
+        ("level0", elevation.level0),
+        ("level1", elevation.level1),
+        ("level2", elevation.level2),
+        ("level3", elevation.level3),
+        ("level4", elevation.level4),
+        ("level5", elevation.level5),
+    ] {
+        if !(0.0..=1.0).contains(&opacity) {
+            return Err(invalid(&format!("{}.elevation.{}", field, name), &format!("elevation opacity must be between 0 and 1, got {}", opacity)));
+        }
+    }
+
+    if tokens.colours.keys().any(|name| name.is_empty()) {
+        return Err(invalid(&format!("{}.colours", field), "colour name is empty"));
+    }
+
+    Ok(())
+}
+
+fn validate_radius(field: &str, radius: Radius) -> Result<(), LoadError> {
+    if !radius.degrees().is_finite() || radius.degrees() < 0.0 {
+        return Err(invalid(field, &format!("radius must be a positive number, got {}", radius.degrees())));
+    }
+
+    Ok(())
+}
+
+fn validate_borders(field: &str, borders: &[Option<Border>; 4]) -> Result<(), LoadError> {
+    let defined: Vec<&Border> = borders.iter().flatten().collect();
+
+    for (i, border) in defined.iter().enumerate() {
+        if defined[..i].iter().any(|other| other.side == border.side) {
+            return Err(invalid(&format!("{}[{}].side", field, i), &format!("border side `{:?}` is defined twice", border.side)));
+        }
+    }
+
+    Ok(())
+}
@@ -11,17 +11,36 @@
 //! - A style defines the graphical properties of an item such as the colour, 
 //! the size, etc...
 
+mod component;
+#[cfg(feature = "serde")]
+mod file;
+mod scheme;
 mod text;
+mod tokens;
 mod widget;
 
+pub use component::*;
+#[cfg(feature = "serde")]
+pub use file::*;
+pub use scheme::*;
 pub use text::*;
+pub use tokens::*;
 pub use widget::*;
 
 use crate::{FontFamily, FontWeight, graphics::colours::RGBA, Radius};
 
+/// Seed colour of the default theme's colour scheme.
+pub const DEFAULT_SEED: RGBA = RGBA {
+    r: 0x67,
+    g: 0x50,
+    b: 0xa4,
+    a: 255,
+};
+
 /// What is called a "global theme" in the whole project. Contains fonts, text 
 /// theme and styles. 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Theme {
     /// All the font families used in the view.
     /// 
@@ -34,6 +53,34 @@ pub struct Theme {
     pub label_style: LabelStyle,
     /// Fallback style for widgets.
     pub style: Style,
+    /// Colours for each role, used by the widgets without independent style.
+    pub colour_scheme: ColourScheme,
+    /// Theme for the buttons.
+    pub button_theme: ButtonTheme,
+    /// Theme for the layouts.
+    pub layout_theme: LayoutTheme,
+    /// Theme for the surfaces.
+    pub surface_theme: SurfaceTheme,
+    /// Theme for the images.
+    pub image_theme: ImageTheme,
+    /// Theme for the input widgets.
+    pub input_theme: InputTheme,
+    /// Theme for the checkboxes, radios and switches.
+    pub toggle_theme: ToggleTheme,
+    /// Theme for the sliders, progress bars and spinners.
+    pub range_theme: RangeTheme,
+    /// Theme for the menus.
+    pub menu_theme: MenuTheme,
+    /// Theme for the tables.
+    pub table_theme: TableTheme,
+    /// Theme for the navigation bars and the bars of the tabs.
+    pub navigation_theme: NavigationTheme,
+    /// Theme for the dialogs and the toasts.
+    pub dialog_theme: DialogTheme,
+    /// Spacing, radius and elevation scales and named colours, referenced by 
+    /// the styles.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tokens: Tokens,
 }
 
 impl Theme {
@@ -50,12 +97,239 @@ impl Theme {
         // Not font with the same name has been found.
         None
     }
+
+    /// Returns the variant of the theme's colour scheme.
+    pub fn mode(&self) -> Mode {
+        self.colour_scheme.mode
+    }
+
+    /// Switches the theme to another variant. 
+    /// 
+    /// The colour scheme is generated again from its seed colour. The 
+    /// fallback colours and the fields of the component themes still 
+    /// generated from the previous scheme are generated again from the new 
+    /// one, the ones which were overridden are kept.
+    pub fn set_mode(&mut self, mode: Mode) {
+        let previous = self.colour_scheme.clone();
+        self.colour_scheme = ColourScheme::new(previous.seed, mode);
+        let scheme = &self.colour_scheme;
+
+        if self.label_style.colour == previous.on_background {
+            self.label_style.colour = scheme.on_background;
+        }
+        if self.style.colour == Some(previous.surface) {
+            self.style.colour = Some(scheme.surface);
+        }
+
+        regenerate(&mut self.button_theme, ButtonTheme::from_scheme, &previous, scheme);
+        regenerate(&mut self.layout_theme, LayoutTheme::from_scheme, &previous, scheme);
+        regenerate(&mut self.surface_theme, SurfaceTheme::from_scheme, &previous, scheme);
+        regenerate(&mut self.image_theme, ImageTheme::from_scheme, &previous, scheme);
+        regenerate(&mut self.input_theme, InputTheme::from_scheme, &previous, scheme);
+        regenerate(&mut self.toggle_theme, ToggleTheme::from_scheme, &previous, scheme);
+        regenerate(&mut self.range_theme, RangeTheme::from_scheme, &previous, scheme);
+        regenerate(&mut self.menu_theme, MenuTheme::from_scheme, &previous, scheme);
+        regenerate(&mut self.table_theme, TableTheme::from_scheme, &previous, scheme);
+        regenerate(&mut self.navigation_theme, NavigationTheme::from_scheme, &previous, scheme);
+        regenerate(&mut self.dialog_theme, DialogTheme::from_scheme, &previous, scheme);
+    }
+
+    /// Returns the style of a component which has no independent style. 
+    /// 
+    /// Same as [`Self::component_style_in`] for the normal state.
+    pub fn component_style(&self, component: Component) -> Style {
+        self.component_style_in(component, State::Normal)
+    }
+
+    /// Returns the style of a component in a state, when the component has no
+    /// independent style. 
+    /// 
+    /// The style comes from the component theme, its undefined properties 
+    /// come from the fallback style.
+    pub fn component_style_in(&self, component: Component, state: State) -> Style {
+        let styles = match component {
+            Component::Button => self.button_theme.styles.get(state),
+            Component::Image => self.image_theme.styles.get(state),
+            Component::Layout => self.layout_theme.styles.get(state),
+            Component::Surface => self.surface_theme.styles.get(state),
+            Component::Input | Component::Placeholder => self.input_theme.styles.get(state),
+            Component::Caret => StateStyles::constant(self.input_theme.caret_colour).get(state),
+            Component::Selection => StateStyles::constant(self.input_theme.selection_colour).get(state),
+            Component::Toggle => self.toggle_theme.styles.get(state),
+            Component::ToggleSelected => self.toggle_theme.selected_styles.get(state),
+            Component::ToggleMark => StateStyles::constant(self.toggle_theme.mark_colour).get(state),
+            Component::Track => StateStyles::constant(self.range_theme.track_colour).get(state),
+            Component::Indicator => StateStyles::constant(self.range_theme.indicator_colour).get(state),
+            Component::Thumb => self.range_theme.thumb_styles.get(state),
+            Component::Menu => self.menu_theme.styles.get(state),
+            Component::MenuHighlight => StateStyles::constant(self.menu_theme.highlight_colour).get(state),
+            Component::TableHeader => self.table_theme.header_styles.get(state),
+            Component::TableSelected => StateStyles::constant(self.table_theme.selected_colour).get(state),
+            Component::Navigation => self.navigation_theme.styles.get(state),
+            Component::NavigationSelected => StateStyles::constant(self.navigation_theme.selected_colour).get(state),
+            Component::Dialog => self.dialog_theme.styles.get(state),
+            Component::Barrier => StateStyles::constant(self.dialog_theme.barrier_colour).get(state),
+            Component::Toast => self.dialog_theme.toast_styles.get(state),
+        };
+
+        self.resolve_style(&styles.merged(&self.style))
+    }
+
+    /// Returns the style of a label drawn on a component, when the label has 
+    /// no independent style.
+    /// 
+    /// The label style comes from the component theme when it defines one, so
+    /// the text is readable over the component. Otherwise, the fallback label 
+    /// style is used.
+    pub fn component_label_style(&self, component: Component) -> LabelStyle {
+        match component {
+            Component::Button => self.button_theme.label_style.clone(),
+            Component::Surface => self.surface_theme.label_style.clone(),
+            Component::Input => self.input_theme.label_style.clone(),
+            Component::Placeholder => LabelStyle {
+                colour: self.input_theme.placeholder_colour,
+                ..self.input_theme.label_style.clone()
+            },
+            Component::ToggleSelected => LabelStyle {
+                colour: self.toggle_theme.mark_colour,
+                text_style: None,
+            },
+            Component::Menu => self.menu_theme.label_style.clone(),
+            Component::MenuHighlight => LabelStyle {
+                colour: self.menu_theme.on_highlight_colour,
+                ..self.menu_theme.label_style.clone()
+            },
+            Component::TableHeader => self.table_theme.header_label_style.clone(),
+            Component::TableSelected => LabelStyle {
+                colour: self.table_theme.on_selected_colour,
+                text_style: None,
+            },
+            Component::Navigation => self.navigation_theme.label_style.clone(),
+            Component::NavigationSelected => LabelStyle {
+                colour: self.navigation_theme.on_selected_colour,
+                ..self.navigation_theme.label_style.clone()
+            },
+            Component::Dialog => self.dialog_theme.label_style.clone(),
+            Component::Toast => self.dialog_theme.toast_label_style.clone(),
+            Component::Image 
+            | Component::Layout 
+            | Component::Caret 
+            | Component::Selection 
+            | Component::Toggle 
+            | Component::ToggleMark 
+            | Component::Track 
+            | Component::Indicator 
+            | Component::Thumb 
+            | Component::Barrier => self.label_style.clone(),
+        }
+    }
+
+    /// Returns the independent style of a component in a state.
+    /// 
+    /// The independent style is the style of the normal state. The styles of 
+    /// the other states are layered from it like the ones of the component 
+    /// themes, with the colour of the component's labels.
+    pub fn independent_style_in(&self, style: &Style, component: Component, state: State) -> Style {
+        let style = self.resolve_style(style);
+
+        if state == State::Normal {
+            return style;
+        }
+
+        let on_colour = self.component_label_style(component).colour;
+
+        StateStyles::layered(style, on_colour, &self.colour_scheme)
+            .get(state)
+    }
+
+    /// Returns the colour named `name`, in the named colours of the tokens or
+    /// in the roles of the colour scheme.
+    pub fn colour(&self, name: &str) -> Option<RGBA> {
+        self.tokens.colours
+            .get(name)
+            .copied()
+            .or_else(|| self.colour_scheme.role(name))
+    }
+
+    /// Replaces the properties of a style referencing tokens by the values of
+    /// the tokens. The returned style does not reference any token.
+    /// 
+    /// A colour name which is neither a named colour nor a role of the colour 
+    /// scheme is ignored. An elevated colour is layered with the primary 
+    /// colour.
+    pub fn resolve_style(&self, style: &Style) -> Style {
+        let tokens = &style.tokens;
+
+        let mut colour = match &tokens.colour {
+            Some(name) => self.colour(name).or(style.colour),
+            None => style.colour,
+        };
+
+        if let (Some(elevation), Some(base)) = (tokens.elevation, colour) {
+            let opacity = self.tokens.elevation.get(elevation);
+            colour = Some(base.mix(self.colour_scheme.primary, opacity));
+        }
+
+        let radius = match tokens.radius {
+            Some(scale) => Some(self.tokens.radius(scale)),
+            None => style.radius,
+        };
+
+        let borders = style.borders.as_ref().map(|borders| {
+            borders
+                .clone()
+                .map(|border| border.map(|border| border.resolve(&self.tokens.spacing)))
+        });
+
+        Style {
+            colour,
+            borders,
+            radius,
+            tokens: StyleTokens::default(),
+        }
+    }
+
+    /// Returns a label style in a state. The text of disabled labels is 
+    /// faded.
+    pub fn label_style_in(&self, label_style: &LabelStyle, state: State) -> LabelStyle {
+        match state {
+            State::Disabled => LabelStyle {
+                colour: label_style.colour.with_alpha(97),
+                ..label_style.clone()
+            },
+            _ => label_style.clone(),
+        }
+    }
+}
+
+/// Generates a component theme again from the colour scheme `scheme`, field
+/// by field. The fields which are not the ones generated from the colour
+/// scheme `previous` were overridden and are kept.
+fn regenerate<T: Regenerate>(
+    theme: &mut T, 
+    from_scheme: fn(&ColourScheme) -> T, 
+    previous: &ColourScheme, 
+    scheme: &ColourScheme,
+) {
+    theme.regenerate(&from_scheme(previous), &from_scheme(scheme));
 }
 
 /// Returns the default project's theme.
 /// 
-/// At least one font family must be given.
+/// The font families are only found by their name with [`Theme::font()`]. 
+/// Without any, the text styles of the theme are drawn with the font chosen 
+/// by the renderer.
 pub fn default(fonts: Vec<FontFamily>) -> Theme {
+    seeded(fonts, DEFAULT_SEED)
+}
+
+/// Returns the default project's theme with a colour scheme generated from
+/// the `seed` colour.
+/// 
+/// The font families can be empty, like for [`default()`].
+pub fn seeded(fonts: Vec<FontFamily>, seed: RGBA) -> Theme {
+    let colour_scheme = ColourScheme::light(seed);
+
     Theme { 
         fonts,
         text_theme: TextTheme {
@@ -111,13 +385,27 @@ pub fn default(fonts: Vec<FontFamily>) -> Theme {
             },
         },
         label_style: LabelStyle { 
-            colour: RGBA::new(0, 0, 0, 255),
+            colour: colour_scheme.on_background,
             text_style: None,
         },
         style: Style { 
-            colour: Some(RGBA::new(180, 180, 180, 255)),
+            colour: Some(colour_scheme.surface),
             borders: Some([None, None, None, None]),
             radius: Some(Radius::default()),
+            tokens: StyleTokens::default(),
         },
+        button_theme: ButtonTheme::from_scheme(&colour_scheme),
+        layout_theme: LayoutTheme::from_scheme(&colour_scheme),
+        surface_theme: SurfaceTheme::from_scheme(&colour_scheme),
+        image_theme: ImageTheme::from_scheme(&colour_scheme),
+        input_theme: InputTheme::from_scheme(&colour_scheme),
+        toggle_theme: ToggleTheme::from_scheme(&colour_scheme),
+        range_theme: RangeTheme::from_scheme(&colour_scheme),
+        menu_theme: MenuTheme::from_scheme(&colour_scheme),
+        table_theme: TableTheme::from_scheme(&colour_scheme),
+        navigation_theme: NavigationTheme::from_scheme(&colour_scheme),
+        dialog_theme: DialogTheme::from_scheme(&colour_scheme),
+        tokens: Tokens::default(),
+        colour_scheme,
     }
 }
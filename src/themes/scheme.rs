@@ -0,0 +1,266 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use crate::graphics::colours::{HSL, RGBA};
+
+/// Same as [`ColourScheme`], with the American spelling.
+pub type ColorScheme = ColourScheme;
+
+/// Variant of a [`ColourScheme`], generally chosen by the user of the 
+/// application.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
+    /// Dark content over light backgrounds.
+    #[default]
+    Light,
+    /// Light content over dark backgrounds.
+    Dark,
+    /// Black content over white backgrounds, with strong colours.
+    HighContrast,
+}
+
+/// Colours of the same hue and saturation, only changing by their tone.
+///
+/// A tone goes from 0 (black) to 100 (white), which is the lightness of the
+/// colour.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TonalPalette {
+    /// Hue of the colours in degrees, from 0 to 360.
+    pub hue: f32,
+    /// Saturation of the colours from 0 (grey) to 1 (full colour).
+    pub saturation: f32,
+}
+
+impl TonalPalette {
+    /// Creates a new palette from a hue and a saturation.
+    pub fn new(hue: f32, saturation: f32) -> Self {
+        Self {
+            hue,
+            saturation: saturation.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Creates a new palette from the hue and saturation of a colour.
+    pub fn from_colour(colour: RGBA) -> Self {
+        let hsl = HSL::from(colour);
+        Self::new(hsl.h, hsl.s)
+    }
+
+    /// Returns the colour of the palette for a tone from 0 (black) to 100
+    /// (white).
+    pub fn tone(&self, tone: u8) -> RGBA {
+        HSL {
+            h: self.hue,
+            s: self.saturation,
+            l: tone.min(100) as f32 / 100.0,
+            a: 1.0,
+        }
+        .into()
+    }
+}
+
+/// Colours to use for each role in an application, generated from a single
+/// seed colour.
+///
+/// The "on" colours are the colours of the texts and icons drawn over the
+/// colour of the same role (`on_primary` over `primary` for example).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColourScheme {
+    /// The colour from which the scheme was generated.
+    pub seed: RGBA,
+    /// The variant of the scheme.
+    pub mode: Mode,
+    /// Colour for the most prominent widgets, such as buttons.
+    pub primary: RGBA,
+    #[allow(missing_docs)]
+    pub on_primary: RGBA,
+    /// Less prominent colour for widgets needing attention.
+    pub primary_container: RGBA,
+    #[allow(missing_docs)]
+    pub on_primary_container: RGBA,
+    /// Colour for less prominent widgets.
+    pub secondary: RGBA,
+    #[allow(missing_docs)]
+    pub on_secondary: RGBA,
+    /// Less prominent colour for secondary widgets needing attention.
+    pub secondary_container: RGBA,
+    #[allow(missing_docs)]
+    pub on_secondary_container: RGBA,
+    /// Colour behind the whole content.
+    pub background: RGBA,
+    #[allow(missing_docs)]
+    pub on_background: RGBA,
+    /// Colour for surfaces such as cards and sheets.
+    pub surface: RGBA,
+    #[allow(missing_docs)]
+    pub on_surface: RGBA,
+    /// Colour for surfaces which have to be distinguished from the others.
+    pub surface_variant: RGBA,
+    #[allow(missing_docs)]
+    pub on_surface_variant: RGBA,
+    /// Colour for errors.
+    pub error: RGBA,
+    #[allow(missing_docs)]
+    pub on_error: RGBA,
+    /// Colour for borders and dividers.
+    pub outline: RGBA,
+}
+
+/// Saturation under which a seed colour is taken as a grey, which has no hue.
+const ACHROMATIC: f32 = 0.05;
+
+/// Palettes used to generate a scheme.
+struct Palettes {
+    primary: TonalPalette,
+    secondary: TonalPalette,
+    neutral: TonalPalette,
+    neutral_variant: TonalPalette,
+    error: TonalPalette,
+}
+
+impl Palettes {
+    /// Creates the palettes from the seed colour.
+    ///
+    /// The primary palette keeps the hue of the seed, the other ones are less
+    /// saturated versions of it. The error palette is always red.
+    ///
+    /// A grey seed gives grey palettes, since its hue means nothing.
+    fn new(seed: RGBA) -> Self {
+        let seed = TonalPalette::from_colour(seed);
+        let primary = match seed.saturation < ACHROMATIC {
+            true => seed.saturation,
+            false => seed.saturation.max(0.5),
+        };
+
+        Self {
+            primary: TonalPalette::new(seed.hue, primary),
+            secondary: TonalPalette::new(seed.hue, seed.saturation.min(0.2)),
+            neutral: TonalPalette::new(seed.hue, seed.saturation.min(0.05)),
+            neutral_variant: TonalPalette::new(seed.hue, seed.saturation.min(0.1)),
+            error: TonalPalette::new(3.0, 0.7),
+        }
+    }
+}
+
+impl ColourScheme {
+    /// Generates a variant of the scheme from a seed colour.
+    pub fn new(seed: RGBA, mode: Mode) -> Self {
+        match mode {
+            Mode::Light => Self::light(seed),
+            Mode::Dark => Self::dark(seed),
+            Mode::HighContrast => Self::high_contrast(seed),
+        }
+    }
+
+    /// Returns the colour of a role from its name, like `primary` or 
+    /// `on_surface`.
+    pub fn role(&self, name: &str) -> Option<RGBA> {
+        Some(match name {
+            "primary" => self.primary,
+            "on_primary" => self.on_primary,
+            "primary_container" => self.primary_container,
+            "on_primary_container" => self.on_primary_container,
+            "secondary" => self.secondary,
+            "on_secondary" => self.on_secondary,
+            "secondary_container" => self.secondary_container,
+            "on_secondary_container" => self.on_secondary_container,
+            "background" => self.background,
+            "on_background" => self.on_background,
+            "surface" => self.surface,
+            "on_surface" => self.on_surface,
+            "surface_variant" => self.surface_variant,
+            "on_surface_variant" => self.on_surface_variant,
+            "error" => self.error,
+            "on_error" => self.on_error,
+            "outline" => self.outline,
+            _ => return None,
+        })
+    }
+
+    /// Generates the light variant of the scheme from a seed colour.
+    pub fn light(seed: RGBA) -> Self {
+        let palettes = Palettes::new(seed);
+
+        Self {
+            seed,
+            mode: Mode::Light,
+            primary: palettes.primary.tone(40),
+            on_primary: palettes.primary.tone(100),
+            primary_container: palettes.primary.tone(90),
+            on_primary_container: palettes.primary.tone(10),
+            secondary: palettes.secondary.tone(40),
+            on_secondary: palettes.secondary.tone(100),
+            secondary_container: palettes.secondary.tone(90),
+            on_secondary_container: palettes.secondary.tone(10),
+            background: palettes.neutral.tone(99),
+            on_background: palettes.neutral.tone(10),
+            surface: palettes.neutral.tone(98),
+            on_surface: palettes.neutral.tone(10),
+            surface_variant: palettes.neutral_variant.tone(90),
+            on_surface_variant: palettes.neutral_variant.tone(30),
+            error: palettes.error.tone(40),
+            on_error: palettes.error.tone(100),
+            outline: palettes.neutral_variant.tone(50),
+        }
+    }
+
+    /// Generates the dark variant of the scheme from a seed colour.
+    pub fn dark(seed: RGBA) -> Self {
+        let palettes = Palettes::new(seed);
+
+        Self {
+            seed,
+            mode: Mode::Dark,
+            primary: palettes.primary.tone(80),
+            on_primary: palettes.primary.tone(20),
+            primary_container: palettes.primary.tone(30),
+            on_primary_container: palettes.primary.tone(90),
+            secondary: palettes.secondary.tone(80),
+            on_secondary: palettes.secondary.tone(20),
+            secondary_container: palettes.secondary.tone(30),
+            on_secondary_container: palettes.secondary.tone(90),
+            background: palettes.neutral.tone(10),
+            on_background: palettes.neutral.tone(90),
+            surface: palettes.neutral.tone(12),
+            on_surface: palettes.neutral.tone(90),
+            surface_variant: palettes.neutral_variant.tone(30),
+            on_surface_variant: palettes.neutral_variant.tone(80),
+            error: palettes.error.tone(80),
+            on_error: palettes.error.tone(20),
+            outline: palettes.neutral_variant.tone(60),
+        }
+    }
+
+    /// Generates the high contrast variant of the scheme from a seed colour.
+    /// 
+    /// Texts are black over white surfaces, and white over very dark role 
+    /// colours.
+    pub fn high_contrast(seed: RGBA) -> Self {
+        let palettes = Palettes::new(seed);
+
+        Self {
+            seed,
+            mode: Mode::HighContrast,
+            primary: palettes.primary.tone(20),
+            on_primary: palettes.primary.tone(100),
+            primary_container: palettes.primary.tone(95),
+            on_primary_container: palettes.primary.tone(0),
+            secondary: palettes.secondary.tone(20),
+            on_secondary: palettes.secondary.tone(100),
+            secondary_container: palettes.secondary.tone(95),
+            on_secondary_container: palettes.secondary.tone(0),
+            background: palettes.neutral.tone(100),
+            on_background: palettes.neutral.tone(0),
+            surface: palettes.neutral.tone(100),
+            on_surface: palettes.neutral.tone(0),
+            surface_variant: palettes.neutral_variant.tone(95),
+            on_surface_variant: palettes.neutral_variant.tone(0),
+            error: palettes.error.tone(25),
+            on_error: palettes.error.tone(100),
+            outline: palettes.neutral_variant.tone(0),
+        }
+    }
+}
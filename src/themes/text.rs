@@ -7,6 +7,7 @@ use crate::FontWeight;
 /// Style for a text such as its size, font weight and spacing between the 
 /// letters.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextStyle {
     /// Size of the text.
     pub size: i32,
@@ -17,7 +18,8 @@ pub struct TextStyle {
 }
 
 /// Text styles for every text such as headings, paragraphs and code blocks...
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextTheme {
     /// Font style for code blocks.
     pub code: TextStyle,
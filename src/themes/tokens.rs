@@ -0,0 +1,257 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use std::collections::HashMap;
+
+use crate::{graphics::colours::RGBA, Radius};
+
+/// Step of a scale of the [`Tokens`], referenced instead of a number.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Scale {
+    /// Nothing, usually zero.
+    None,
+    /// The smallest step.
+    ExtraSmall,
+    /// A small step.
+    Small,
+    /// The step between small and large.
+    Medium,
+    /// A large step.
+    Large,
+    /// The largest step.
+    ExtraLarge,
+}
+
+/// Elevation level of a surface. The higher a surface is, the more it is
+/// tinted with the primary colour.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Elevation {
+    /// Flat surface.
+    Level0,
+    #[allow(missing_docs)]
+    Level1,
+    #[allow(missing_docs)]
+    Level2,
+    #[allow(missing_docs)]
+    Level3,
+    #[allow(missing_docs)]
+    Level4,
+    /// The highest surface.
+    Level5,
+}
+
+/// Values of a scale, for each [`Scale`] step.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScaleValues<T> {
+    #[allow(missing_docs)]
+    pub none: T,
+    #[allow(missing_docs)]
+    pub extra_small: T,
+    #[allow(missing_docs)]
+    pub small: T,
+    #[allow(missing_docs)]
+    pub medium: T,
+    #[allow(missing_docs)]
+    pub large: T,
+    #[allow(missing_docs)]
+    pub extra_large: T,
+}
+
+impl<T: Copy> ScaleValues<T> {
+    /// Returns the value of a step.
+    pub fn get(&self, scale: Scale) -> T {
+        match scale {
+            Scale::None => self.none,
+            Scale::ExtraSmall => self.extra_small,
+            Scale::Small => self.small,
+            Scale::Medium => self.medium,
+            Scale::Large => self.large,
+            Scale::ExtraLarge => self.extra_large,
+        }
+    }
+}
+
+/// Spacing scale, in pixels.
+pub type SpacingScale = ScaleValues<usize>;
+
+/// Radius scale, in degrees.
+pub type RadiusScale = ScaleValues<f32>;
+
+impl Default for SpacingScale {
+    fn default() -> Self {
+        Self {
+            none: 0,
+            extra_small: 4,
+            small: 8,
+            medium: 16,
+            large: 24,
+            extra_large: 32,
+        }
+    }
+}
+
+impl Default for RadiusScale {
+    fn default() -> Self {
+        Self {
+            none: 0.0,
+            extra_small: 4.0,
+            small: 8.0,
+            medium: 12.0,
+            large: 16.0,
+            extra_large: 28.0,
+        }
+    }
+}
+
+/// Elevation scale, as the opacity of the primary colour layered over an
+/// elevated surface.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElevationScale {
+    #[allow(missing_docs)]
+    pub level0: f32,
+    #[allow(missing_docs)]
+    pub level1: f32,
+    #[allow(missing_docs)]
+    pub level2: f32,
+    #[allow(missing_docs)]
+    pub level3: f32,
+    #[allow(missing_docs)]
+    pub level4: f32,
+    #[allow(missing_docs)]
+    pub level5: f32,
+}
+
+impl ElevationScale {
+    /// Returns the opacity of a level.
+    pub fn get(&self, elevation: Elevation) -> f32 {
+        match elevation {
+            Elevation::Level0 => self.level0,
+            Elevation::Level1 => self.level1,
+            Elevation::Level2 => self.level2,
+            Elevation::Level3 => self.level3,
+            Elevation::Level4 => self.level4,
+            Elevation::Level5 => self.level5,
+        }
+    }
+}
+
+impl Default for ElevationScale {
+    fn default() -> Self {
+        Self {
+            level0: 0.0,
+            level1: 0.05,
+            level2: 0.08,
+            level3: 0.11,
+            level4: 0.12,
+            level5: 0.14,
+        }
+    }
+}
+
+/// Shared vocabulary of the theme for spacing, radii, elevations and colours.
+///
+/// Styles, paddings, gaps and borders reference the tokens instead of
+/// numbers. They are resolved when building the drawables, so changing a
+/// token restyles every widget referencing it.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tokens {
+    /// Spacing scale, for paddings, gaps and borders.
+    pub spacing: SpacingScale,
+    /// Radius scale.
+    pub radius: RadiusScale,
+    /// Elevation scale.
+    pub elevation: ElevationScale,
+    /// Named colours.
+    ///
+    /// The roles of the [`ColourScheme`](super::ColourScheme) can also be
+    /// referenced by their names (`primary`, `on_surface`...), the named
+    /// colours come first.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub colours: HashMap<String, RGBA>,
+}
+
+/// Length in pixels, either given directly or referenced in the spacing
+/// scale of the [`Tokens`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum Spacing {
+    /// Number of pixels.
+    Pixels(usize),
+    /// Step of the spacing scale.
+    Token(Scale),
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self::Pixels(0)
+    }
+}
+
+impl From<usize> for Spacing {
+    fn from(value: usize) -> Self {
+        Self::Pixels(value)
+    }
+}
+
+impl From<Scale> for Spacing {
+    fn from(value: Scale) -> Self {
+        Self::Token(value)
+    }
+}
+
+impl Spacing {
+    /// Returns the number of pixels, following the spacing scale when it is a
+    /// token.
+    pub fn resolve(&self, scale: &SpacingScale) -> usize {
+        match self {
+            Self::Pixels(pixels) => *pixels,
+            Self::Token(token) => scale.get(*token),
+        }
+    }
+
+    /// Returns the number of pixels. A token which was not resolved with a
+    /// theme takes the value of the default spacing scale.
+    pub fn pixels(&self) -> usize {
+        self.resolve(&SpacingScale::default())
+    }
+}
+
+/// References to the [`Tokens`] of the theme from a [`Style`](super::Style).
+///
+/// They replace the properties of the style when the drawables are built.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StyleTokens {
+    /// Name of the colour, in the named colours or the colour scheme.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub colour: Option<String>,
+    /// Step of the radius scale.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub radius: Option<Scale>,
+    /// Elevation level, tinting the colour.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub elevation: Option<Elevation>,
+}
+
+impl StyleTokens {
+    /// Whether no token is referenced.
+    pub fn is_empty(&self) -> bool {
+        self.colour.is_none() && self.radius.is_none() && self.elevation.is_none()
+    }
+}
+
+impl Tokens {
+    /// Returns the radius of a step of the radius scale.
+    pub fn radius(&self, scale: Scale) -> Radius {
+        Radius::new(self.radius.get(scale))
+    }
+}
@@ -4,10 +4,88 @@
 
 use crate::{graphics::colours::RGBA, Border, Radius};
 
-use super::text::TextStyle;
+use super::{text::TextStyle, StyleTokens};
+
+/// Kind of widget a primitive widget ([`Surface`](crate::widgets::Surface), 
+/// [`Label`](crate::widgets::Label)...) is drawn for.
+/// 
+/// When the primitive widget does not have an independent style, it is used
+/// to find its style in the global theme. For example, the surface of a 
+/// button is coloured with the primary colour of the 
+/// [`ColourScheme`](super::ColourScheme).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Component {
+    /// Drawn for a [`Button`](crate::widgets::Button).
+    Button,
+    /// Drawn for an [`Image`](crate::widgets::Image).
+    Image,
+    /// Drawn for a [`Layout`](crate::widgets::Layout).
+    Layout,
+    /// Drawn for a [`Surface`](crate::widgets::Surface).
+    #[default]
+    Surface,
+    /// Drawn for a [`TextField`](crate::widgets::TextField).
+    Input,
+    /// Drawn for the text cursor of a 
+    /// [`TextField`](crate::widgets::TextField).
+    Caret,
+    /// Drawn behind the selected text of a 
+    /// [`TextField`](crate::widgets::TextField).
+    Selection,
+    /// Drawn for the text shown in an empty 
+    /// [`TextField`](crate::widgets::TextField).
+    Placeholder,
+    /// Drawn for a [`Checkbox`](crate::widgets::Checkbox), a 
+    /// [`Radio`](crate::widgets::Radio) or a [`Switch`](crate::widgets::Switch)
+    /// which is off.
+    Toggle,
+    /// Drawn for a [`Checkbox`](crate::widgets::Checkbox), a 
+    /// [`Radio`](crate::widgets::Radio) or a [`Switch`](crate::widgets::Switch)
+    /// which is on.
+    ToggleSelected,
+    /// Drawn for the dot of a [`Radio`](crate::widgets::Radio) and the thumb
+    /// of a [`Switch`](crate::widgets::Switch).
+    ToggleMark,
+    /// Drawn for the track of a [`Slider`](crate::widgets::Slider) and behind
+    /// the indicator of a [`ProgressBar`](crate::widgets::ProgressBar) or a
+    /// [`Spinner`](crate::widgets::Spinner).
+    Track,
+    /// Drawn for the part of the track of a 
+    /// [`Slider`](crate::widgets::Slider) in the selected range, and for the
+    /// progress of a [`ProgressBar`](crate::widgets::ProgressBar) or a 
+    /// [`Spinner`](crate::widgets::Spinner).
+    Indicator,
+    /// Drawn for the thumbs of a [`Slider`](crate::widgets::Slider).
+    Thumb,
+    /// Drawn for a [`Menu`](crate::widgets::Menu).
+    Menu,
+    /// Drawn behind the highlighted item of a 
+    /// [`Menu`](crate::widgets::Menu), and behind the selected row of a 
+    /// [`TreeView`](crate::widgets::TreeView).
+    MenuHighlight,
+    /// Drawn for the headers of a [`Table`](crate::widgets::Table).
+    TableHeader,
+    /// Drawn behind the cells of the selected row of a 
+    /// [`Table`](crate::widgets::Table).
+    TableSelected,
+    /// Drawn for a [`NavigationBar`](crate::widgets::NavigationBar), and for
+    /// the bar of [`Tabs`](crate::widgets::Tabs).
+    Navigation,
+    /// Drawn behind the selected item of a 
+    /// [`NavigationBar`](crate::widgets::NavigationBar).
+    NavigationSelected,
+    /// Drawn for a [`Dialog`](crate::controllers::dialog::Dialog).
+    Dialog,
+    /// Drawn over the view, under a modal [`Popup`](crate::widgets::Popup).
+    Barrier,
+    /// Drawn for a [`Toast`](crate::controllers::dialog::Toast).
+    Toast,
+}
 
 /// Style for a label.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LabelStyle {
     /// The colour of the text.
     pub colour: RGBA,
@@ -15,27 +93,108 @@ pub struct LabelStyle {
     /// 
     /// If set as `None`, the default text style from the global theme will be 
     /// used.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
     pub text_style: Option<TextStyle>,
 }
 
 /// Style for any widget.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     /// Independent colour from the global theme.
     /// 
     /// If set as `None`, use the colour of the default widget style from the 
     /// global theme.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
     pub colour: Option<RGBA>,
     /// Independent borders from the global theme.
     /// 
     /// If set as `None`, use the borders of the default widget style from the 
     /// global theme.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none", with = "borders"))]
     pub borders: Option<[Option<Border>; 4]>,
     /// Independent radius from the global theme.
     /// 
     /// If set as `None`, use the radius of the default widget style from the 
     /// global theme.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
     pub radius: Option<Radius>,
+    /// References to the tokens of the global theme, replacing the properties
+    /// above when the drawables are built.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "StyleTokens::is_empty"))]
+    pub tokens: StyleTokens,
+}
+
+/// Serialization of the borders of a [`Style`] as a list of the defined 
+/// borders, since TOML does not have null values. The side of a border is 
+/// given by the border itself, not by its index.
+#[cfg(feature = "serde")]
+mod borders {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Border;
+
+    pub fn serialize<S>(borders: &Option<[Option<Border>; 4]>, serializer: S) -> Result<S::Ok, S::Error> 
+    where 
+        S: Serializer,
+    {
+        borders
+            .as_ref()
+            .map(|borders| borders.iter().flatten().collect::<Vec<&Border>>())
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<[Option<Border>; 4]>, D::Error>
+    where 
+        D: Deserializer<'de>,
+    {
+        let list: Option<Vec<Border>> = Option::deserialize(deserializer)?;
+
+        list.map(|list| {
+            if list.len() > 4 {
+                return Err(D::Error::invalid_length(list.len(), &"at most 4 borders"));
+            }
+
+            let mut borders = [None, None, None, None];
+
+            for (i, border) in list.into_iter().enumerate() {
+                borders[i] = Some(border);
+            }
+
+            Ok(borders)
+        })
+        .transpose()
+    }
+}
+
+impl Style {
+    /// Returns the style where the undefined properties are taken from the 
+    /// `fallback` style.
+    /// 
+    /// A property referencing a token is defined, so the property and its 
+    /// token are both taken from the same style.
+    pub fn merged(&self, fallback: &Style) -> Style {
+        let (colour, colour_token) = match self.colour.is_some() || self.tokens.colour.is_some() {
+            true => (self.colour, self.tokens.colour.clone()),
+            false => (fallback.colour, fallback.tokens.colour.clone()),
+        };
+
+        let (radius, radius_token) = match self.radius.is_some() || self.tokens.radius.is_some() {
+            true => (self.radius, self.tokens.radius),
+            false => (fallback.radius, fallback.tokens.radius),
+        };
+
+        Style {
+            colour,
+            borders: self.borders.clone().or_else(|| fallback.borders.clone()),
+            radius,
+            tokens: StyleTokens {
+                colour: colour_token,
+                radius: radius_token,
+                elevation: self.tokens.elevation.or(fallback.tokens.elevation),
+            },
+        }
+    }
 }
 
 /// Creates ""dynamically"" a new [`Style`].
@@ -46,6 +205,7 @@ macro_rules! style {
             colour: Some($colour),
             borders: Some($borders),
             radius: Some($radius),
+            tokens: Default::default(),
         }
     };
 
@@ -54,6 +214,7 @@ macro_rules! style {
             colour: Some($colour),
             borders: Some($borders),
             radius: None,
+            tokens: Default::default(),
         }
     };
 
@@ -62,6 +223,7 @@ macro_rules! style {
             colour: Some($colour),
             borders: None,
             radius: Some($radius),
+            tokens: Default::default(),
         }
     };
 
@@ -70,6 +232,7 @@ macro_rules! style {
             colour: $colour,
             borders: Some($borders),
             radius: Some($radius),
+            tokens: Default::default(),
         }
     };
 
@@ -78,6 +241,7 @@ macro_rules! style {
             colour: Some($colour),
             borders: None,
             radius: None,
+            tokens: Default::default(),
         }
     };
 
@@ -86,6 +250,7 @@ macro_rules! style {
             colour: None,
             borders: Some($borders),
             radius: None,
+            tokens: Default::default(),
         }
     };
 
@@ -94,6 +259,7 @@ macro_rules! style {
             colour: None,
             borders: None,
             radius: Some($radius),
+            tokens: Default::default(),
         }
     };
 }
@@ -42,4 +42,28 @@ impl Zone {
     pub fn height(self) -> usize {
         self.size[1]
     }
+
+    /// Whether the point is in the zone. The right and bottom edges are not 
+    /// in the zone, so the point is in only one of two zones side by side.
+    pub fn contains(self, point: Point) -> bool {
+        point[0] >= self.x() && point[0] < self.x() + self.width() as isize
+            && point[1] >= self.y() && point[1] < self.y() + self.height() as isize
+    }
+
+    /// Returns the zone covered by both zones, if they overlap.
+    pub fn intersection(self, other: Zone) -> Option<Zone> {
+        let left = self.x().max(other.x());
+        let top = self.y().max(other.y());
+        let right = (self.x() + self.width() as isize).min(other.x() + other.width() as isize);
+        let bottom = (self.y() + self.height() as isize).min(other.y() + other.height() as isize);
+
+        if right <= left || bottom <= top {
+            return None;
+        }
+
+        Some(Zone {
+            position: Point::from([left, top]),
+            size: Size::from([(right - left) as usize, (bottom - top) as usize]),
+        })
+    }
 }
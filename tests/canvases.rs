@@ -0,0 +1,167 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Canvases drawn by a function recording commands in their zone.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::{cell::RefCell, rc::Rc};
+
+use haussmann::{
+    graphics::{colours::RGBA, draw::Object, Transform},
+    themes::{Component, LabelStyle},
+    widgets::{Canvas, Command, Container, Context, Image, Label, Path, Stroke, Transformed},
+    Direction,
+    Zone,
+};
+
+use common::{built, view};
+
+/// Draws a bar over a background, crossed by a line, with a caption.
+fn bars(zone: Zone, context: &mut Context) {
+    context.rect(zone, RGBA::new(255, 255, 255, 255));
+    context.rect(([zone.x() + 10, zone.y() + 10], [10, 20]).into(), RGBA::new(200, 0, 0, 255));
+    context.line(
+        [zone.x() as f32, zone.y() as f32],
+        [(zone.x() + zone.width() as isize) as f32, zone.y() as f32],
+        RGBA::new(0, 0, 0, 255),
+        2.0,
+    );
+    context.text(([zone.x(), zone.y() + 30], [zone.width(), 10]).into(), "bars");
+}
+
+#[test]
+fn recorded() {
+    let view = view([200, 100], Direction::Column, vec![
+        Container::new([40, 40], Canvas::new(bars)).into(),
+        Label::normal("canvases").into(),
+    ]);
+    let drawables = view.build();
+    let canvases = built(&drawables, |object| matches!(object, Object::Canvas(_)));
+
+    assert_eq!(canvases.len(), 1);
+    assert_eq!(canvases[0].zone, ([0, 0], [40, 40]).into());
+    assert!(canvases[0].style.is_none() && canvases[0].label_style.is_none());
+
+    let Object::Canvas(commands) = &canvases[0].object else {
+        unreachable!();
+    };
+
+    // Drawn in the final zone of the canvas, in the order of the commands.
+    assert_eq!(commands, &vec![
+        Command::Rect {
+            zone: ([0, 0], [40, 40]).into(),
+            colour: RGBA::new(255, 255, 255, 255),
+        },
+        Command::Rect {
+            zone: ([10, 10], [10, 20]).into(),
+            colour: RGBA::new(200, 0, 0, 255),
+        },
+        Command::Line {
+            from: [0.0, 0.0],
+            to: [40.0, 0.0],
+            stroke: Stroke {
+                colour: RGBA::new(0, 0, 0, 255),
+                width: 2.0,
+            },
+        },
+        // The style of the text is resolved with the theme of the view.
+        Command::Text {
+            zone: ([0, 30], [40, 10]).into(),
+            text: "bars".to_string(),
+            label_style: view.theme().label_style.clone(),
+        },
+    ]);
+}
+
+#[test]
+fn moved_and_transformed() {
+    let view = view([200, 100], Direction::Column, vec![
+        Label::normal("canvases").into(),
+        Container::new([40, 40], Transformed::new(Transform::scaled(2.0, 2.0), Canvas::new(bars))).into(),
+    ]);
+    let drawables = view.build();
+    let canvas = built(&drawables, |object| matches!(object, Object::Canvas(_)))[0];
+
+    // Recorded in the zone where the canvas is placed, and transformed with
+    // its drawable.
+    let Object::Canvas(commands) = &canvas.object else {
+        unreachable!();
+    };
+    assert_eq!(commands[0], Command::Rect {
+        zone: canvas.zone,
+        colour: RGBA::new(255, 255, 255, 255),
+    });
+    assert!(canvas.zone.x() > 0);
+    assert!(!canvas.transform.is_identity());
+}
+
+#[test]
+fn paths_and_images() {
+    fn draw(zone: Zone, context: &mut Context) {
+        let triangle = Path::parse("M0 0H10L5 10Z").unwrap().with_view_box([10.0, 10.0]);
+        context.path(triangle.with_fill(RGBA::new(0, 200, 0, 255)), zone);
+
+        let image = Image {
+            style: None,
+            ratio: (1.0, 1.0),
+        };
+        context.image(zone, image);
+
+        let style = LabelStyle {
+            colour: RGBA::new(0, 0, 200, 255),
+            text_style: None,
+        };
+        context.styled_text(zone, "styled", style);
+
+        assert_eq!(context.commands().len(), 3);
+        assert_eq!(context.zone(), zone);
+    }
+
+    let theme = haussmann::themes::default(vec![]);
+    let commands = Canvas::new(draw).record(([5, 5], [20, 20]).into(), &theme);
+
+    assert!(matches!(&commands[0], Command::Path { zone, .. } if *zone == ([5, 5], [20, 20]).into()));
+    assert!(matches!(&commands[1], Command::Image { image, .. } if image.style == Some(theme.component_style(Component::Image))));
+    assert!(matches!(&commands[2], Command::Text { label_style, .. } if label_style.colour == RGBA::new(0, 0, 200, 255)));
+}
+
+#[test]
+fn captured_data() {
+    // The canvas draws a bar for each value of the data it captures.
+    let values = Rc::new(RefCell::new(vec![10, 30, 20]));
+    let data = values.clone();
+    let chart = Canvas::new(move |zone, context| {
+        for (i, value) in data.borrow().iter().enumerate() {
+            let bar = ([zone.x() + i as isize * 10, zone.y()], [10, *value]).into();
+            context.rect(bar, RGBA::new(0, 0, 200, 255));
+        }
+    });
+
+    let view = view([200, 100], Direction::Column, vec![Container::new([40, 40], chart).into()]);
+    let bars = |drawables: &[_]| -> Vec<Zone> {
+        let Object::Canvas(commands) = &built(drawables, |object| matches!(object, Object::Canvas(_)))[0].object else {
+            unreachable!();
+        };
+
+        commands
+            .iter()
+            .map(|command| match command {
+                Command::Rect { zone, .. } => *zone,
+                _ => unreachable!(),
+            })
+            .collect()
+    };
+
+    assert_eq!(bars(&view.build()), [
+        ([0, 0], [10, 10]).into(),
+        ([10, 0], [10, 30]).into(),
+        ([20, 0], [10, 20]).into(),
+    ]);
+
+    // The data changed since is drawn at the next build.
+    values.borrow_mut().push(40);
+    assert_eq!(bars(&view.build()).len(), 4);
+}
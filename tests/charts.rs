@@ -0,0 +1,207 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Line, bar and pie charts built as surfaces, labels and paths.
+
+use haussmann::{
+    graphics::{colours::RGBA, draw::{Drawable, Object}},
+    widgets::{Axis, Chart, Layout, ValueScale, Segment, Series, View},
+    Align,
+    Direction,
+    Overflow,
+    Zone,
+};
+
+const RED: RGBA = RGBA { r: 200, g: 0, b: 0, a: 255 };
+const BLUE: RGBA = RGBA { r: 0, g: 0, b: 200, a: 255 };
+
+/// A view of size `size` filled with a chart.
+fn view(size: [usize; 2], chart: Chart) -> View {
+    View::new(([0, 0], size).into(), Layout::normal(
+        Overflow::Hide,
+        Align::Left,
+        Align::Top,
+        Direction::Row,
+        vec![chart.into()],
+    ))
+}
+
+/// Returns the zones of the surfaces of a colour.
+fn surfaces(drawables: &[Drawable], colour: RGBA) -> Vec<Zone> {
+    drawables
+        .iter()
+        .filter(|drawable| matches!(drawable.object, Object::Surface(_)))
+        .filter(|drawable| drawable.style.as_ref().unwrap().colour == Some(colour))
+        .map(|drawable| drawable.zone)
+        .collect()
+}
+
+/// Returns the texts of the labels with their zones.
+fn labels(drawables: &[Drawable]) -> Vec<(String, Zone)> {
+    drawables
+        .iter()
+        .filter_map(|drawable| match &drawable.object {
+            Object::Label(label) => Some((label.text.clone(), drawable.zone)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn scales() {
+    let scale = ValueScale::new(0.0, 87.0, 5);
+    assert_eq!(scale, ValueScale { min: 0.0, max: 100.0, step: 20.0 });
+    assert_eq!(scale.ticks(), vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+    assert_eq!(scale.position(50.0, 200), 100.0);
+
+    // Small steps are written with decimals.
+    let scale = ValueScale::new(0.0, 1.0, 5);
+    let ticks: Vec<String> = scale.ticks().iter().map(|tick| scale.format(*tick)).collect();
+    assert_eq!(ticks, vec!["0.0", "0.2", "0.4", "0.6", "0.8", "1.0"]);
+
+    // Same value everywhere.
+    let scale = ValueScale::new(5.0, 5.0, 5);
+    assert!(scale.min < 5.0 && scale.max > 5.0);
+    assert_eq!(ValueScale::new(0.0, 0.0, 5), ValueScale { min: 0.0, max: 1.0, step: 0.2 });
+
+    // Negative values.
+    assert_eq!(ValueScale::new(-10.0, 30.0, 5), ValueScale { min: -10.0, max: 30.0, step: 10.0 });
+    assert_eq!(ValueScale::new(-10.0, 30.0, 5).format(-0.0), "0");
+
+    // Bar charts always go from zero, and the axis can be fixed.
+    let chart = Chart::bar(&["a"], vec![Series::new("s", &[12.0, 18.0])]);
+    assert_eq!(chart.scale(), ValueScale { min: 0.0, max: 20.0, step: 5.0 });
+
+    let chart = Chart::line(&["a"], vec![Series::new("s", &[12.0, 18.0])]);
+    assert_eq!(chart.scale(), ValueScale { min: 12.0, max: 18.0, step: 2.0 });
+
+    let chart = chart.with_axis(Axis { min: Some(0.0), max: Some(100.0), ticks: 3 });
+    assert_eq!(chart.scale(), ValueScale { min: 0.0, max: 100.0, step: 50.0 });
+}
+
+#[test]
+fn bars() {
+    let chart = Chart::bar(&["a", "b"], vec![
+        Series::new("first", &[10.0, 30.0]).with_colour(RED),
+        Series::new("second", &[20.0, -10.0]).with_colour(BLUE),
+    ]);
+
+    // A plot of 200x100 under the legend, from -10 to 30.
+    assert_eq!(chart.plot_zone([248, 148]), ([48, 24], [200, 100]).into());
+
+    let view = view([248, 148], chart);
+    let drawables = view.build();
+
+    // The swatch of the legend, then a bar for each value, from zero.
+    assert_eq!(surfaces(&drawables, RED), vec![
+        ([4, 6], [12, 12]).into(),
+        ([58, 74], [40, 25]).into(),
+        ([158, 24], [40, 75]).into(),
+    ]);
+    assert_eq!(surfaces(&drawables, BLUE), vec![
+        ([128, 6], [12, 12]).into(),
+        ([98, 49], [40, 50]).into(),
+        ([198, 99], [40, 25]).into(),
+    ]);
+
+    let labels = labels(&drawables);
+    let texts: Vec<&str> = labels.iter().map(|(text, _)| text.as_str()).collect();
+    assert_eq!(texts, vec!["first", "second", "-10", "0", "10", "20", "30", "a", "b"]);
+
+    // The ticks face their values, the categories are under their bars.
+    assert_eq!(labels[3].1, ([0, 91], [44, 16]).into());
+    assert_eq!(labels[7].1, ([48, 124], [100, 24]).into());
+    assert_eq!(labels[8].1, ([148, 124], [100, 24]).into());
+
+    // Everything shares the identifier of the chart, hidden out of its zone.
+    let parts: Vec<&Drawable> = drawables.iter().filter(|drawable| drawable.group_id == 1).collect();
+    assert_eq!(parts.len(), 1 + 4 + 5 * 2 + 2 + 4 + 2);
+    assert!(parts[1..].iter().all(|part| part.clip == Some(([0, 0], [248, 148]).into())));
+}
+
+#[test]
+fn lines() {
+    let chart = Chart::line(&["a", "b", "c"], vec![
+        Series::new("first", &[0.0, 10.0, 20.0]).with_colour(RED),
+        Series::new("second", &[5.0, f32::NAN, 15.0]).with_colour(BLUE),
+    ]);
+
+    let view = view([348, 148], chart);
+    let drawables = view.build();
+
+    let paths: Vec<(&Drawable, _)> = drawables
+        .iter()
+        .filter_map(|drawable| match &drawable.object {
+            Object::Path(path) => Some((drawable, path)),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(paths.len(), 2);
+
+    // Placed in the plot, through the middle of each category.
+    let (drawable, path) = paths[0];
+    assert_eq!(drawable.zone, ([48, 24], [300, 100]).into());
+    assert_eq!(path.stroke.unwrap().colour, RED);
+    assert_eq!(path.segments, vec![
+        Segment::MoveTo([50.0, 100.0]),
+        Segment::LineTo([150.0, 50.0]),
+        Segment::LineTo([250.0, 0.0]),
+    ]);
+
+    // A missing value breaks the line.
+    assert_eq!(paths[1].1.segments, vec![
+        Segment::MoveTo([50.0, 75.0]),
+        Segment::MoveTo([250.0, 25.0]),
+    ]);
+}
+
+#[test]
+fn pie() {
+    let chart = Chart::pie(&["a", "b", "c"], &[1.0, 1.0, 2.0]);
+    let pies = view([200, 224], chart.clone());
+    let drawables = pies.build();
+
+    let paths: Vec<(&Drawable, _)> = drawables
+        .iter()
+        .filter_map(|drawable| match &drawable.object {
+            Object::Path(path) => Some((drawable, path)),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(paths.len(), 3);
+
+    for (i, (drawable, path)) in paths.iter().enumerate() {
+        assert_eq!(drawable.zone, ([0, 24], [200, 200]).into());
+        assert_eq!(path.fill, Some(chart.colour(pies.theme(), i)));
+
+        // From the centre, in the disc.
+        assert_eq!(path.segments[0], Segment::MoveTo([100.0, 100.0]));
+        for polyline in path.flatten(0.1) {
+            assert!(polyline.closed);
+            for [x, y] in polyline.points {
+                assert!(((x - 100.0).powi(2) + (y - 100.0).powi(2)).sqrt() <= 100.01);
+            }
+        }
+    }
+
+    // The slices have different colours, named by the legend.
+    assert_ne!(paths[0].1.fill, paths[1].1.fill);
+    let texts: Vec<String> = labels(&drawables).into_iter().map(|(text, _)| text).collect();
+    assert_eq!(texts, vec!["a", "b", "c"]);
+
+    // The only slice is the whole disc.
+    let drawables = view([200, 200], Chart::pie(&["all"], &[3.0]).without_legend()).build();
+    let whole = drawables
+        .iter()
+        .find_map(|drawable| match &drawable.object {
+            Object::Path(path) => Some(path),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_eq!(whole.segments.len(), 4);
+    assert!(labels(&drawables).is_empty());
+}
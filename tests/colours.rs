@@ -0,0 +1,78 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Conversions, parsing and blending of colours.
+
+use haussmann::graphics::colours::{self, ParseColourError, HSL, HSV, RGBA, RGBAf};
+
+#[test]
+fn hex_round_trip() {
+    let colour = RGBA::from_hex(0x12345678);
+
+    assert_eq!(colour, RGBA::new(0x12, 0x34, 0x56, 0x78));
+    assert_eq!(colour.to_hex(), 0x12345678);
+    assert_eq!(colour.to_string(), "#12345678");
+    assert_eq!(colour.to_string().parse::<RGBA>(), Ok(colour));
+}
+
+#[test]
+fn parse() {
+    let orange = RGBA::new(255, 136, 0, 255);
+
+    assert_eq!("#f80".parse(), Ok(orange));
+    assert_eq!("#FF8800".parse(), Ok(orange));
+    assert_eq!("#ff880080".parse(), Ok(orange.with_alpha(128)));
+    assert_eq!("rgb(255, 136, 0)".parse(), Ok(orange));
+    assert_eq!("rgba(255, 136, 0, 0.5)".parse(), Ok(orange.with_alpha(128)));
+    assert_eq!("rgb(100% 0% 0% / 50%)".parse(), Ok(colours::RED.with_alpha(128)));
+    assert_eq!("hsl(120, 100%, 50%)".parse(), Ok(RGBA::new(0, 255, 0, 255)));
+    assert_eq!(" White ".parse(), Ok(colours::WHITE));
+    assert_eq!("transparent".parse(), Ok(colours::TRANSPARENT));
+
+    assert_eq!("#ff88".parse::<RGBA>(), Ok(RGBA::new(255, 255, 136, 136)));
+    assert_eq!("#ff8".parse::<RGBA>().map(|c| c.to_hex()), Ok(0xffff88ff));
+    assert_eq!("#ff88000".parse::<RGBA>(), Err(ParseColourError::InvalidHex("ff88000".to_string())));
+    assert_eq!("rgb(1, 2)".parse::<RGBA>(), Err(ParseColourError::ArgumentCount(2)));
+    assert_eq!("rgb(1, x, 2)".parse::<RGBA>(), Err(ParseColourError::InvalidNumber("x".to_string())));
+    assert_eq!("lab(1, 2, 3)".parse::<RGBA>(), Err(ParseColourError::UnknownFunction("lab".to_string())));
+    assert_eq!("bleu".parse::<RGBA>(), Err(ParseColourError::UnknownName("bleu".to_string())));
+}
+
+#[test]
+fn hsl_hsv_round_trip() {
+    for hex in [0x000000ff, 0xffffffff, 0xff8800ff, 0x12345678, 0x7f3fbf40, 0x00ff80ff] {
+        let colour = RGBA::from_hex(hex);
+
+        assert_eq!(RGBA::from(HSL::from(colour)), colour);
+        assert_eq!(RGBA::from(HSV::from(colour)), colour);
+        assert_eq!(RGBA::from(RGBAf::from(colour)), colour);
+    }
+
+    let hsl = HSL::from(RGBA::new(255, 0, 0, 255));
+    assert_eq!((hsl.h, hsl.s, hsl.l), (0.0, 1.0, 0.5));
+
+    let hsv = HSV::from(RGBA::new(0, 0, 255, 255));
+    assert_eq!((hsv.h, hsv.s, hsv.v), (240.0, 1.0, 1.0));
+}
+
+#[test]
+fn blending() {
+    let grey = RGBA::new(128, 128, 128, 255);
+
+    assert_eq!(colours::BLACK.mix(colours::WHITE, 0.5), grey);
+    assert_eq!(grey.lighten(1.0), colours::WHITE);
+    assert_eq!(grey.darken(1.0), colours::BLACK);
+
+    assert_eq!(colours::RED.over(colours::BLUE), colours::RED);
+    assert_eq!(colours::TRANSPARENT.over(colours::BLUE), colours::BLUE);
+    assert_eq!(colours::RED.with_alpha(128).over(colours::BLUE), RGBA::new(128, 0, 127, 255));
+}
+
+#[test]
+fn contrast() {
+    assert!((colours::BLACK.contrast_ratio(colours::WHITE) - 21.0).abs() < 0.01);
+    assert!((colours::RED.contrast_ratio(colours::RED) - 1.0).abs() < 0.01);
+    assert!(colours::BLACK.is_readable_on(colours::WHITE));
+    assert!(!RGBA::new(119, 119, 119, 255).is_readable_on(colours::WHITE));
+}
@@ -0,0 +1,108 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Helpers shared by the tests: creating views, clicking in them, finding what
+//! they built and logging the changes told by the functions of controllers.
+//!
+//! Included by each test file with `#[path = "common/mod.rs"]`, so the test
+//! files still run on their own.
+
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use haussmann::{
+    controllers::pointer::{Button, Input},
+    graphics::{draw::{Drawable, Object}, Point, Size},
+    widgets::{Layout, View, Widget},
+    Align,
+    Direction,
+    Overflow,
+};
+
+thread_local! {
+    static CHANGES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Logs a change told by the function of a controller.
+pub fn log(change: String) {
+    CHANGES.with(|changes| changes.borrow_mut().push(change));
+}
+
+/// Returns the changes logged since the last call.
+pub fn logged() -> Vec<String> {
+    CHANGES.with(|changes| changes.borrow_mut().drain(..).collect())
+}
+
+/// Changes logged by the closures of a controller, which own a clone of it.
+pub type Log = Rc<RefCell<Vec<String>>>;
+
+/// Returns the changes logged in a log since the last call.
+pub fn logged_in(log: &Log) -> Vec<String> {
+    log.borrow_mut().drain(..).collect()
+}
+
+/// Creates a view of a size with its widgets in a direction, from its top-left
+/// corner, hidden out of it.
+pub fn view(size: Size, direction: Direction, widgets: Vec<Box<dyn Widget>>) -> View {
+    View::new(([0, 0], size).into(), Layout::normal(
+        Overflow::Hide,
+        Align::Left,
+        Align::Top,
+        direction,
+        widgets,
+    ))
+}
+
+/// Clicks at a position with a button, `at` after the start. Returns the
+/// number of events dispatched for the press.
+pub fn click_with(view: &mut View, drawables: &[Drawable], position: Point, button: Button, at: Duration) -> usize {
+    let down = view.pointer(drawables, Input::Down {
+        position,
+        button,
+        time: at,
+    });
+    view.pointer(drawables, Input::Up {
+        position,
+        button,
+        time: at + Duration::from_millis(10),
+    });
+
+    down.len()
+}
+
+/// Clicks at a position with the primary button. Returns the number of
+/// events dispatched for the press.
+pub fn click(view: &mut View, drawables: &[Drawable], position: Point) -> usize {
+    click_with(view, drawables, position, Button::Primary, Duration::ZERO)
+}
+
+/// Clicks at a position with the primary button, `at` seconds after the
+/// start so that the clicks at other seconds are not double-clicks.
+pub fn click_at(view: &mut View, drawables: &[Drawable], position: Point, at: u64) -> usize {
+    click_with(view, drawables, position, Button::Primary, Duration::from_secs(at))
+}
+
+/// Returns the middle of the first drawable built for an identifier.
+pub fn centre(drawables: &[Drawable], id: usize) -> Point {
+    let zone = drawables.iter().find(|drawable| drawable.group_id as usize == id).unwrap().zone;
+    [zone.x() + zone.width() as isize / 2, zone.y() + zone.height() as isize / 2]
+}
+
+/// Returns the texts of the labels built for an identifier.
+pub fn texts(drawables: &[Drawable], id: usize) -> Vec<String> {
+    drawables
+        .iter()
+        .filter(|drawable| drawable.group_id as usize == id)
+        .filter_map(|drawable| match &drawable.object {
+            Object::Label(label) => Some(label.text.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the drawables built with an object matched by a function.
+pub fn built(drawables: &[Drawable], matches: impl Fn(&Object) -> bool) -> Vec<&Drawable> {
+    drawables.iter().filter(|drawable| matches(&drawable.object)).collect()
+}
@@ -0,0 +1,173 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Dialogs and toasts shown over the view.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::time::Duration;
+
+use haussmann::{
+    controllers::{
+        dialog::{Dialog, Toast},
+        keyboard::{self, Key},
+        tap,
+    },
+    graphics::{draw::{Drawable, Object}, Point},
+    themes::Component,
+    widgets::{self, Label, Placement, Popup, Surface, View, Widget},
+    Direction,
+    Zone,
+};
+
+use common::{click, log, logged, view};
+
+/// A view of 400x300 with a button in its top half.
+fn button_view() -> View {
+    view([400, 300], Direction::Row, haussmann::widgets![
+        tap::Detector::new(widgets::Button::normal(Label::normal("tap")), |_, _| log("tapped".to_string())),
+        Surface::normal(),
+    ])
+}
+
+fn dialog() -> Dialog {
+    Dialog::new("Delete the file?", "It cannot be restored.", &["Cancel", "Delete"], |_, action, _| {
+        log(format!("dialog {:?}", action))
+    })
+}
+
+/// Returns the middle of the drawable of a label.
+fn label_at(drawables: &[Drawable], text: &str) -> Point {
+    let zone = drawables
+        .iter()
+        .find(|drawable| matches!(&drawable.object, Object::Label(label) if label.text == text))
+        .unwrap()
+        .zone;
+
+    [zone.x() + zone.width() as isize / 2, zone.y() + zone.height() as isize / 2]
+}
+
+/// Returns the zone of the first surface drawn for a component.
+fn zone_of(drawables: &[Drawable], component: Component) -> Zone {
+    drawables
+        .iter()
+        .find(|drawable| matches!(&drawable.object, Object::Surface(surface) if surface.component == component))
+        .unwrap()
+        .zone
+}
+
+#[test]
+fn placement() {
+    let view = ([0, 0], [400, 300]).into();
+    let anchor = ([20, 20], [50, 20]).into();
+
+    let centre = Popup::new(anchor, Placement::Centre, [200, 100], Surface::normal());
+    assert_eq!(centre.zone_in(view), ([100, 100], [200, 100]).into());
+
+    let bottom = Popup::new(anchor, Placement::Bottom, [200, 40], Surface::normal());
+    assert_eq!(bottom.zone_in(view), ([100, 244], [200, 40]).into());
+}
+
+#[test]
+fn modal() {
+    let mut view = button_view();
+    let drawables = view.build();
+    let button = label_at(&drawables, "tap");
+
+    click(&mut view, &drawables, button);
+    assert_eq!(logged(), ["tapped"]);
+
+    view.show_dialog(dialog());
+    let drawables = view.build();
+
+    // The barrier covers the view, the dialog is in its middle.
+    assert_eq!(zone_of(&drawables, Component::Barrier), ([0, 0], [400, 300]).into());
+    assert_eq!(zone_of(&drawables, Component::Dialog), ([40, 70], [320, 160]).into());
+
+    // The button under the barrier is not reachable, pressing the barrier
+    // does not close the dialog.
+    click(&mut view, &drawables, button);
+    view.focus_next(&drawables);
+    assert_ne!(view.focus().focused(), Some(1));
+    assert!(logged().is_empty());
+    assert_eq!(view.overlay().len(), 1);
+
+    // Escape does not close it either.
+    view.key(&drawables, keyboard::Event::down(Key::Escape));
+    assert_eq!(view.overlay().len(), 1);
+
+    // An action closes it.
+    click(&mut view, &drawables, label_at(&drawables, "Delete"));
+    assert_eq!(logged(), ["dialog Some(1)"]);
+    assert!(view.overlay().is_empty());
+
+    let drawables = view.build();
+    click(&mut view, &drawables, button);
+    assert_eq!(logged(), ["tapped"]);
+}
+
+#[test]
+fn dismissible() {
+    let mut view = button_view();
+    view.show_dialog(dialog().with_dismissible(true));
+    let drawables = view.build();
+
+    // The keyboard reaches the actions of the dialog only, the first one has
+    // the focus when the dialog is shown.
+    let order = view.focus_order(&drawables);
+    assert_eq!(order.len(), 2);
+    assert_eq!(view.focus().focused(), Some(order[0]));
+    view.key(&drawables, keyboard::Event::down(Key::Enter));
+    assert_eq!(logged(), ["dialog Some(0)"]);
+
+    // The focus goes back to the button focused before the dialog.
+    view.set_focus(&drawables, Some(1), true);
+    view.show_dialog(dialog());
+    assert_ne!(view.focus().focused(), Some(1));
+    let drawables = view.build();
+    view.key(&drawables, keyboard::Event::down(Key::Space));
+    assert_eq!(logged(), ["dialog Some(0)"]);
+    assert_eq!(view.focus().focused(), Some(1));
+
+    // Pressing the barrier closes a dismissible dialog, the press is not
+    // dispatched.
+    view.show_dialog(dialog().with_dismissible(true));
+    let drawables = view.build();
+    assert_eq!(click(&mut view, &drawables, [10, 10]), 0);
+    assert_eq!(logged(), ["dialog None"]);
+    assert!(view.overlay().is_empty());
+}
+
+#[test]
+fn toasts() {
+    let mut view = button_view();
+
+    view.show_toast(Toast::new("saved", None, |_, action, _| log(format!("saved {:?}", action))));
+    view.show_toast(
+        Toast::new("deleted", Some("Undo"), |_, action, _| log(format!("deleted {:?}", action)))
+            .with_duration(Duration::from_secs(2)),
+    );
+
+    // One toast is shown at a time, at the bottom of the view.
+    let drawables = view.build();
+    assert_eq!(zone_of(&drawables, Component::Toast), ([40, 236], [320, 48]).into());
+    assert!(view.overlay().is_empty());
+    assert!(drawables.iter().all(|drawable| !matches!(&drawable.object, Object::Label(label) if label.text == "deleted")));
+
+    // The next toast is shown when the time of the first one passed.
+    assert!(!view.advance(&drawables, Duration::from_secs(3)));
+    assert!(view.advance(&drawables, Duration::from_secs(1)));
+    assert_eq!(logged(), ["saved None"]);
+    assert!(view.toast().is_some());
+
+    // Its action closes it, the toast stays over the popups.
+    view.show_dialog(dialog());
+    let drawables = view.build();
+    click(&mut view, &drawables, label_at(&drawables, "Undo"));
+    assert_eq!(logged(), ["deleted Some(0)"]);
+    assert!(view.toast().is_none());
+    assert_eq!(view.overlay().len(), 1);
+    assert!(!view.advance(&drawables, Duration::from_secs(10)));
+}
@@ -0,0 +1,196 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Keyboard focus and keyboard events dispatched to the focused controller.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::time::Duration;
+
+use haussmann::{
+    controllers::{
+        keyboard::{self, Key, Modifiers},
+        pointer::{Button, Input},
+        propagation::Propagation,
+        tap,
+    },
+    graphics::draw::Drawable,
+    themes::{self, State, Theme},
+    widgets::{Label, Layout, View, Widget},
+    widgets,
+    Align,
+    Direction,
+    Overflow,
+};
+
+use common::view;
+
+fn tapped(label: &mut Label, _: &Theme) {
+    label.text.push('!');
+}
+
+/// Writes the typed characters in the text of the label.
+fn type_in(label: &mut Label, event: &keyboard::Event, _: &mut Propagation, _: &Theme) {
+    if let keyboard::Event::Char { character } = event {
+        label.text.push(*character);
+    }
+}
+
+/// Keeps the focus when tab is pressed.
+fn trap(label: &mut Label, event: &keyboard::Event, propagation: &mut Propagation, theme: &Theme) {
+    type_in(label, event, propagation, theme);
+
+    if event.is_down(Key::Tab) {
+        propagation.prevent_default();
+    }
+}
+
+fn layout(widgets: Vec<Box<dyn Widget>>) -> Layout {
+    Layout::normal(Overflow::Ignore, Align::Center, Align::Center, Direction::Column, widgets)
+}
+
+/// The identifiers of the controllers are: `a` 1, `c` 4, `d` 5 and `e` 6.
+fn controllers_view() -> View {
+    let mut disabled = tap::Detector::new(Label::normal("e"), tapped);
+    disabled.state = State::Disabled;
+
+    view([100, 100], Direction::Column, widgets![
+        tap::Detector::new(Label::normal("a"), tapped),
+        Label::normal("b"),
+        layout(widgets![
+            tap::Detector::new(Label::normal("c"), tapped),
+            keyboard::Listener::new(Label::normal("d"), type_in),
+        ]),
+        disabled,
+    ])
+}
+
+/// Returns the state of the drawable built for a controller.
+fn state(drawables: &[Drawable], id: i32) -> State {
+    drawables.iter().find(|drawable| drawable.group_id == id).unwrap().state
+}
+
+fn tab(shift: bool) -> keyboard::Event {
+    keyboard::Event::Down {
+        key: Key::Tab,
+        modifiers: if shift { Modifiers::SHIFT } else { Modifiers::NONE },
+        repeat: false,
+    }
+}
+
+#[test]
+fn tab_order() {
+    let mut view = controllers_view();
+    let drawables = view.build();
+
+    assert_eq!(view.focus_order(&drawables), [1, 4, 5]);
+    assert_eq!(view.focus().focused(), None);
+
+    let mut focused = vec![];
+    for shift in [false, false, false, false, true, true] {
+        view.key(&drawables, tab(shift));
+        focused.push(view.focus().focused().unwrap());
+    }
+
+    assert_eq!(focused, [1, 4, 5, 1, 5, 4]);
+    assert!(view.focus().is_visible());
+
+    // The focus is visible on the focused controller only.
+    let drawables = view.build();
+    assert_eq!(state(&drawables, 4), State::FocusVisible);
+    assert_eq!(state(&drawables, 1), State::Normal);
+
+    // Tab with control is not for the focus.
+    view.key(&drawables, keyboard::Event::Down {
+        key: Key::Tab,
+        modifiers: Modifiers {
+            control: true,
+            ..Modifiers::NONE
+        },
+        repeat: false,
+    });
+    assert_eq!(view.focus().focused(), Some(4));
+}
+
+#[test]
+fn keys_to_the_focused_controller() {
+    let mut view = controllers_view();
+    let drawables = view.build();
+
+    // Nothing is focused.
+    assert_eq!(view.key(&drawables, keyboard::Event::Char { character: 'x' }), None);
+
+    view.set_focus(&drawables, Some(5), true);
+    assert_eq!(view.key(&drawables, keyboard::Event::Char { character: 'x' }), Some(5));
+    assert_eq!(view.layout.widgets::<Layout>()[0].widgets::<Label>()[1].text, "dx");
+
+    // Enter and space tap the focused detector.
+    view.set_focus(&drawables, Some(1), true);
+    view.key(&drawables, keyboard::Event::down(Key::Enter));
+    view.key(&drawables, keyboard::Event::up(Key::Enter));
+    view.key(&drawables, keyboard::Event::down(Key::Space));
+    assert_eq!(view.layout.widgets::<Label>()[0].text, "a!!");
+}
+
+#[test]
+fn tab_prevented() {
+    let mut view = view([100, 100], Direction::Column, widgets![
+        keyboard::Listener::new(Label::normal("a"), trap),
+        keyboard::Listener::new(Label::normal("b"), type_in),
+    ]);
+    let drawables = view.build();
+
+    view.key(&drawables, tab(false));
+    assert_eq!(view.focus().focused(), Some(1));
+    view.key(&drawables, tab(false));
+    assert_eq!(view.focus().focused(), Some(1));
+}
+
+#[test]
+fn focused_by_pointer() {
+    let mut view = controllers_view();
+    let drawables = view.build();
+
+    let zone = drawables.iter().find(|drawable| drawable.group_id == 1).unwrap().zone;
+    let position = [zone.x() + 1, zone.y() + 1];
+
+    view.pointer(&drawables, Input::Down {
+        position,
+        button: Button::Primary,
+        time: Duration::ZERO,
+    });
+    view.pointer(&drawables, Input::Up {
+        position,
+        button: Button::Primary,
+        time: Duration::from_millis(10),
+    });
+    view.pointer(&drawables, Input::Leave {
+        time: Duration::from_millis(20),
+    });
+
+    assert_eq!(view.focus().focused(), Some(1));
+    assert!(!view.focus().is_visible());
+    assert_eq!(state(&view.build(), 1), State::Focused);
+
+    // Pressing out of the controllers removes the focus.
+    view.pointer(&drawables, Input::Down {
+        position: [99, 99],
+        button: Button::Primary,
+        time: Duration::from_secs(1),
+    });
+
+    assert_eq!(view.focus().focused(), None);
+    assert_eq!(state(&view.build(), 1), State::Normal);
+}
+
+#[test]
+fn focus_visible_style() {
+    let theme = themes::default(vec![]);
+    let styles = &theme.button_theme.styles;
+
+    assert!(styles.get(State::FocusVisible).borders.is_some());
+    assert_eq!(styles.get(State::FocusVisible).colour, styles.get(State::Focused).colour);
+    assert!(styles.get(State::Focused).borders.is_none());
+}
@@ -0,0 +1,192 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Hit-testing of the built drawables.
+
+use haussmann::{
+    controllers::tap,
+    graphics::{draw::Drawable, hit::Index, Matrix, Transform},
+    widgets::{Layout, Surface, Transformed, View, Widget},
+    widgets,
+    Align,
+    Direction,
+    Overflow,
+    Zone,
+};
+
+fn layout(overflow: Overflow, widgets: Vec<Box<dyn Widget>>) -> Layout {
+    Layout::normal(overflow, Align::Center, Align::Center, Direction::Column, widgets)
+}
+
+fn view(layout: Layout) -> View {
+    View::new(
+        Zone {
+            position: [0, 0],
+            size: [100, 100],
+        },
+        layout,
+    )
+}
+
+/// Returns the centre of the zone of a drawable, without its transformation.
+fn centre(drawable: &Drawable) -> [isize; 2] {
+    [
+        drawable.zone.x() + drawable.zone.width() as isize / 2,
+        drawable.zone.y() + drawable.zone.height() as isize / 2,
+    ]
+}
+
+#[test]
+fn topmost_first() {
+    // The second surface is moved half over the first one.
+    let view = view(layout(
+        Overflow::Ignore,
+        widgets![
+            Surface::normal(),
+            Transformed::new(Transform::translated(-25.0, 0.0), Surface::normal()),
+        ],
+    ));
+    let drawables = view.build();
+
+    let first = drawables[1].zone;
+    let covered = [first.x() + first.width() as isize - 5, first.y() + 5];
+    let uncovered = [first.x() + 5, first.y() + 5];
+
+    let index = Index::new(&drawables, ([0, 0], [100, 100]).into());
+
+    assert_eq!(index.hits(&drawables, covered), vec![2, 1, 0]);
+    assert_eq!(view.hit_test(&drawables, covered).unwrap().index, 2);
+    assert_eq!(view.hit_test(&drawables, uncovered).unwrap().index, 1);
+
+    // Out of the view.
+    assert_eq!(view.hit_test(&drawables, [-10, 50]), None);
+    assert_eq!(view.hit_test(&drawables, [50, 100]), None);
+}
+
+#[test]
+fn ancestors() {
+    let view = view(layout(
+        Overflow::Ignore,
+        widgets![
+            Surface::normal(),
+            layout(Overflow::Ignore, widgets![Surface::normal()]),
+        ],
+    ));
+    let drawables = view.build();
+
+    // The view's layout, the surface, the inner layout and its surface.
+    assert_eq!(drawables.len(), 4);
+
+    let hit = view.hit_test(&drawables, centre(&drawables[3])).unwrap();
+    assert_eq!(hit.index, 3);
+    assert_eq!(hit.ancestors, vec![2, 0]);
+    assert_eq!(hit.chain().collect::<Vec<usize>>(), vec![3, 2, 0]);
+
+    let hit = view.hit_test(&drawables, centre(&drawables[1])).unwrap();
+    assert_eq!(hit.index, 1);
+    assert_eq!(hit.ancestors, vec![0]);
+}
+
+#[test]
+fn clipped_by_layout() {
+    // The surfaces are moved half out of their layouts, only the one hiding
+    // its overflow clips it.
+    let moved = || Transformed::new(Transform::translated(25.0, 0.0), Surface::normal());
+    let view = view(layout(
+        Overflow::Ignore,
+        widgets![
+            layout(Overflow::Hide, widgets![moved()]),
+            layout(Overflow::Ignore, widgets![moved()]),
+        ],
+    ));
+    let drawables = view.build();
+
+    assert_eq!(drawables.len(), 5);
+    assert_eq!(drawables[2].clip, Some(drawables[1].zone));
+    assert_eq!(drawables[4].clip, None);
+
+    // Just out of the right side of the layouts, the hidden part of the
+    // first surface is over the second layout.
+    let hidden = [drawables[1].zone.x() + drawables[1].zone.width() as isize + 1, centre(&drawables[2])[1]];
+    let shown = [drawables[3].zone.x() + drawables[3].zone.width() as isize + 1, centre(&drawables[4])[1]];
+
+    assert_eq!(view.hit_test(&drawables, hidden).unwrap().index, 3);
+    assert_eq!(view.hit_test(&drawables, shown).unwrap().index, 4);
+}
+
+#[test]
+fn transformed() {
+    let view = view(layout(
+        Overflow::Ignore,
+        widgets![Transformed::new(Transform::scaled(0.5, 0.5), Surface::normal())],
+    ));
+    let drawables = view.build();
+    let zone = drawables[1].zone;
+
+    // The surface is scaled from its centre.
+    assert_eq!(view.hit_test(&drawables, centre(&drawables[1])).unwrap().index, 1);
+    assert_eq!(view.hit_test(&drawables, [zone.x() + 1, zone.y() + 1]).unwrap().index, 0);
+
+    // Small cells give the same results.
+    let index = Index::with_cell_size(&drawables, ([0, 0], [100, 100]).into(), 3);
+    for point in [centre(&drawables[1]), [zone.x() + 1, zone.y() + 1], [99, 99]] {
+        assert_eq!(index.hit(&drawables, point), view.hit_test(&drawables, point));
+    }
+}
+
+#[test]
+fn clamped_to_view() {
+    // The second surface is moved far out of the view, over its right side.
+    let view = view(layout(
+        Overflow::Ignore,
+        widgets![
+            Surface::normal(),
+            Transformed::new(Transform::translated(100_000.0, 0.0), Surface::normal()),
+        ],
+    ));
+    let drawables = view.build();
+    let moved = drawables[2].transform.bounds(drawables[2].zone);
+
+    assert_eq!(view.hit_test(&drawables, centre(&drawables[1])).unwrap().index, 1);
+    assert_eq!(view.hit_test(&drawables, moved.position), None);
+
+    // The grid only covers the view.
+    let index = Index::with_cell_size(&drawables, ([0, 0], [100, 100]).into(), 1);
+    assert!(index.candidates(moved.position).is_empty());
+    assert!(index.is_for(&drawables));
+
+    // Drawables which were not built last are indexed again.
+    let fewer = &drawables[..2];
+    assert!(!index.is_for(fewer));
+    assert_eq!(view.hit_test(fewer, centre(&drawables[1])).unwrap().index, 1);
+
+    // So are drawables built before the last build, even moved.
+    let mut back = view.build();
+    let _last = view.build();
+    back[2].transform = Matrix::identity();
+    assert!(!index.is_for(&back));
+    assert_eq!(view.hit_test(&back, centre(&back[2])).unwrap().index, 2);
+
+    // Each build has its own generation, the drawables built again in the
+    // memory of dropped drawables are not taken for them.
+    let index = Index::new(&back, ([0, 0], [100, 100]).into());
+    assert!(index.is_for(&back));
+    drop(back);
+    let again = view.build();
+    assert!(!index.is_for(&again));
+    assert!(again.iter().all(|drawable| drawable.generation == again[0].generation));
+}
+
+#[test]
+fn edges() {
+    let view = view(layout(Overflow::Ignore, widgets![Surface::normal(), Surface::normal()]));
+    let drawables = view.build();
+    let edge = drawables[2].zone.position;
+
+    // The edge between two drawables side by side is only over the second 
+    // one, when hit-testing and when tapping.
+    assert_eq!(view.hit_test(&drawables, edge).unwrap().index, 2);
+    assert!(!tap::is_tapped(edge, drawables[1].zone, drawables[1].transform));
+    assert!(tap::is_tapped(edge, drawables[2].zone, drawables[2].transform));
+}
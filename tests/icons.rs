@@ -0,0 +1,90 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Icons sized by their text size and tinted by their label style.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use haussmann::{
+    graphics::{colours::RGBA, draw::Object},
+    themes::{Component, LabelStyle, State, TextStyle},
+    widgets::{icons, Container, Glyph, Icon, Label},
+    Direction,
+    FontWeight,
+};
+
+use common::{built, view};
+
+#[test]
+fn sized_and_tinted() {
+    let large = LabelStyle {
+        colour: RGBA::new(200, 0, 0, 255),
+        text_style: Some(TextStyle {
+            size: 24,
+            weight: FontWeight::Regular,
+            spacing: 0.0,
+        }),
+    };
+
+    let view = view([200, 100], Direction::Column, vec![
+        Container::new([40, 40], Icon::paths(icons::ADD)).into(),
+        Container::new([40, 40], Icon::paths(icons::MENU).with_style(large.clone())).into(),
+        Container::new([10, 10], Icon::paths(icons::CLOSE).with_style(large.clone())).into(),
+        Container::new([40, 40], Icon::paths(icons::CHECK).with_component(Component::Button)).into(),
+        Label::normal("icons").into(),
+    ]);
+    let drawables = view.build();
+    let icons = built(&drawables, |object| matches!(object, Object::Icon(_)));
+
+    // As large as the text of the paragraphs, in the middle of the zone.
+    assert_eq!(icons[0].zone, ([12, 12], [16, 16]).into());
+    assert_eq!(icons[0].label_style.as_ref().unwrap().colour, view.theme().label_style.colour);
+
+    assert_eq!(icons[1].zone, ([48, 8], [24, 24]).into());
+    assert_eq!(icons[1].label_style.as_ref(), Some(&large));
+
+    // Not larger than its zone.
+    assert_eq!(icons[2].zone, ([80, 0], [10, 10]).into());
+
+    // Readable over the component it is drawn on.
+    assert_eq!(
+        icons[3].label_style.as_ref().unwrap().colour,
+        view.theme().button_theme.label_style.colour,
+    );
+}
+
+#[test]
+fn disabled() {
+    let icon = Icon::paths(icons::PLAY);
+    let theme = haussmann::themes::default(vec![]);
+
+    let colour = icon.label_style_in(&theme, State::Disabled).colour;
+    assert_eq!(colour, theme.label_style.colour.with_alpha(97));
+}
+
+#[test]
+fn glyphs() {
+    let icon = Icon::font("Material Icons", '\u{e88a}');
+    assert_eq!(icon.glyph, Glyph::Font {
+        family: "Material Icons".to_string(),
+        character: '\u{e88a}',
+    });
+
+    let Glyph::Paths { paths, view_box } = Icon::paths(icons::SEARCH).glyph else {
+        panic!("the built-in icons are paths");
+    };
+    assert_eq!(view_box, 24.0);
+    assert_eq!(paths.len(), 2);
+
+    // Every built-in path is a closed shape.
+    let set = [
+        icons::ADD, icons::REMOVE, icons::CLOSE, icons::CHECK, icons::CHEVRON_UP, icons::CHEVRON_DOWN,
+        icons::CHEVRON_LEFT, icons::CHEVRON_RIGHT, icons::MENU, icons::HOME, icons::SEARCH, icons::INFO,
+        icons::PLAY, icons::PAUSE,
+    ];
+    for path in set.iter().flat_map(|paths| paths.iter()) {
+        assert!(path.starts_with('M') && path.ends_with('z'), "{}", path);
+    }
+}
@@ -0,0 +1,227 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Lists building only their visible rows.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use haussmann::{
+    controllers::{
+        keyboard::{self, Key},
+        pointer::Input,
+        scroll::{Scroller, STEP},
+        tap,
+    },
+    graphics::draw::{Drawable, Object},
+    themes::Theme,
+    widgets::{Label, Layout, ListView, RowHeight, View, Widget},
+    Align,
+    Direction,
+    Overflow,
+};
+
+use common::{click, log, logged};
+
+fn row(row: usize) -> Box<dyn Widget> {
+    Label::normal(&format!("row {}", row)).into()
+}
+
+/// Rows of 10, 20 and 30 pixels, one after the other.
+fn variable(row: usize) -> usize {
+    10 + row % 3 * 10
+}
+
+/// A view of 200x100 filled with a list.
+fn list_view(list: impl Into<Box<dyn Widget>>) -> View {
+    View::new(([0, 0], [200, 100]).into(), Layout::normal(
+        Overflow::Hide,
+        Align::Left,
+        Align::Top,
+        Direction::Row,
+        vec![list.into()],
+    ))
+}
+
+/// Returns the texts of the built rows, with their drawables.
+fn rows(drawables: &[Drawable]) -> Vec<(String, &Drawable)> {
+    drawables
+        .iter()
+        .filter_map(|drawable| match &drawable.object {
+            Object::Label(label) => Some((label.text.clone(), drawable)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn list(view: &View) -> &ListView {
+    view.layout.widgets[0].as_any().downcast_ref().unwrap()
+}
+
+#[test]
+fn fixed_rows() {
+    let mut list = ListView::new(10_000, RowHeight::Fixed(20), row);
+    assert_eq!(list.visible_rows(100), 0..5);
+    assert_eq!(list.built_rows(100), 0..7);
+    assert_eq!(list.content_height(), 200_000);
+
+    list.offset = 1010;
+    assert_eq!(list.visible_rows(100), 50..56);
+    assert_eq!(list.built_rows(100), 48..58);
+    assert_eq!(list.row_at(5), Some(50));
+    assert_eq!(list.row_at(15), Some(51));
+
+    // Not scrolled past the last row.
+    assert!(list.scroll_by(1_000_000, 100));
+    assert_eq!(list.offset, 199_900);
+    assert_eq!(list.visible_rows(100), 9995..10_000);
+    assert!(!list.scroll_by(20, 100));
+}
+
+#[test]
+fn variable_rows() {
+    let mut list = ListView::new(100, RowHeight::variable(variable), row);
+    assert_eq!(list.row_top(3), 60);
+    assert_eq!(list.content_height(), 1990);
+
+    list.offset = 65;
+    // The rows 3 (60 to 70) to 6 (120 to 130) and 7 (130 to 150).
+    assert_eq!(list.visible_rows(70), 3..8);
+    assert_eq!(list.row_at(0), Some(3));
+    assert_eq!(list.row_at(6), Some(4));
+    assert_eq!(list.row_at(-70), None);
+    assert_eq!(list.row_at(1925), None);
+
+    // The heights are summed again when the rows change.
+    list.count = 4;
+    assert_eq!(list.content_height(), 70);
+
+    list.set_height(RowHeight::Fixed(5));
+    assert_eq!(list.content_height(), 20);
+}
+
+#[test]
+fn closures() {
+    // The rows and their heights come from data captured by the closures.
+    let names = ["first", "second", "third"];
+    let heights = Rc::new(RefCell::new(vec![10, 20, 30]));
+    let row_heights = heights.clone();
+
+    let mut list = ListView::new(
+        names.len(),
+        RowHeight::variable(move |row| row_heights.borrow()[row]),
+        move |row| Label::normal(names[row]).into(),
+    );
+    assert_eq!(list.content_height(), 60);
+
+    // Not summed again until the list is told the heights changed.
+    heights.borrow_mut()[0] = 50;
+    assert_eq!(list.content_height(), 60);
+    list.refresh_heights();
+    assert_eq!(list.content_height(), 100);
+    assert_eq!(list.row_at(55), Some(1));
+
+    let drawables = list_view(list).build();
+    let rows = rows(&drawables);
+    assert_eq!(rows.iter().map(|(text, _)| text.as_str()).collect::<Vec<_>>(), ["first", "second", "third"]);
+    assert_eq!(rows[1].1.zone, ([0, 50], [200, 20]).into());
+}
+
+#[test]
+fn built_drawables() {
+    let mut list = ListView::new(10_000, RowHeight::Fixed(20), row);
+    list.offset = 1010;
+    let view = list_view(list);
+    let drawables = view.build();
+
+    // Only the visible rows have drawables.
+    let rows = rows(&drawables);
+    assert_eq!(rows.len(), 6);
+    assert_eq!(rows[0].0, "row 50");
+    assert_eq!(rows[5].0, "row 55");
+
+    // Scrolled by the offset, hidden out of the list.
+    let (_, first_visible) = rows[0];
+    assert_eq!(first_visible.zone, ([0, -10], [200, 20]).into());
+    assert_eq!(first_visible.clip, Some(([0, 0], [200, 100]).into()));
+
+    // Each row takes the identifier following the previous one, the rows of
+    // the buffer before them included.
+    let ids: Vec<i32> = rows.iter().map(|(_, drawable)| drawable.group_id).collect();
+    assert_eq!(ids, (4..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn visible_drawables() {
+    let mut view = list_view(ListView::new(1000, RowHeight::variable(variable), row));
+
+    for offset in [0, 5, 35, 1000, 19_900] {
+        view.layout.widgets[0].as_any_mut().downcast_mut::<ListView>().unwrap().offset = offset;
+        let drawables = view.build();
+
+        // One drawable for each visible row, none for the buffer.
+        let visible = list(&view).visible_rows(100);
+        let texts: Vec<String> = rows(&drawables).into_iter().map(|(text, _)| text).collect();
+        assert_eq!(texts, visible.map(|row| format!("row {}", row)).collect::<Vec<_>>());
+    }
+}
+
+fn tapped(label: &mut Label, _: &Theme) {
+    log(label.text.clone());
+}
+
+#[test]
+fn row_controllers() {
+    let mut view = list_view(Scroller::new(ListView::new(50, RowHeight::Fixed(20), |row| {
+        tap::Detector::new(Label::normal(&format!("row {}", row)), tapped).into()
+    })));
+    let drawables = view.build();
+
+    // The controllers of the rows are reached.
+    click(&mut view, &drawables, [50, 30]);
+    assert_eq!(logged(), ["row 1"]);
+
+    // The wheel over a controller of a row still scrolls the list.
+    view.pointer(&drawables, Input::Wheel {
+        position: [50, 50],
+        delta: [0.0, -1.0],
+        time: Duration::ZERO,
+    });
+    assert_eq!(list(&view).offset, STEP);
+
+    // The rows entering the built rows take the next identifiers.
+    let drawables = view.build();
+    assert_eq!(rows(&drawables).last().unwrap().1.group_id, 8);
+
+    click(&mut view, &drawables, [50, 30]);
+    assert_eq!(logged(), ["row 3"]);
+}
+
+#[test]
+fn scroller() {
+    let mut view = list_view(Scroller::new(ListView::new(50, RowHeight::Fixed(20), row)));
+    let drawables = view.build();
+
+    view.pointer(&drawables, Input::Wheel {
+        position: [50, 50],
+        delta: [0.0, -2.0],
+        time: Duration::ZERO,
+    });
+    assert_eq!(list(&view).offset, 2 * STEP);
+
+    view.set_focus(&drawables, Some(1), true);
+    view.key(&drawables, keyboard::Event::down(Key::Up));
+    assert_eq!(list(&view).offset, STEP);
+
+    view.key(&drawables, keyboard::Event::down(Key::End));
+    assert_eq!(list(&view).offset, 900);
+
+    view.key(&drawables, keyboard::Event::down(Key::PageUp));
+    assert_eq!(list(&view).offset, 800);
+
+    let drawables = view.build();
+    assert_eq!(rows(&drawables)[0].0, "row 40");
+}
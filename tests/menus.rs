@@ -0,0 +1,202 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Selects and context menus opened in the overlay of the view.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::time::Duration;
+
+use haussmann::{
+    controllers::{
+        keyboard::{self, Key},
+        menu::{Contextual, Dropdown},
+        pointer::{Button, Input},
+    },
+    graphics::draw::{Drawable, Object},
+    themes::Component,
+    widgets::{Container, Label, Layout, Menu, Placement, Popup, Select, Surface, View, Widget},
+    widgets,
+    Align,
+    Direction,
+    Overflow,
+    Zone,
+};
+
+use common::{click, click_with, log, logged, view};
+
+/// A view of 300x200 with a select at `y`.
+fn select_view(y: usize) -> View {
+    let dropdown = Dropdown::new(
+        Select::normal(&["red", "green", "blue"], None).with_placeholder("colour"),
+        |select, _| log(format!("selected {:?}", select.selected)),
+    );
+
+    view([300, 200], Direction::Row, widgets![
+        Container::new([300, y], Surface::normal()),
+        Container::new([120, 24], dropdown),
+        Surface::normal(),
+    ])
+}
+
+fn select(view: &View) -> &Select {
+    view.layout.widgets::<Container>()[1].widget.as_any().downcast_ref().unwrap()
+}
+
+/// Returns the zone of the menu drawn in the overlay.
+fn menu_zone(drawables: &[Drawable]) -> Zone {
+    drawables
+        .iter()
+        .find(|drawable| matches!(&drawable.object, Object::Surface(surface) if surface.component == Component::Menu))
+        .unwrap()
+        .zone
+}
+
+#[test]
+fn placement() {
+    let view = ([0, 0], [300, 200]).into();
+    let popup = |anchor: Zone, placement| Popup::new(anchor, placement, [100, 80], Surface::normal());
+
+    let anchor: Zone = ([20, 20], [50, 20]).into();
+    assert_eq!(popup(anchor, Placement::Below).zone_in(view), ([20, 40], [100, 80]).into());
+    assert_eq!(popup(anchor, Placement::Right).zone_in(view), ([70, 20], [100, 80]).into());
+
+    // Flipped at the edges, or aligned on the end of the anchor.
+    let anchor: Zone = ([250, 150], [40, 20]).into();
+    assert_eq!(popup(anchor, Placement::Below).zone_in(view), ([190, 70], [100, 80]).into());
+    assert_eq!(popup(anchor, Placement::Right).zone_in(view), ([150, 90], [100, 80]).into());
+
+    // Not flipped without more space on the other side, but kept in the view.
+    let anchor: Zone = ([0, 0], [300, 150]).into();
+    assert_eq!(popup(anchor, Placement::Above).zone_in(view), ([0, 120], [100, 80]).into());
+}
+
+#[test]
+fn dropdown() {
+    let mut view = select_view(0);
+    let drawables = view.build();
+
+    // Clicking the select opens its options under it.
+    click(&mut view, &drawables, [60, 12]);
+    assert_eq!(view.overlay().len(), 1);
+    assert!(select(&view).open);
+
+    let drawables = view.build();
+    let menu = menu_zone(&drawables);
+    assert_eq!(menu, ([0, 24], [120, 72]).into());
+
+    // The menu is drawn last, not hidden by the layout hiding its overflow.
+    let index = drawables.iter().position(|drawable| drawable.zone == menu).unwrap();
+    assert!(drawables[index].clip.is_none());
+    assert!(drawables[index + 1..]
+        .iter()
+        .all(|drawable| matches!(drawable.clip, Some(clip) if menu.intersection(clip) == Some(clip))));
+
+    // Clicking an option chooses it and closes the menu.
+    click(&mut view, &drawables, [60, 60]);
+    assert_eq!(logged(), ["selected Some(1)"]);
+    assert!(view.overlay().is_empty());
+    assert!(!select(&view).open);
+
+    let drawables = view.build();
+    assert!(drawables.iter().any(|drawable| matches!(&drawable.object, Object::Label(label) if label.text == "green")));
+}
+
+#[test]
+fn dismissed() {
+    let mut view = select_view(120);
+    let drawables = view.build();
+
+    // The menu does not fit under the select, it goes over it.
+    click(&mut view, &drawables, [60, 132]);
+    let drawables = view.build();
+    assert_eq!(menu_zone(&drawables), ([0, 48], [120, 72]).into());
+
+    // Pressing out of the menu closes it, the press is not dispatched.
+    assert_eq!(click(&mut view, &drawables, [250, 150]), 0);
+    assert!(view.overlay().is_empty());
+    assert!(!select(&view).open);
+    assert!(logged().is_empty());
+
+    // Escape closes the menu opened with the keyboard, the arrows choose
+    // without opening it.
+    let drawables = view.build();
+    view.set_focus(&drawables, Some(2), true);
+    view.key(&drawables, keyboard::Event::down(Key::Enter));
+    assert_eq!(view.overlay().len(), 1);
+    view.key(&drawables, keyboard::Event::down(Key::Escape));
+    assert!(view.overlay().is_empty());
+
+    view.key(&drawables, keyboard::Event::down(Key::Down));
+    view.key(&drawables, keyboard::Event::down(Key::Down));
+    view.key(&drawables, keyboard::Event::down(Key::Up));
+    assert_eq!(logged(), ["selected Some(0)", "selected Some(1)", "selected Some(0)"]);
+}
+
+#[test]
+fn keyboard() {
+    let mut view = select_view(0);
+    let drawables = view.build();
+
+    // The menu opened with the keyboard takes the focus, its items are
+    // reached without the pointer.
+    view.set_focus(&drawables, Some(2), true);
+    view.key(&drawables, keyboard::Event::down(Key::Enter));
+    let chooser = view.focus().focused().unwrap();
+    assert_ne!(chooser, 2);
+    assert!(view.focus().is_visible());
+
+    let drawables = view.build();
+    assert_eq!(view.focus_order(&drawables), [2, chooser]);
+    assert_eq!(view.key(&drawables, keyboard::Event::down(Key::Down)), Some(chooser));
+    view.key(&drawables, keyboard::Event::down(Key::Down));
+    view.key(&drawables, keyboard::Event::down(Key::Enter));
+    assert_eq!(logged(), ["selected Some(1)"]);
+
+    // The focus goes back to the select when the menu is closed.
+    assert!(view.overlay().is_empty());
+    assert_eq!(view.focus().focused(), Some(2));
+}
+
+#[test]
+fn context_menu() {
+    let mut view = View::new(([0, 0], [300, 200]).into(), Layout::normal(
+        Overflow::Ignore,
+        Align::Center,
+        Align::Center,
+        Direction::Row,
+        widgets![Contextual::new(
+            Label::normal("text"),
+            Menu::normal(&["copy", "paste"]),
+            |label, item, _| log(format!("{} {}", label.text, item)),
+        )],
+    ));
+    let drawables = view.build();
+
+    // The primary button does not open the menu.
+    click(&mut view, &drawables, [200, 50]);
+    assert!(view.overlay().is_empty());
+
+    // The menu is opened at the pointer, on its left at the right edge.
+    click_with(&mut view, &drawables, [200, 50], Button::Secondary, Duration::ZERO);
+    let drawables = view.build();
+    assert_eq!(menu_zone(&drawables), ([40, 50], [160, 48]).into());
+
+    // Moving over an item highlights it.
+    view.pointer(&drawables, Input::Move {
+        position: [100, 80],
+        time: Duration::from_millis(20),
+    });
+    let drawables = view.build();
+    let highlight = drawables
+        .iter()
+        .find(|drawable| matches!(&drawable.object, Object::Surface(surface) if surface.component == Component::MenuHighlight))
+        .unwrap();
+    assert_eq!(highlight.zone, ([40, 74], [160, 24]).into());
+
+    click(&mut view, &drawables, [100, 80]);
+    assert_eq!(logged(), ["text 1"]);
+    assert!(view.overlay().is_empty());
+}
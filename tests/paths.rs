@@ -0,0 +1,194 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Vector paths parsed from SVG path data and flattened to polylines.
+
+use haussmann::{
+    graphics::{colours::RGBA, draw::Object, Matrix},
+    widgets::{icons, Icon, Label, Layout, Path, PathError, Polyline, Segment, View, TOLERANCE},
+    Align,
+    Direction,
+    Overflow,
+};
+
+#[test]
+fn parsed() {
+    // Relative commands, horizontal and vertical lines, implicit lines after
+    // a move.
+    let path = Path::parse("m10 10 10 0v10h-10z").unwrap();
+    assert_eq!(path.segments, vec![
+        Segment::MoveTo([10.0, 10.0]),
+        Segment::LineTo([20.0, 10.0]),
+        Segment::LineTo([20.0, 20.0]),
+        Segment::LineTo([10.0, 20.0]),
+        Segment::Close,
+    ]);
+
+    // Numbers without separators, exponents, and smooth curves reflecting
+    // the previous control point.
+    let path = Path::parse("M0,0C0-5 10-5 10 0S20 5 20 0M.5.5Q1 0 2 0T4 0").unwrap();
+    assert_eq!(path.segments, vec![
+        Segment::MoveTo([0.0, 0.0]),
+        Segment::CubicTo([0.0, -5.0], [10.0, -5.0], [10.0, 0.0]),
+        Segment::CubicTo([10.0, 5.0], [20.0, 5.0], [20.0, 0.0]),
+        Segment::MoveTo([0.5, 0.5]),
+        Segment::QuadTo([1.0, 0.0], [2.0, 0.0]),
+        Segment::QuadTo([3.0, 0.0], [4.0, 0.0]),
+    ]);
+
+    let path = Path::parse("M1e1 2E-1 l-1e0,0").unwrap();
+    assert_eq!(path.segments, vec![
+        Segment::MoveTo([10.0, 0.2]),
+        Segment::LineTo([9.0, 0.2]),
+    ]);
+
+    // Flags of the arcs written without separators.
+    let path = Path::parse("M0 0a5 5 0 105 5").unwrap();
+    assert_eq!(path.segments[1], Segment::ArcTo {
+        radii: [5.0, 5.0],
+        rotation: 0.0,
+        large_arc: true,
+        sweep: false,
+        to: [5.0, 5.0],
+    });
+
+    // Same as built.
+    assert_eq!(
+        Path::parse("M0 0L10 0Q10 10 0 10Z").unwrap(),
+        Path::new().move_to([0.0, 0.0]).line_to([10.0, 0.0]).quad_to([10.0, 10.0], [0.0, 10.0]).close(),
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(Path::parse("L0 0"), Err(PathError::MissingMove));
+    assert_eq!(Path::parse("0 0"), Err(PathError::MissingMove));
+    assert_eq!(Path::parse("M0 0 X"), Err(PathError::UnknownCommand { index: 5, character: 'X' }));
+    assert_eq!(Path::parse("M0 0 L5"), Err(PathError::ExpectedNumber { index: 7 }));
+    assert_eq!(Path::parse("M0 0 A1 1 0 2 0 5 5"), Err(PathError::ExpectedFlag { index: 12 }));
+
+    assert_eq!(
+        PathError::UnknownCommand { index: 5, character: 'X' }.to_string(),
+        "unknown path command `X` at 5",
+    );
+}
+
+#[test]
+fn flattened() {
+    // The lines are kept as they are, and each move starts a polyline.
+    let path = Path::parse("M0 0H10V10ZM20 0h5").unwrap();
+    assert_eq!(path.flatten(TOLERANCE), vec![
+        Polyline {
+            points: vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]],
+            closed: true,
+        },
+        Polyline {
+            points: vec![[20.0, 0.0], [25.0, 0.0]],
+            closed: false,
+        },
+    ]);
+
+    let lines: Vec<_> = path.flatten(TOLERANCE)[0].lines().collect();
+    assert_eq!(lines, vec![
+        ([0.0, 0.0], [10.0, 0.0]),
+        ([10.0, 0.0], [10.0, 10.0]),
+        ([10.0, 10.0], [0.0, 0.0]),
+    ]);
+
+    // The curves get more points with a smaller tolerance, and end where
+    // they end.
+    let curve = Path::parse("M0 0Q50 100 100 0").unwrap();
+    let coarse = curve.flatten(1.0);
+    let fine = curve.flatten(0.1);
+
+    assert!(fine[0].points.len() > coarse[0].points.len());
+    assert_eq!(coarse[0].points.last(), Some(&[100.0, 0.0]));
+
+    // Each point is on the parabola `y = 2x - x² / 50`.
+    for [x, y] in fine[0].points.iter() {
+        assert!((y - (2.0 * x - x * x / 50.0)).abs() < 0.1);
+    }
+}
+
+#[test]
+fn arcs() {
+    // A half circle of radius 10 around [10, 0].
+    let path = Path::parse("M0 0A10 10 0 0 1 20 0").unwrap();
+    let points = &path.flatten(0.05)[0].points;
+
+    assert_eq!(points.last(), Some(&[20.0, 0.0]));
+
+    for [x, y] in points {
+        let radius = ((x - 10.0).powi(2) + y.powi(2)).sqrt();
+        assert!((radius - 10.0).abs() < 0.1, "{} is not on the arc", radius);
+        // Clockwise on the screen, over the line.
+        assert!(*y <= 0.001);
+    }
+
+    // Radii too small are scaled up to reach the end, into the same half
+    // circle.
+    let small = Path::parse("M0 0A1 1 0 0 1 20 0").unwrap();
+    for [x, y] in &small.flatten(0.05)[0].points {
+        let radius = ((x - 10.0).powi(2) + y.powi(2)).sqrt();
+        assert!((radius - 10.0).abs() < 0.1);
+    }
+
+    // Transformed exactly, as curves.
+    let moved = path.transformed(&Matrix::translation(5.0, 5.0));
+    assert!(moved.segments.iter().skip(1).all(|segment| matches!(segment, Segment::CubicTo(..))));
+    assert_eq!(moved.flatten(0.05)[0].points.last(), Some(&[25.0, 5.0]));
+}
+
+#[test]
+fn drawn() {
+    let triangle = Path::parse("M0 0H10L5 10Z")
+        .unwrap()
+        .with_fill(RGBA::new(200, 0, 0, 255))
+        .with_view_box([10.0, 10.0]);
+
+    let view = View::new(([0, 0], [200, 100]).into(), Layout::normal(
+        Overflow::Hide,
+        Align::Left,
+        Align::Top,
+        Direction::Column,
+        vec![triangle.clone().into(), Label::normal("paths").into()],
+    ));
+    let drawables = view.build();
+
+    let drawable = drawables
+        .iter()
+        .find(|drawable| matches!(drawable.object, Object::Path(_)))
+        .unwrap();
+
+    let Object::Path(path) = &drawable.object else {
+        unreachable!();
+    };
+    assert_eq!(path, &triangle);
+    assert!(drawable.style.is_none() && drawable.label_style.is_none());
+
+    // Scaled from the view box to the zone of the drawable.
+    let zone = drawable.zone;
+    let polylines = path.polylines_in(zone, TOLERANCE);
+    assert_eq!(polylines[0].points, vec![
+        [zone.x() as f32, zone.y() as f32],
+        [(zone.x() + zone.width() as isize) as f32, zone.y() as f32],
+        [zone.x() as f32 + zone.width() as f32 / 2.0, (zone.y() + zone.height() as isize) as f32],
+    ]);
+}
+
+#[test]
+fn icons() {
+    let colour = RGBA::new(0, 0, 200, 255);
+
+    let paths = Icon::paths(icons::MENU).to_paths(colour).unwrap();
+    assert_eq!(paths.len(), 3);
+    assert!(paths.iter().all(|path| path.fill == Some(colour) && path.view_box == Some([24.0, 24.0])));
+
+    // Every built-in icon is valid path data.
+    for icon in [icons::ADD, icons::CLOSE, icons::CHEVRON_UP, icons::HOME, icons::SEARCH, icons::INFO, icons::PAUSE] {
+        assert!(Icon::paths(icon).to_paths(colour).is_ok());
+    }
+
+    assert!(Icon::font("Material Icons", 'a').to_paths(colour).unwrap().is_empty());
+}
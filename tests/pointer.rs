@@ -0,0 +1,174 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Pointer events created from synthetic inputs and dispatched to the 
+//! controllers.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::time::Duration;
+
+use haussmann::{
+    controllers::{
+        pointer::{Button, Event, Input, Kind, Listener},
+        propagation::Propagation,
+        tap,
+    },
+    themes::{State, Theme},
+    widgets::{Label, View, Widget},
+    widgets,
+    Direction,
+};
+
+use common::view;
+
+/// Writes the kind of the events in the text of the label.
+fn record(label: &mut Label, event: &Event, _: &mut Propagation, _: &Theme) {
+    label.text.push_str(&format!("{:?};", event.kind));
+}
+
+/// Two listeners side by side, of 50 by 100 pixels.
+fn listeners() -> View {
+    view([100, 100], Direction::Column, widgets![
+        Listener::new(Label::normal(""), record),
+        Listener::new(Label::normal(""), record),
+    ])
+}
+
+/// Returns the recorded events of a listener.
+fn recorded(view: &View, i: usize) -> Vec<String> {
+    view.layout.widgets::<Label>()[i]
+        .text
+        .split(';')
+        .filter(|kind| !kind.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn ms(milliseconds: u64) -> Duration {
+    Duration::from_millis(milliseconds)
+}
+
+fn down(position: [isize; 2], time: u64) -> Input {
+    Input::Down {
+        position,
+        button: Button::Primary,
+        time: ms(time),
+    }
+}
+
+fn up(position: [isize; 2], time: u64) -> Input {
+    Input::Up {
+        position,
+        button: Button::Primary,
+        time: ms(time),
+    }
+}
+
+#[test]
+fn hover() {
+    let mut view = listeners();
+    let drawables = view.build();
+
+    let events = view.pointer(&drawables, Input::Move { position: [10, 10], time: ms(0) });
+    assert_eq!(events.iter().map(|(id, event)| (*id, event.kind)).collect::<Vec<_>>(), [(1, Kind::Enter), (1, Kind::Move)]);
+
+    view.pointer(&drawables, Input::Move { position: [60, 10], time: ms(10) });
+    view.pointer(&drawables, Input::Leave { time: ms(20) });
+
+    assert_eq!(recorded(&view, 0), ["Enter", "Move", "Leave"]);
+    assert_eq!(recorded(&view, 1), ["Enter", "Move", "Leave"]);
+}
+
+#[test]
+fn clicks() {
+    let mut view = listeners();
+    let drawables = view.build();
+
+    view.pointer(&drawables, down([10, 10], 0));
+    assert_eq!(view.build()[1].state, State::Pressed);
+
+    view.pointer(&drawables, up([10, 10], 50));
+    view.pointer(&drawables, down([10, 10], 200));
+    view.pointer(&drawables, up([10, 10], 250));
+
+    assert_eq!(
+        recorded(&view, 0), 
+        ["Enter", "Down", "Up", "Click", "Down", "Up", "Click", "DoubleClick"],
+    );
+
+    // Released over another widget.
+    view.pointer(&drawables, down([10, 10], 1000));
+    view.pointer(&drawables, up([60, 10], 1050));
+    assert_eq!(recorded(&view, 0)[8..], ["Down", "Leave"]);
+    assert_eq!(recorded(&view, 1), ["Enter", "Up"]);
+}
+
+#[test]
+fn clock_going_backwards() {
+    let mut view = listeners();
+    let drawables = view.build();
+
+    view.pointer(&drawables, down([10, 10], 1000));
+    view.pointer(&drawables, up([10, 10], 1050));
+    // The clock of the backend went back, the clicks are close in time.
+    view.pointer(&drawables, down([10, 10], 500));
+    view.pointer(&drawables, up([10, 10], 550));
+
+    assert_eq!(recorded(&view, 0).last().unwrap(), "DoubleClick");
+}
+
+#[test]
+fn long_press_and_drag() {
+    let mut view = listeners();
+    let drawables = view.build();
+
+    view.pointer(&drawables, down([10, 10], 0));
+    view.pointer(&drawables, Input::Tick { time: ms(100) });
+    view.pointer(&drawables, Input::Tick { time: ms(600) });
+    view.pointer(&drawables, up([10, 10], 700));
+
+    // No click after a long press.
+    assert_eq!(recorded(&view, 0), ["Enter", "Down", "LongPress", "Up"]);
+
+    let mut view = listeners();
+    view.pointer(&drawables, down([10, 10], 0));
+    view.pointer(&drawables, Input::Move { position: [12, 10], time: ms(10) });
+    let events = view.pointer(&drawables, Input::Move { position: [30, 20], time: ms(20) });
+
+    assert!(events.iter().any(|(_, event)| event.kind == Kind::DragStart));
+    assert!(events.iter().any(|(_, event)| event.kind == Kind::DragMove { delta: [20, 10] }));
+
+    // The drag goes on outside the dragged widget.
+    let events = view.pointer(&drawables, Input::Move { position: [70, 20], time: ms(30) });
+    assert!(events.iter().any(|(id, event)| *id == 1 && event.kind == Kind::DragMove { delta: [40, 0] }));
+
+    view.pointer(&drawables, up([70, 20], 40));
+    assert_eq!(recorded(&view, 0).last().unwrap(), "DragEnd");
+    assert!(!recorded(&view, 0).contains(&"Click".to_string()));
+}
+
+#[test]
+fn wheel_and_taps() {
+    let mut view = listeners();
+    let drawables = view.build();
+
+    let events = view.pointer(&drawables, Input::Wheel { position: [60, 60], delta: [0.0, -3.0], time: ms(0) });
+    assert_eq!(events.last().unwrap().1.kind, Kind::Wheel { delta: [0.0, -3.0] });
+    assert_eq!(events.last().unwrap().0, 2);
+
+    // Tap detectors are tapped by clicks.
+    let mut view = common::view([100, 100], Direction::Column, widgets![
+        tap::Detector::new(Label::normal("label"), |label, _| {
+            label.text = "tapped".to_string();
+        }),
+    ]);
+
+    let drawables = view.build();
+    view.pointer(&drawables, down([50, 50], 0));
+    view.pointer(&drawables, up([50, 50], 10));
+
+    assert_eq!(view.layout.widgets::<Label>()[0].text, "tapped");
+}
@@ -0,0 +1,209 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Propagation of the pointer events through the controllers containing
+//! their targets.
+
+use std::{cell::RefCell, time::Duration};
+
+use haussmann::{
+    controllers::{
+        pointer::{Button, Event, Input, Kind, Listener},
+        propagation::Propagation,
+        tap,
+    },
+    graphics::draw::Drawable,
+    themes::Theme,
+    widgets::{Label, Layout, View, Widget},
+    widgets,
+    Align,
+    Direction,
+    Overflow,
+    Zone,
+};
+
+thread_local! {
+    /// Handled events, in the order they were handled.
+    static LOG: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn log(entry: String) {
+    LOG.with(|log| log.borrow_mut().push(entry));
+}
+
+/// Returns and clears the handled events of the kind.
+fn logged(kind: Kind) -> Vec<String> {
+    let kind = format!("{:?}", kind);
+
+    LOG.with(|log| log.borrow_mut().drain(..).filter(|entry| entry.ends_with(&kind)).collect())
+}
+
+fn list(event: &Event, propagation: &Propagation) {
+    log(format!("list {:?} {:?} {:?}", propagation.phase(), propagation.child(), event.kind));
+}
+
+fn row(label: &mut Label, event: &Event, propagation: &Propagation) {
+    log(format!("{} {:?} {:?}", label.text, propagation.phase(), event.kind));
+}
+
+/// Selects the tapped row.
+fn select(layout: &mut Layout, event: &Event, propagation: &mut Propagation, _: &Theme) {
+    list(event, propagation);
+
+    if event.kind == Kind::Click {
+        let row = propagation.child().unwrap();
+        layout.widgets_mut::<Listener<Label>>()[row].widget.text.push('*');
+    }
+}
+
+fn record(label: &mut Label, event: &Event, propagation: &mut Propagation, _: &Theme) {
+    row(label, event, propagation);
+}
+
+/// Stops the clicks on the row.
+fn stop(label: &mut Label, event: &Event, propagation: &mut Propagation, _: &Theme) {
+    row(label, event, propagation);
+
+    if event.kind == Kind::Click {
+        propagation.stop_propagation();
+    }
+}
+
+/// Prevents the clicks on the rows of the list.
+fn prevent(_: &mut Layout, event: &Event, propagation: &mut Propagation, _: &Theme) {
+    list(event, propagation);
+
+    if event.kind == Kind::Click {
+        propagation.prevent_default();
+    }
+}
+
+fn rows(first: Listener<Label>) -> Vec<Box<dyn Widget>> {
+    widgets![
+        first,
+        Listener::new(Label::normal("b"), record),
+        Listener::new(Label::normal("c"), record),
+    ]
+}
+
+/// A list of rows in a view of 100 by 100 pixels.
+fn view(list: Listener<Layout>) -> View {
+    View::new(
+        Zone {
+            position: [0, 0],
+            size: [100, 100],
+        },
+        Layout::normal(Overflow::Ignore, Align::Center, Align::Center, Direction::Column, widgets![list]),
+    )
+}
+
+fn layout(widgets: Vec<Box<dyn Widget>>) -> Layout {
+    Layout::normal(Overflow::Ignore, Align::Center, Align::Center, Direction::Row, widgets)
+}
+
+/// Returns the centre of the drawable of a row, the list's layout being the
+/// second drawable.
+fn centre(drawables: &[Drawable], row: usize) -> [isize; 2] {
+    let zone = drawables
+        .iter()
+        .filter(|drawable| drawable.parent == Some(1))
+        .nth(row)
+        .unwrap()
+        .zone;
+
+    [zone.x() + zone.width() as isize / 2, zone.y() + zone.height() as isize / 2]
+}
+
+fn click(view: &mut View, drawables: &[Drawable], position: [isize; 2], time: u64) {
+    for input in [
+        Input::Down {
+            position,
+            button: Button::Primary,
+            time: Duration::from_millis(time),
+        },
+        Input::Up {
+            position,
+            button: Button::Primary,
+            time: Duration::from_millis(time + 10),
+        },
+    ] {
+        view.pointer(drawables, input);
+    }
+}
+
+#[test]
+fn bubbling_to_the_list() {
+    let mut view = view(Listener::new(layout(rows(Listener::new(Label::normal("a"), record))), select));
+    let drawables = view.build();
+
+    click(&mut view, &drawables, centre(&drawables, 1), 0);
+
+    assert_eq!(logged(Kind::Click), ["b Target Click", "list Bubble Some(1) Click"]);
+    assert_eq!(view.layout.widgets::<Layout>()[0].widgets::<Label>()[1].text, "b*");
+}
+
+#[test]
+fn stopped_by_a_row() {
+    let mut view = view(Listener::new(layout(rows(Listener::new(Label::normal("a"), stop))), select));
+    let drawables = view.build();
+
+    click(&mut view, &drawables, centre(&drawables, 0), 0);
+    assert_eq!(logged(Kind::Click), ["a Target Click"]);
+    assert_eq!(view.layout.widgets::<Layout>()[0].widgets::<Label>()[0].text, "a");
+
+    // Only the clicks are stopped.
+    click(&mut view, &drawables, centre(&drawables, 0), 1000);
+    assert_eq!(logged(Kind::Down), ["a Target Down", "list Bubble Some(0) Down"]);
+}
+
+#[test]
+fn captured_by_the_list() {
+    let capturing = Listener::new(layout(rows(Listener::new(Label::normal("a"), record))), select).with_capture(true);
+    let mut view = view(capturing);
+    let drawables = view.build();
+
+    click(&mut view, &drawables, centre(&drawables, 2), 0);
+    assert_eq!(logged(Kind::Click), ["list Capture Some(2) Click", "c* Target Click"]);
+}
+
+#[test]
+fn default_prevented() {
+    fn tapped(label: &mut Label, _: &Theme) {
+        label.text.push('!');
+    }
+
+    fn listened(on_event: fn(&mut Layout, &Event, &mut Propagation, &Theme)) -> Listener<Layout> {
+        Listener::new(layout(widgets![tap::Detector::new(Label::normal("a"), tapped)]), on_event)
+    }
+
+    fn log_only(_: &mut Layout, event: &Event, propagation: &mut Propagation, _: &Theme) {
+        list(event, propagation);
+    }
+
+    // The row is tapped after the propagation.
+    let mut tapped_view = view(listened(log_only));
+    let drawables = tapped_view.build();
+    click(&mut tapped_view, &drawables, centre(&drawables, 0), 0);
+    assert_eq!(tapped_view.layout.widgets::<Layout>()[0].widgets::<Label>()[0].text, "a!");
+    assert_eq!(logged(Kind::Click), ["list Bubble Some(0) Click"]);
+
+    let mut prevented_view = view(listened(prevent).with_capture(true));
+    let drawables = prevented_view.build();
+    click(&mut prevented_view, &drawables, centre(&drawables, 0), 0);
+    assert_eq!(logged(Kind::Click), ["list Capture Some(0) Click"]);
+    assert_eq!(prevented_view.layout.widgets::<Layout>()[0].widgets::<Label>()[0].text, "a");
+}
+
+#[test]
+fn enter_not_bubbling() {
+    let mut view = view(Listener::new(layout(rows(Listener::new(Label::normal("a"), record))), select));
+    let drawables = view.build();
+
+    view.pointer(&drawables, Input::Move {
+        position: centre(&drawables, 0),
+        time: Duration::ZERO,
+    });
+
+    assert_eq!(logged(Kind::Enter), ["a Target Enter"]);
+}
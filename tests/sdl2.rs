@@ -6,18 +6,20 @@ use std::{time::Duration, borrow::Borrow};
 
 use sdl2::{
     event::{Event, WindowEvent},
-    keyboard::Keycode,
+    keyboard::{Keycode, Mod},
     mouse::MouseButton,
-    pixels::Color,
+    pixels::{Color, PixelFormatEnum},
     rect::Rect,
+    render::{Canvas, RenderTarget},
+    surface,
 };
 
 use rand::Rng;
 
 use haussmann::{
-    controllers::tap, 
-    graphics::{Size, draw, Point, colours::{self, RGBA}}, 
-    themes::{self, Style},  
+    controllers::{keyboard, pointer, tap}, 
+    graphics::{Size, draw::{self, Drawable}, colours::{self, RGBA}}, 
+    themes::{self, Mode, SpacingScale, Style},  
     widgets::*, 
     Align,
     Border,
@@ -51,9 +53,7 @@ fn with_sdl2() {
         .build()
         .unwrap();
 
-    let theme = themes::default(vec![]);
-
-    let mut view = View::new(
+    let mut view = View::themed(
         Zone {
             position: [0, 0],
             size: window_size,
@@ -109,21 +109,17 @@ fn with_sdl2() {
                         button!(
                             style!(colour: rgba!(0, 0, 255, a: 255)),
                             Label::normal("blue button"),
-                            on_tap: |button, theme| {
-                                let b_channel = &mut button.style_mut(&theme).colour.as_mut().unwrap().b;
-
-                                if *b_channel <= 10 {
-                                    *b_channel = 255;
-                                } else {
-                                    *b_channel -= 10;
-                                }
+                            on_tap: |_button, _theme| {
+                                // The button is already restyled while 
+                                // hovered and pressed.
                             }
                         ),
                     ]
                 ),
                 Surface::normal(),
             ]
-        )
+        ),
+        themes::default(vec![]),
     );
 
     // Where to draw the widgets.
@@ -145,68 +141,7 @@ fn with_sdl2() {
         canvas.clear();        
             
         // Draws the drawables.
-        for drawable in &drawables {
-            let zone = drawable.zone;
-
-            match &drawable.object {
-                draw::Object::Surface(surface) => {
-                    let colour = surface.style(&theme).colour.unwrap();
-
-                    canvas.set_draw_color(Color::RGBA(
-                        colour.r as u8,
-                        colour.g as u8,
-                        colour.b as u8,
-                        colour.a as u8,
-                    ));
-        
-                    canvas.fill_rect(Rect::new(
-                        zone.x() as i32,
-                        zone.y() as i32,
-                        zone.width() as u32,
-                        zone.height() as u32,
-                    ))
-                    .unwrap();
-
-                    // Draws the borders of this surface.
-                    let borders = surface.style(&theme)
-                        .borders
-                        .unwrap_or([None, None, None, None]);
-                    
-                    for border in borders {
-                        if border == None {
-                            continue;
-                        }
-
-                        let zone = border.clone().unwrap().as_zone(&zone);
-                        let colour = border.unwrap().colour;
-
-                        canvas.set_draw_color(Color::RGBA(
-                            colour.r as u8,
-                            colour.g as u8,
-                            colour.b as u8,
-                            colour.a as u8,
-                        ));
-
-                        canvas.fill_rect(Rect::new(
-                            zone.x() as i32,
-                            zone.y() as i32,
-                            zone.width() as u32,
-                            zone.height() as u32,
-                        ))
-                        .unwrap();
-                    }
-                }
-                draw::Object::Image(_) => {
-                    //println!("draws image {:?}", image);
-                }
-                draw::Object::Label(_) => {
-                    //println!("draws label {:?}", label);
-                }
-                draw::Object::Unknown(widget) => {
-                    println!("unknown widget to draw : {:?}", widget);
-                }
-            }
-        }
+        draw(&mut canvas, &drawables, &view.theme().tokens.spacing);
 
         // Events handling
         for event in event_pump.poll_iter() {
@@ -216,6 +151,60 @@ fn with_sdl2() {
                     keycode: Some(Keycode::Escape),
                     ..
                 } => break 'running,
+                Event::KeyDown {
+                    keycode: Some(Keycode::D),
+                    ..
+                } => {
+                    // Toggles the dark mode.
+                    let mode = match view.theme().mode() {
+                        Mode::Dark => Mode::Light,
+                        _ => Mode::Dark,
+                    };
+
+                    drawables = view.set_theme_mode(mode);
+                }
+                Event::KeyDown {
+                    keycode: Some(keycode), keymod, repeat, ..
+                } => {
+                    let key = match keycode {
+                        Keycode::Tab => keyboard::Key::Tab,
+                        Keycode::Return => keyboard::Key::Enter,
+                        Keycode::Space => keyboard::Key::Space,
+                        Keycode::Backspace => keyboard::Key::Backspace,
+                        Keycode::Delete => keyboard::Key::Delete,
+                        Keycode::Left => keyboard::Key::Left,
+                        Keycode::Right => keyboard::Key::Right,
+                        Keycode::Up => keyboard::Key::Up,
+                        Keycode::Down => keyboard::Key::Down,
+                        Keycode::Home => keyboard::Key::Home,
+                        Keycode::End => keyboard::Key::End,
+                        _ => continue,
+                    };
+                    let modifiers = keyboard::Modifiers {
+                        shift: keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD),
+                        control: keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD),
+                        alt: keymod.intersects(Mod::LALTMOD | Mod::RALTMOD),
+                        meta: keymod.intersects(Mod::LGUIMOD | Mod::RGUIMOD),
+                    };
+
+                    // Moves the focus with tab, and taps the focused button 
+                    // with enter or space.
+                    view.key(&drawables, keyboard::Event::Down { key, modifiers, repeat });
+                    drawables = view.build();
+                }
+                Event::TextInput { text, .. } => {
+                    for character in text.chars() {
+                        view.key(&drawables, keyboard::Event::Char { character });
+                    }
+                    drawables = view.build();
+                }
+                Event::TextEditing { text, start, .. } => {
+                    view.key(&drawables, keyboard::Event::Composition { 
+                        text, 
+                        cursor: start.max(0) as usize,
+                    });
+                    drawables = view.build();
+                }
                 Event::Window { win_event, .. } => match win_event {
                     WindowEvent::Resized(width, height) => {
                         window_size = [width as usize, height as usize];
@@ -225,22 +214,42 @@ fn with_sdl2() {
                     }
                     _ => {}
                 },
-                Event::MouseButtonDown {
-                    mouse_btn, x, y, ..
+                Event::MouseMotion {
+                    timestamp, x, y, ..
                 } => {
-                    if mouse_btn != MouseButton::Left {
-                        return;
-                    }
+                    let input = pointer::Input::Move {
+                        position: [x as isize, y as isize],
+                        time: Duration::from_millis(timestamp as u64),
+                    };
 
-                    let tap: Point = [x as isize, y as isize];
+                    // Restyles the buttons when they are hovered.
+                    view.pointer(&drawables, input);
+                    drawables = view.build();
+                }
+                Event::MouseButtonDown {
+                    timestamp, mouse_btn: MouseButton::Left, x, y, ..
+                } => {
+                    let input = pointer::Input::Down {
+                        position: [x as isize, y as isize],
+                        button: pointer::Button::Primary,
+                        time: Duration::from_millis(timestamp as u64),
+                    };
 
-                    view.controllers::<tap::Detector<Button>>(drawables, |controller| {
-                        if tap::is_tapped(tap, controller.zone) {
-                            controller.on_tap(&theme);
-                        }
-                    });
+                    view.pointer(&drawables, input);
+                    drawables = view.build();
+                }
+                Event::MouseButtonUp {
+                    timestamp, mouse_btn: MouseButton::Left, x, y, ..
+                } => {
+                    let input = pointer::Input::Up {
+                        position: [x as isize, y as isize],
+                        button: pointer::Button::Primary,
+                        time: Duration::from_millis(timestamp as u64),
+                    };
 
-                    drawables = view.rebuild([0, 0], window_size);
+                    // The tapped buttons are clicked.
+                    view.pointer(&drawables, input);
+                    drawables = view.build();
                 }
                 _ => {}
             }
@@ -250,3 +259,207 @@ fn with_sdl2() {
         ::std::thread::sleep(Duration::new(0, 1_000_000_000u32 / 60));
     }
 }
+
+#[test]
+fn chart_with_sdl2() {
+    const RED: RGBA = RGBA { r: 200, g: 0, b: 0, a: 255 };
+    const BLUE: RGBA = RGBA { r: 0, g: 0, b: 200, a: 255 };
+
+    // Draws a chart in memory, without a window, and returns its pixels.
+    let pixels = |size: Size, chart: Chart| {
+        let view = View::new(([0, 0], size).into(), Layout::normal(
+            Overflow::Hide,
+            Align::Left,
+            Align::Top,
+            Direction::Row,
+            widgets![chart],
+        ));
+        let drawables = view.build();
+
+        let surface = surface::Surface::new(size[0] as u32, size[1] as u32, PixelFormatEnum::RGBA32).unwrap();
+        let mut canvas = surface.into_canvas().unwrap();
+        draw(&mut canvas, &drawables, &view.theme().tokens.spacing);
+
+        let pixels = canvas.read_pixels(None, PixelFormatEnum::RGBA32).unwrap();
+        move |x: usize, y: usize| {
+            let i = (y * size[0] + x) * 4;
+            RGBA::new(pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3])
+        }
+    };
+
+    // The bars of the charts' tests, from zero.
+    let pixel = pixels([248, 148], Chart::bar(&["a", "b"], vec![
+        Series::new("first", &[10.0, 30.0]).with_colour(RED),
+        Series::new("second", &[20.0, -10.0]).with_colour(BLUE),
+    ]));
+
+    assert_eq!(pixel(70, 90), RED);
+    assert_eq!(pixel(110, 60), BLUE);
+    assert_eq!(pixel(170, 30), RED);
+    assert_eq!(pixel(210, 110), BLUE);
+    assert_ne!(pixel(70, 60), RED);
+
+    // The lines go through their values, in the middle of each category.
+    let pixel = pixels([348, 148], Chart::line(&["a", "b", "c"], vec![
+        Series::new("first", &[0.0, 10.0, 20.0]).with_colour(RED),
+    ]));
+
+    assert_eq!(pixel(198, 74), RED);
+    assert_eq!(pixel(298, 24), RED);
+    assert_ne!(pixel(198, 40), RED);
+}
+
+/// Draws the drawables on a canvas, the token widths of the borders resolved
+/// with a spacing scale.
+fn draw<T: RenderTarget>(canvas: &mut Canvas<T>, drawables: &[Drawable], scale: &SpacingScale) {
+    for drawable in drawables {
+        // SDL2 does not draw rotated rectangles, the transformed zone is 
+        // approximated by its bounds.
+        let zone = drawable.transform.bounds(drawable.zone);
+
+        match &drawable.object {
+            draw::Object::Surface(_) => {
+                // The style was resolved with the view's theme.
+                let style = drawable.style.as_ref().unwrap();
+                let colour = style.colour.unwrap();
+
+                canvas.set_draw_color(Color::RGBA(
+                    colour.r,
+                    colour.g,
+                    colour.b,
+                    colour.a,
+                ));
+    
+                canvas.fill_rect(Rect::new(
+                    zone.x() as i32,
+                    zone.y() as i32,
+                    zone.width() as u32,
+                    zone.height() as u32,
+                ))
+                .unwrap();
+
+                // Draws the borders of this surface.
+                let borders = style
+                    .borders
+                    .clone()
+                    .unwrap_or([None, None, None, None]);
+                
+                for border in borders {
+                    if border == None {
+                        continue;
+                    }
+
+                    let zone = border.clone().unwrap().as_zone(&zone, scale);
+                    let colour = border.unwrap().colour;
+
+                    canvas.set_draw_color(Color::RGBA(
+                        colour.r,
+                        colour.g,
+                        colour.b,
+                        colour.a,
+                    ));
+
+                    canvas.fill_rect(Rect::new(
+                        zone.x() as i32,
+                        zone.y() as i32,
+                        zone.width() as u32,
+                        zone.height() as u32,
+                    ))
+                    .unwrap();
+                }
+            }
+            draw::Object::Image(_) => {
+                //println!("draws image {:?}", image);
+            }
+            draw::Object::Label(_) => {
+                //println!("draws label {:?}", label);
+            }
+            draw::Object::Icon(_) => {
+                //println!("draws icon {:?}", icon);
+            }
+            draw::Object::Canvas(commands) => {
+                for command in commands {
+                    match command {
+                        Command::Rect { zone, colour } => {
+                            let zone = drawable.transform.bounds(*zone);
+
+                            canvas.set_draw_color(Color::RGBA(
+                                colour.r,
+                                colour.g,
+                                colour.b,
+                                colour.a,
+                            ));
+
+                            canvas.fill_rect(Rect::new(
+                                zone.x() as i32,
+                                zone.y() as i32,
+                                zone.width() as u32,
+                                zone.height() as u32,
+                            ))
+                            .unwrap();
+                        }
+                        Command::Line { from, to, stroke } => {
+                            let [x1, y1] = drawable.transform.apply(*from);
+                            let [x2, y2] = drawable.transform.apply(*to);
+                            let colour = stroke.colour;
+
+                            canvas.set_draw_color(Color::RGBA(
+                                colour.r,
+                                colour.g,
+                                colour.b,
+                                colour.a,
+                            ));
+
+                            canvas.draw_line(
+                                (x1 as i32, y1 as i32),
+                                (x2 as i32, y2 as i32),
+                            )
+                            .unwrap();
+                        }
+                        Command::Path { .. } => {
+                            //println!("draws path {:?}", path);
+                        }
+                        Command::Text { .. } => {
+                            //println!("draws text {:?}", text);
+                        }
+                        Command::Image { .. } => {
+                            //println!("draws image {:?}", image);
+                        }
+                    }
+                }
+            }
+            draw::Object::Path(path) => {
+                // SDL2 does not fill polygons, the path is drawn as its
+                // outline, in the colour of its stroke or of its fill.
+                let colour = match (&path.stroke, path.fill) {
+                    (Some(stroke), _) => stroke.colour,
+                    (None, Some(fill)) => fill,
+                    (None, None) => continue,
+                };
+
+                canvas.set_draw_color(Color::RGBA(
+                    colour.r,
+                    colour.g,
+                    colour.b,
+                    colour.a,
+                ));
+
+                for polyline in path.polylines_in(drawable.zone, TOLERANCE) {
+                    for (from, to) in polyline.lines() {
+                        let [x1, y1] = drawable.transform.apply(from);
+                        let [x2, y2] = drawable.transform.apply(to);
+
+                        canvas.draw_line(
+                            (x1 as i32, y1 as i32),
+                            (x2 as i32, y2 as i32),
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+            draw::Object::Unknown(widget) => {
+                println!("unknown widget to draw : {:?}", widget);
+            }
+        }
+    }
+}
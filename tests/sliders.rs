@@ -0,0 +1,224 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Sliders moved by their controllers, and progress indicators.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::time::Duration;
+
+use haussmann::{
+    controllers::{
+        keyboard::{self, Key},
+        pointer::{Button, Input},
+        slide::Dragger,
+    },
+    graphics::draw::{Drawable, Object},
+    themes::Component,
+    widgets::{Label, Layout, ProgressBar, Slider, Spinner, Stack, Surface, View, Weighted, Widget},
+    widgets,
+    Align,
+    Direction,
+    Overflow,
+};
+
+use common::{log, logged, view};
+
+/// A view of the length of the sliders with a widget.
+fn slider_view(widget: impl Into<Box<dyn Widget>>) -> View {
+    view([216, 16], Direction::Column, vec![widget.into()])
+}
+
+/// Returns the widths of the surfaces of a component.
+fn widths(drawables: &[Drawable], component: Component) -> Vec<usize> {
+    drawables
+        .iter()
+        .filter(|drawable| matches!(&drawable.object, Object::Surface(surface) if surface.component == component))
+        .map(|drawable| drawable.zone.width())
+        .collect()
+}
+
+fn press(view: &mut View, drawables: &[Drawable], x: isize, time: u64) {
+    view.pointer(drawables, Input::Down {
+        position: [x, 8],
+        button: Button::Primary,
+        time: Duration::from_millis(time),
+    });
+}
+
+#[test]
+fn weighted_shares() {
+    let surface = Surface::normal;
+    let drawables = slider_view(Layout::normal(
+        Overflow::Ignore,
+        Align::Center,
+        Align::Center,
+        Direction::Column,
+        widgets![Weighted::new(1.0, surface()), Weighted::new(3.0, surface()), surface()],
+    )).build();
+
+    assert_eq!(drawables[2].zone.width(), 216 / 5);
+    assert_eq!(drawables[3].zone.width(), 216 * 3 / 5);
+    assert_eq!(drawables[4].zone.width(), 216 / 5);
+
+    // The weighted widgets are built like they would be without weight.
+    let mut view = slider_view(Layout::normal(
+        Overflow::Ignore,
+        Align::Center,
+        Align::Center,
+        Direction::Column,
+        widgets![
+            Weighted::new(1.0, Stack::new().filled(Label::normal("stacked"))),
+            Weighted::new(3.0, Layout::normal(
+                Overflow::Ignore,
+                Align::Center,
+                Align::Center,
+                Direction::Column,
+                widgets![Dragger::new(Slider::normal(0.0, 1.0, 0.5), |_, _| {})],
+            )),
+        ],
+    ));
+    let drawables = view.build();
+
+    let label = drawables.iter().find(|drawable| matches!(drawable.object, Object::Label(_))).unwrap();
+    assert_eq!(label.zone.width(), 216 / 4);
+    assert_eq!(view.focus_order(&drawables), vec![4]);
+}
+
+#[test]
+fn stepped_values() {
+    let mut slider = Slider::normal(0.0, 10.0, 3.3).with_step(2.0);
+    assert_eq!(slider.values(), [4.0]);
+
+    // Out of the bounds.
+    assert!(slider.set_value(0, 13.0));
+    assert_eq!(slider.values(), [10.0]);
+    assert!(!slider.set_value(0, 9.5));
+
+    // Not past the last step when the maximum is off the steps.
+    let mut slider = Slider::normal(0.0, 10.0, 0.0).with_step(3.0);
+    assert!(slider.set_value(0, 11.0));
+    assert_eq!(slider.values(), [9.0]);
+    assert!(!slider.set_value(0, 10.0));
+
+    // No such thumb.
+    assert!(!slider.set_value(1, 5.0));
+    assert_eq!(slider.values(), [9.0]);
+
+    // The thumbs of a range do not cross.
+    let mut range = Slider::range(0.0, 100.0, [20.0, 60.0]);
+    range.set_value(0, 80.0);
+    assert_eq!(range.values(), [60.0, 60.0]);
+    assert_eq!(range.closest_thumb(70.0), 1);
+    assert_eq!(range.closest_thumb(10.0), 0);
+
+    // Bounds in the wrong order keep the values at the minimum.
+    assert_eq!(Slider::normal(10.0, 0.0, 5.0).values(), [10.0]);
+    assert_eq!(Slider::range(10.0, 0.0, [2.0, 8.0]).values(), [10.0, 10.0]);
+
+    // The centre of the thumbs goes from half a thumb to the length minus
+    // half a thumb, the minimum is at the bottom of a vertical slider.
+    let slider = Slider::normal(0.0, 100.0, 0.0);
+    assert_eq!(slider.value_at([8, 0], 216, 0), 0.0);
+    assert_eq!(slider.value_at([108, 0], 216, 0), 50.0);
+    assert_eq!(slider.value_at([300, 0], 216, 0), 100.0);
+    assert_eq!(slider.vertical().value_at([0, 58], 216, 0), 75.0);
+
+    // The thumbs of a range are where the built track places them.
+    let range = Slider::range(0.0, 100.0, [25.0, 50.0]);
+    assert_eq!(range.value_at([54, 0], 216, 0), 25.0);
+    assert_eq!(range.value_at([116, 0], 216, 1), 50.0);
+    assert_eq!(range.closest_thumb_at([110, 0], 216).0, 1);
+    assert_eq!(range.closest_thumb_at([70, 0], 216).0, 0);
+    assert_eq!(range.vertical().value_at([0, 100], 216, 1), 50.0);
+}
+
+#[test]
+fn identifiers() {
+    let drawables = View::new(([0, 0], [216, 32]).into(), Layout::normal(
+        Overflow::Ignore,
+        Align::Center,
+        Align::Center,
+        Direction::Row,
+        widgets![Slider::range(0.0, 100.0, [25.0, 50.0]), Label::normal("after")],
+    )).build();
+
+    // The layouts of the track are part of the slider.
+    let label = drawables.iter().find(|drawable| matches!(drawable.object, Object::Label(_))).unwrap();
+    assert_eq!(label.group_id, 2);
+    assert!(drawables[1..drawables.len() - 1].iter().all(|drawable| drawable.group_id == 1));
+}
+
+#[test]
+fn built_track() {
+    let drawables = slider_view(Slider::range(0.0, 100.0, [25.0, 50.0])).build();
+
+    // The segments share the length left by the thumbs.
+    assert_eq!(widths(&drawables, Component::Thumb), [16, 16]);
+    assert_eq!(widths(&drawables, Component::Track), [46, 92]);
+    assert_eq!(widths(&drawables, Component::Indicator), [46]);
+
+    let drawables = slider_view(ProgressBar::determinate(0.25)).build();
+    assert_eq!(widths(&drawables, Component::Indicator), [54]);
+}
+
+#[test]
+fn dragged_and_keys() {
+    let mut view = slider_view(Dragger::new(Slider::range(0.0, 100.0, [0.0, 100.0]).with_step(10.0), |slider, _| {
+        log(format!("{:?}", slider.values()))
+    }));
+    let drawables = view.build();
+
+    // Pressing moves the closest thumb under the pointer, dragging moves it.
+    press(&mut view, &drawables, 148, 0);
+    assert_eq!(logged(), ["[0.0, 70.0]"]);
+    view.pointer(&drawables, Input::Move {
+        position: [60, 8],
+        time: Duration::from_millis(10),
+    });
+    assert_eq!(logged(), ["[0.0, 20.0]"]);
+    view.pointer(&drawables, Input::Up {
+        position: [60, 8],
+        button: Button::Primary,
+        time: Duration::from_millis(20),
+    });
+
+    // The keys move the last moved thumb.
+    view.set_focus(&drawables, Some(1), true);
+    view.key(&drawables, keyboard::Event::down(Key::Right));
+    view.key(&drawables, keyboard::Event::down(Key::PageDown));
+    view.key(&drawables, keyboard::Event::down(Key::End));
+    assert_eq!(logged(), ["[0.0, 30.0]", "[0.0, 0.0]", "[0.0, 100.0]"]);
+
+    // Nothing changes at the bound.
+    view.key(&drawables, keyboard::Event::down(Key::Up));
+    assert!(logged().is_empty());
+}
+
+#[test]
+fn progress() {
+    let mut bar = ProgressBar::indeterminate();
+    bar.advance(Duration::from_millis(750));
+    assert_eq!(bar.phase, 0.5);
+    bar.advance(Duration::from_millis(1500));
+    assert_eq!(bar.phase, 0.5);
+
+    let drawables = slider_view(bar).build();
+    assert_eq!(widths(&drawables, Component::Indicator), [64]);
+
+    // A tick of a spinner is drawn for each twelfth of the progress.
+    let indicators = |spinner: Spinner| {
+        let drawables = slider_view(spinner).build();
+        let count = |component| drawables
+            .iter()
+            .filter(|drawable| matches!(&drawable.object, Object::Surface(surface) if surface.component == component))
+            .count();
+
+        (count(Component::Indicator), count(Component::Track))
+    };
+
+    assert_eq!(indicators(Spinner::determinate(0.5)), (6, 6));
+    assert_eq!(indicators(Spinner::indeterminate()), (3, 9));
+}
@@ -0,0 +1,90 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Interaction states tracked by the controllers and styles of the states.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use haussmann::{
+    controllers::{tap, Controller},
+    graphics::{colours, draw::Drawable},
+    themes::{Component, State, Style},
+    widgets::{Button, Label, View, Widget},
+    widgets,
+    Direction,
+};
+
+use common::view;
+
+/// A view with a tapped button.
+fn button_view() -> View {
+    view([100, 100], Direction::Column, widgets![
+        tap::Detector::new(Button::normal(Label::normal("button")), |_, _| {}),
+    ])
+}
+
+/// Returns the drawables of the button: its surface and its label.
+fn button(drawables: &[Drawable]) -> (&Drawable, &Drawable) {
+    (&drawables[1], &drawables[2])
+}
+
+#[test]
+fn track_pointer() {
+    let mut view = button_view();
+    let drawables = view.build();
+    let zone = button(&drawables).0.zone;
+    let inside = [zone.x() + 1, zone.y() + 1];
+
+    assert!(view.track::<tap::Detector<Button>>(drawables, inside, false));
+    let hovered = view.build();
+    assert_eq!(button(&hovered).0.state, State::Hovered);
+    assert_eq!(button(&hovered).1.state, State::Hovered);
+
+    assert!(view.track::<tap::Detector<Button>>(hovered, inside, true));
+    let pressed = view.build();
+    assert_eq!(button(&pressed).0.state, State::Pressed);
+
+    // Nothing changes while the pointer stays pressed.
+    assert!(!view.track::<tap::Detector<Button>>(pressed, inside, true));
+
+    assert!(view.track::<tap::Detector<Button>>(view.build(), [-10, -10], false));
+    assert_eq!(button(&view.build()).0.state, State::Normal);
+}
+
+#[test]
+fn state_styles() {
+    let mut view = button_view();
+    let theme = view.theme().clone();
+    let normal = view.build();
+    let zone = button(&normal).0.zone;
+
+    view.track::<tap::Detector<Button>>(view.build(), [zone.x() + 1, zone.y() + 1], false);
+    let hovered = view.build();
+
+    let styles = &theme.button_theme.styles;
+    assert_eq!(button(&normal).0.style.as_ref().unwrap().colour, styles.normal.colour);
+    assert_eq!(button(&hovered).0.style.as_ref().unwrap().colour, styles.get(State::Hovered).colour);
+    assert_ne!(styles.get(State::Hovered).colour, styles.normal.colour);
+
+    // Disabled widgets are not tracked and their labels are faded.
+    view.controllers::<tap::Detector<Button>>(view.build(), |controller| {
+        controller.set_state(State::Disabled);
+    });
+
+    let disabled = view.build();
+    assert!(!view.track::<tap::Detector<Button>>(view.build(), [zone.x() + 1, zone.y() + 1], true));
+    assert_eq!(button(&disabled).0.style.as_ref().unwrap().colour, styles.get(State::Disabled).colour);
+    assert!(button(&disabled).1.label_style.as_ref().unwrap().colour.a < theme.button_theme.label_style.colour.a);
+
+    // Independent styles are layered too.
+    let style = Style {
+        colour: Some(colours::RED),
+        borders: None,
+        radius: None,
+        tokens: Default::default(),
+    };
+    let pressed = theme.independent_style_in(&style, Component::Button, State::Pressed);
+    assert_ne!(pressed.colour, style.colour);
+}
@@ -0,0 +1,225 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Tables sorted, selected and resized by their grid.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::time::Duration;
+
+use haussmann::{
+    controllers::{
+        keyboard::{self, Key},
+        pointer::{Button, Input},
+        table::Grid,
+        tap,
+    },
+    graphics::draw::Object,
+    themes::Component,
+    widgets::{self, Column, ColumnWidth, Label, Part, Sort, Stack, Table, View, Widget},
+    Direction,
+};
+
+use common::{click, logged_in, texts, view, Log};
+
+fn cell(row: usize, column: usize) -> Box<dyn Widget> {
+    Label::normal(&format!("r{}c{}", row, column)).into()
+}
+
+fn table() -> Table {
+    Table::new(
+        vec![Column::fixed("id", 60), Column::flex("name", 1.0), Column::flex("size", 1.0)],
+        3,
+        cell,
+    )
+}
+
+/// A view of 300x200 with a table over a button, with the log of its grid.
+fn table_view() -> (View, Log) {
+    let log = Log::default();
+    let (sorts, selections) = (log.clone(), log.clone());
+    let grid = Grid::new(
+        table(),
+        move |_, column, sort, _| sorts.borrow_mut().push(format!("sort {} {:?}", column, sort)),
+        move |table, _| selections.borrow_mut().push(format!("select {:?}", table.selected)),
+    );
+
+    let view = view([300, 200], Direction::Row, haussmann::widgets![
+        grid,
+        tap::Detector::new(widgets::Button::normal(Label::normal("tap")), |_, _| ()),
+    ]);
+
+    (view, log)
+}
+
+fn table_of(view: &View) -> &Table {
+    view.layout.widgets[0].as_any().downcast_ref().unwrap()
+}
+
+#[test]
+fn columns() {
+    let mut table = table();
+    assert_eq!(table.column_widths(300), [60, 120, 120]);
+
+    assert_eq!(table.part_at([30, 10], 300), Some(Part::Header(0)));
+    assert_eq!(table.part_at([58, 10], 300), Some(Part::Border(0)));
+    assert_eq!(table.part_at([200, 60], 300), Some(Part::Cell(1, 2)));
+    assert_eq!(table.part_at([200, 100], 300), None);
+
+    assert_eq!(table.sort_by(1), Some(Sort::Ascending));
+    assert_eq!(table.sort_by(1), Some(Sort::Descending));
+    assert_eq!(table.sort_by(0), Some(Sort::Ascending));
+    assert_eq!(table.columns[1].sort, None);
+
+    // Resized to a fixed width, not smaller than the minimum.
+    assert!(table.resize(1, -110, 300));
+    assert_eq!(table.columns[1].width, ColumnWidth::Fixed(24));
+    assert_eq!(table.column_widths(300), [60, 24, 216]);
+
+    // Nothing happens without the column.
+    assert_eq!(table.sort_by(3), None);
+    assert!(!table.resize(3, 10, 300));
+    assert_eq!(table.columns[0].sort, Some(Sort::Ascending));
+}
+
+#[test]
+fn cell_ids() {
+    let (mut view, _) = table_view();
+    let drawables = view.build();
+    let table = table_of(&view);
+
+    // Each header and each cell has its own identifier.
+    assert_eq!(texts(&drawables, table.header_id(1, 1)), ["name"]);
+    assert_eq!(texts(&drawables, table.cell_id(1, 1, 2)), ["r1c2"]);
+
+    let cell = drawables.iter().find(|drawable| drawable.group_id as usize == table.cell_id(1, 1, 2)).unwrap();
+    assert_eq!(cell.zone, ([180, 48], [120, 24]).into());
+    assert_eq!(cell.clip, Some(([0, 0], [300, 100]).into()));
+
+    // The widgets after the table follow its cells.
+    assert_eq!(table.id_count(), 13);
+    assert_eq!(texts(&drawables, 14), ["tap"]);
+    assert_eq!(view.focus_order(&drawables), [1, 14]);
+}
+
+#[test]
+fn sorted_and_selected() {
+    let (mut view, log) = table_view();
+    let drawables = view.build();
+
+    click(&mut view, &drawables, [100, 10]);
+    click(&mut view, &drawables, [100, 10]);
+    assert_eq!(logged_in(&log), ["sort 1 Ascending", "sort 1 Descending"]);
+
+    let drawables = view.build();
+    assert_eq!(texts(&drawables, 3), ["name", "▾"]);
+
+    click(&mut view, &drawables, [100, 60]);
+    assert_eq!(logged_in(&log), ["select Some(1)"]);
+
+    let drawables = view.build();
+    let selected: Vec<usize> = drawables
+        .iter()
+        .filter(|drawable| matches!(&drawable.object, Object::Surface(surface) if surface.component == Component::TableSelected))
+        .map(|drawable| drawable.group_id as usize)
+        .collect();
+    assert_eq!(selected, [8, 9, 10]);
+
+    view.key(&drawables, keyboard::Event::down(Key::Down));
+    view.key(&drawables, keyboard::Event::down(Key::End));
+    view.key(&drawables, keyboard::Event::down(Key::Home));
+    assert_eq!(logged_in(&log), ["select Some(2)", "select Some(0)"]);
+}
+
+#[test]
+fn resized() {
+    let (mut view, log) = table_view();
+    let drawables = view.build();
+
+    view.pointer(&drawables, Input::Down {
+        position: [58, 10],
+        button: Button::Primary,
+        time: Duration::ZERO,
+    });
+
+    for x in [70, 98] {
+        view.pointer(&drawables, Input::Move {
+            position: [x, 12],
+            time: Duration::from_millis(10),
+        });
+    }
+
+    view.pointer(&drawables, Input::Up {
+        position: [98, 12],
+        button: Button::Primary,
+        time: Duration::from_millis(20),
+    });
+
+    assert_eq!(table_of(&view).columns[0].width, ColumnWidth::Fixed(100));
+    assert_eq!(table_of(&view).column_widths(300), [100, 100, 100]);
+    assert!(logged_in(&log).is_empty());
+}
+
+#[test]
+fn resized_past_minimum() {
+    let (mut view, _) = table_view();
+    let drawables = view.build();
+
+    view.pointer(&drawables, Input::Down {
+        position: [58, 10],
+        button: Button::Primary,
+        time: Duration::ZERO,
+    });
+
+    // Dragged far past the minimum width.
+    view.pointer(&drawables, Input::Move {
+        position: [0, 12],
+        time: Duration::from_millis(10),
+    });
+    assert_eq!(table_of(&view).columns[0].width, ColumnWidth::Fixed(24));
+
+    // Then back to 12 pixels right of the border, which still follows the 
+    // pointer.
+    view.pointer(&drawables, Input::Move {
+        position: [70, 12],
+        time: Duration::from_millis(20),
+    });
+    assert_eq!(table_of(&view).columns[0].width, ColumnWidth::Fixed(72));
+}
+
+#[test]
+fn hidden_rows() {
+    let (mut view, _) = table_view();
+    view.layout.widgets[0]
+        .as_any_mut()
+        .downcast_mut::<Grid>()
+        .unwrap()
+        .widget
+        .rows = 1000;
+    let drawables = view.build();
+    let table = table_of(&view);
+
+    // Only the rows in the 100 pixels of the table are built, even partially.
+    assert_eq!(texts(&drawables, table.cell_id(1, 3, 0)), ["r3c0"]);
+    assert!(texts(&drawables, table.cell_id(1, 4, 0)).is_empty());
+    assert!(drawables.len() < 100);
+
+    // The identifiers of the hidden rows are still taken.
+    assert_eq!(texts(&drawables, table.id_count() + 1), ["tap"]);
+}
+
+#[test]
+fn stacked() {
+    let mut view = view([300, 200], Direction::Row, haussmann::widgets![
+        Stack::new().filled(table()),
+        tap::Detector::new(widgets::Button::normal(Label::normal("tap")), |_, _| ()),
+    ]);
+    let drawables = view.build();
+
+    // The cells of a table in a stack share the identifier of the stack.
+    assert!(texts(&drawables, 1).contains(&"r2c1".to_string()));
+    assert_eq!(texts(&drawables, 2), ["tap"]);
+    assert_eq!(view.focus_order(&drawables), [2]);
+}
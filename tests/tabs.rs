@@ -0,0 +1,156 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Tabs and navigation bars switched by their switcher.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use haussmann::{
+    controllers::{
+        keyboard::{self, Key},
+        tabs::Switcher,
+        tap,
+    },
+    graphics::draw::Object,
+    themes::Component,
+    widgets::{self, Label, Layout, NavigationBar, Stack, Tabs, View, Widget},
+    Align,
+    Direction,
+    Overflow,
+};
+
+use common::{click_at, log, logged, texts, view};
+
+/// A view of 300x200 with a widget over a button.
+fn tabs_view(widget: impl Into<Box<dyn Widget>>) -> View {
+    view([300, 200], Direction::Row, vec![
+        widget.into(),
+        tap::Detector::new(widgets::Button::normal(Label::normal("after")), |_, _| log("after".to_string())).into(),
+    ])
+}
+
+/// Tabs with a page with a button, and a page with a text.
+fn tabs() -> Switcher<Tabs> {
+    let button = tap::Detector::new(widgets::Button::normal(Label::normal("tap")), |_, _| log("tap".to_string()));
+    let page = Layout::normal(Overflow::Hide, Align::Center, Align::Center, Direction::Row, haussmann::widgets![button]);
+
+    Switcher::new(
+        Tabs::new(vec![("one", page.into()), ("two", Label::normal("second page").into())]),
+        |tabs, _| log(format!("select {}", tabs.selected())),
+    )
+}
+
+#[test]
+fn items() {
+    let mut bar = NavigationBar::normal(&["home", "search", "settings"], 0);
+    assert_eq!(bar.item_at([250, 10], [300, 40]), Some(2));
+    assert_eq!(bar.item_at([300, 10], [300, 40]), None);
+    assert_eq!(bar.item_at([250, 40], [300, 40]), None);
+
+    assert!(bar.select(1));
+    assert!(!bar.select(1));
+    assert!(!bar.select(3));
+    assert_eq!(bar.selected, 1);
+
+    // The destinations of a rail are one under the other.
+    let rail = NavigationBar::rail(&["home", "search", "settings"], 0);
+    assert_eq!(rail.item_at([10, 70], [80, 120]), Some(1));
+}
+
+#[test]
+fn pages() {
+    let mut view = tabs_view(tabs());
+    let drawables = view.build();
+
+    // The bar, then the selected page, then the widgets after the tabs.
+    assert_eq!(texts(&drawables, 2), ["one", "two"]);
+    assert_eq!(texts(&drawables, 4), ["tap"]);
+    assert_eq!(texts(&drawables, 5), ["after"]);
+    assert_eq!(view.focus_order(&drawables), [1, 4, 5]);
+
+    let selected = drawables
+        .iter()
+        .find(|drawable| matches!(&drawable.object, Object::Surface(surface) if surface.component == Component::NavigationSelected))
+        .unwrap();
+    assert_eq!(selected.zone, ([0, 0], [150, 40]).into());
+
+    // Each destination is a label styled for the bar, the selected one for
+    // the indicator around it.
+    let destinations: Vec<_> = drawables
+        .iter()
+        .filter_map(|drawable| match &drawable.object {
+            Object::Label(label) if drawable.group_id == 2 => Some((label.component, drawable.zone)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(destinations.len(), 2);
+    assert_eq!(destinations[0].0, Component::NavigationSelected);
+    assert_eq!(destinations[1].0, Component::Navigation);
+    assert!(selected.zone.contains(destinations[0].1.position));
+    assert!(!selected.zone.contains(destinations[1].1.position));
+
+    // The controllers of the page are reached.
+    click_at(&mut view, &drawables, [150, 70], 0);
+    assert_eq!(logged(), ["tap"]);
+
+    click_at(&mut view, &drawables, [225, 20], 1);
+    assert_eq!(logged(), ["select 1"]);
+
+    let drawables = view.build();
+    assert_eq!(texts(&drawables, 3), ["second page"]);
+    assert_eq!(texts(&drawables, 4), ["after"]);
+    assert_eq!(view.focus_order(&drawables), [1, 4]);
+
+    let page = drawables.iter().find(|drawable| drawable.group_id == 3).unwrap();
+    assert_eq!(page.zone, ([0, 40], [300, 60]).into());
+}
+
+#[test]
+fn blank_page() {
+    let mut view = tabs_view(tabs());
+    let drawables = view.build();
+
+    click_at(&mut view, &drawables, [225, 20], 0);
+    assert_eq!(logged(), ["select 1"]);
+
+    // A click on the page out of its controllers selects nothing.
+    let drawables = view.build();
+    click_at(&mut view, &drawables, [20, 70], 1);
+    assert!(logged().is_empty());
+    assert_eq!(texts(&view.build(), 3), ["second page"]);
+}
+
+#[test]
+fn stacked() {
+    let tabs = Tabs::new(vec![("one", Label::normal("first page").into()), ("two", Label::normal("second page").into())]);
+    let mut view = tabs_view(Stack::new().filled(tabs));
+    let drawables = view.build();
+
+    // The bar and the page of tabs in a stack share the identifier of the
+    // stack.
+    assert_eq!(texts(&drawables, 1), ["one", "two", "first page"]);
+    assert_eq!(texts(&drawables, 2), ["after"]);
+    assert_eq!(view.focus_order(&drawables), [2]);
+}
+
+#[test]
+fn rail() {
+    let switcher = Switcher::new(
+        NavigationBar::rail(&["home", "search", "settings"], 0),
+        |bar, _| log(format!("select {}", bar.selected)),
+    );
+    let mut view = tabs_view(switcher);
+    let drawables = view.build();
+
+    view.set_focus(&drawables, Some(1), true);
+    view.key(&drawables, keyboard::Event::down(Key::Right));
+    view.key(&drawables, keyboard::Event::down(Key::Down));
+    view.key(&drawables, keyboard::Event::down(Key::End));
+    view.key(&drawables, keyboard::Event::down(Key::Up));
+    assert_eq!(logged(), ["select 1", "select 2", "select 1"]);
+
+    click_at(&mut view, &drawables, [150, 10], 0);
+    assert_eq!(logged(), ["select 0"]);
+}
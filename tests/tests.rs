@@ -3,14 +3,89 @@
 // Copyright (c) 2023 Antonin Hérault
 
 #![allow(unused)]
+// Each test file includes the shared helpers to still run on its own.
+#![allow(clippy::duplicate_mod)]
 
 //! This file is designed to run the tests easier from the code studio rather 
 //! than opening the test file and click on the "run test" just above the test
 //! function.
 
+// Run this test to check the canvases and their recorded commands.
+mod canvases;
+
+// Run this test to check the line, bar and pie charts.
+mod charts;
+
+// Run this test to check conversions, parsing and blending of colours.
+mod colours;
+
+// Run this test to check the dialogs and the toasts shown over the view.
+mod dialogs;
+
+// Run this test to check the keyboard focus and keyboard events.
+mod focus;
+
+// Run this test to check hit-testing of the drawables.
+mod hit;
+
+// Run this test to check the icons and the built-in icon set.
+mod icons;
+
+// Run this test to check the lists building only their visible rows.
+mod lists;
+
+// Run this test to check the selects and the menus opened over the view.
+mod menus;
+
+// Run this test to check the vector paths and their flattening.
+mod paths;
+
+// Run this test to check pointer events dispatched to the controllers.
+mod pointer;
+
+// Run this test to check the propagation of events through the controllers.
+mod propagation;
+
 // Run this test to use the project on "SDL2".
 mod sdl2;
 
+// Run this test to check the sliders and the progress indicators.
+mod sliders;
+
+// Run this test to check interaction states and their styles.
+mod states;
+
+// Run this test to check the tables and their grids.
+mod tables;
+
+// Run this test to check the tabs, the navigation bars and their switchers.
+mod tabs;
+
+// Run this test to check editing text with the text fields.
+mod text_field;
+
+// Run this test to check loading and saving themes (requires the `serde` 
+// feature).
+mod theme_files;
+
+// Run this test to check colour schemes and style resolution.
+mod themes;
+
+// Run this test to check the checkboxes, radios and switches.
+mod toggles;
+
+// Run this test to check theme tokens.
+mod tokens;
+
+// Run this test to check transformations of widgets.
+mod transform;
+
+// Run this test to check the tree views and their navigators.
+mod trees;
+
+// Run this test to draw the project on "tui", in a test terminal.
+mod tui;
+
 // Run this test to check unusual usages of the project.
 mod unusual;
 
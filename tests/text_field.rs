@@ -0,0 +1,298 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Editing text with the text fields.
+
+use std::{cell::{Cell, RefCell}, rc::Rc, time::Duration};
+
+use haussmann::{
+    controllers::{
+        keyboard::{self, Key, Modifiers},
+        pointer::{Button, Input},
+        text::Editor,
+    },
+    graphics::draw::{Drawable, Object},
+    text::{Buffer, Clipboard, MemoryClipboard, Motion},
+    themes::{Component, Theme},
+    widgets::{Layout, TextField, View, Widget},
+    widgets,
+    Align,
+    Direction,
+    Overflow,
+    Zone,
+};
+
+thread_local! {
+    static CHANGES: Cell<usize> = const { Cell::new(0) };
+    static SUBMITTED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn changed(_: &mut TextField, _: &Theme) {
+    CHANGES.with(|changes| changes.set(changes.get() + 1));
+}
+
+fn submitted(field: &mut TextField, _: &Theme) {
+    SUBMITTED.with(|submitted| submitted.borrow_mut().push(field.buffer.text().to_string()));
+}
+
+fn view(editor: Editor) -> View {
+    View::new(
+        Zone {
+            position: [0, 0],
+            size: [200, 100],
+        },
+        Layout::normal(Overflow::Ignore, Align::Left, Align::Top, Direction::Column, widgets![editor]),
+    )
+}
+
+fn field(view: &View) -> &TextField {
+    view.layout.widgets::<TextField>()[0]
+}
+
+fn shortcut(character: char) -> keyboard::Event {
+    keyboard::Event::Down {
+        key: Key::Character(character),
+        modifiers: Modifiers {
+            control: true,
+            ..Modifiers::NONE
+        },
+        repeat: false,
+    }
+}
+
+/// Returns the zones of the surfaces drawn for a component.
+fn surfaces(drawables: &[Drawable], component: Component) -> Vec<Zone> {
+    drawables
+        .iter()
+        .filter(|drawable| matches!(&drawable.object, Object::Surface(surface) if surface.component == component))
+        .map(|drawable| drawable.zone)
+        .collect()
+}
+
+#[test]
+fn editing() {
+    let mut buffer = Buffer::new("hello world", false);
+    assert_eq!(buffer.cursor(), 11);
+
+    buffer.move_cursor(Motion::WordLeft, false);
+    assert_eq!(buffer.cursor(), 6);
+    buffer.move_cursor(Motion::WordLeft, true);
+    assert_eq!(buffer.selected_text().as_deref(), Some("hello "));
+
+    // The selection is replaced.
+    assert!(buffer.insert("bye "));
+    assert_eq!(buffer.text(), "bye world");
+    assert_eq!(buffer.selection(), None);
+
+    assert!(buffer.delete(Motion::WordRight));
+    assert_eq!(buffer.text(), "bye ");
+    assert!(buffer.delete_backward());
+    assert!(!buffer.delete_forward());
+    assert_eq!(buffer.text(), "bye");
+
+    buffer.select_word(1);
+    assert_eq!(buffer.selection(), Some(0..3));
+
+    // Single-line buffers have no line break, the positions are characters.
+    buffer.set_text("é\nà");
+    assert_eq!(buffer.text(), "é à");
+    buffer.move_cursor(Motion::Left, false);
+    buffer.delete_backward();
+    assert_eq!(buffer.text(), "éà");
+}
+
+#[test]
+fn undo_redo() {
+    let mut buffer = Buffer::new("", false);
+
+    // Typing is undone at once.
+    for character in ["a", "b", "c"] {
+        buffer.insert(character);
+    }
+    buffer.insert(" pasted");
+    buffer.delete_backward();
+
+    assert_eq!(buffer.text(), "abc paste");
+    assert!(buffer.undo());
+    assert_eq!(buffer.text(), "abc pasted");
+    assert!(buffer.undo());
+    assert_eq!(buffer.text(), "abc");
+    assert!(buffer.undo());
+    assert_eq!(buffer.text(), "");
+    assert!(!buffer.undo());
+
+    assert!(buffer.redo());
+    assert_eq!(buffer.text(), "abc");
+    assert_eq!(buffer.cursor(), 3);
+
+    // Editing forgets the undone edits.
+    buffer.insert("!");
+    assert!(!buffer.can_redo());
+}
+
+#[test]
+fn multi_line() {
+    let mut buffer = Buffer::new("first\nab\nthird", true);
+    assert_eq!(buffer.lines().collect::<Vec<_>>(), ["first", "ab", "third"]);
+    assert_eq!(buffer.line_column(buffer.cursor()), (2, 5));
+
+    // The column is kept in the shorter lines.
+    buffer.move_cursor(Motion::Up, false);
+    assert_eq!(buffer.line_column(buffer.cursor()), (1, 2));
+    buffer.move_cursor(Motion::Up, false);
+    assert_eq!(buffer.line_column(buffer.cursor()), (0, 2));
+
+    buffer.move_cursor(Motion::LineEnd, true);
+    assert_eq!(buffer.selected_text().as_deref(), Some("rst"));
+    buffer.move_cursor(Motion::Down, true);
+    assert_eq!(buffer.selected_text().as_deref(), Some("rst\nab"));
+
+    // Pasted line breaks of any platform.
+    let mut buffer = Buffer::new("", true);
+    buffer.insert("a\r\nb\rc");
+    assert_eq!(buffer.lines().collect::<Vec<_>>(), ["a", "b", "c"]);
+
+    let mut buffer = Buffer::new("one\r\n", false);
+    buffer.insert("two\r\nthree\r");
+    assert_eq!(buffer.text(), "one two three ");
+}
+
+#[test]
+fn composition() {
+    let mut buffer = Buffer::new("ab", false);
+    buffer.set_cursor(1, false);
+
+    buffer.set_composition("にほ", 2);
+    assert_eq!(buffer.text(), "ab");
+    assert_eq!(buffer.composed(), ("aにほb".to_string(), 3));
+
+    assert!(buffer.commit("日本"));
+    assert_eq!(buffer.composition(), None);
+    assert_eq!(buffer.text(), "a日本b");
+    assert_eq!(buffer.cursor(), 3);
+}
+
+#[test]
+fn keyboard_editing() {
+    let clipboard = Rc::new(RefCell::new(MemoryClipboard::default()));
+    let editor = Editor::new(TextField::normal(""), changed)
+        .with_submit(submitted)
+        .with_clipboard(clipboard.clone());
+
+    let mut view = view(editor);
+    let drawables = view.build();
+
+    assert_eq!(view.focus_order(&drawables), [1]);
+    view.set_focus(&drawables, Some(1), true);
+    assert!(field(&view).focused);
+
+    for character in "hi there".chars() {
+        view.key(&drawables, keyboard::Event::Char { character });
+    }
+    assert_eq!(field(&view).buffer.text(), "hi there");
+    assert_eq!(CHANGES.with(Cell::get), 8);
+
+    // Moving the cursor does not change the text.
+    view.key(&drawables, keyboard::Event::Down {
+        key: Key::Left,
+        modifiers: Modifiers {
+            shift: true,
+            control: true,
+            ..Modifiers::NONE
+        },
+        repeat: false,
+    });
+    assert_eq!(CHANGES.with(Cell::get), 8);
+
+    view.key(&drawables, shortcut('x'));
+    assert_eq!(clipboard.borrow_mut().read().as_deref(), Some("there"));
+    view.key(&drawables, keyboard::Event::down(Key::Home));
+    view.key(&drawables, shortcut('v'));
+    assert_eq!(field(&view).buffer.text(), "therehi ");
+
+    view.key(&drawables, shortcut('z'));
+    view.key(&drawables, shortcut('z'));
+    assert_eq!(field(&view).buffer.text(), "hi there");
+    view.key(&drawables, shortcut('y'));
+    assert_eq!(field(&view).buffer.text(), "hi ");
+
+    view.key(&drawables, keyboard::Event::down(Key::Enter));
+    assert_eq!(SUBMITTED.with(|submitted| submitted.borrow().clone()), ["hi "]);
+
+    // Losing the focus hides the caret.
+    view.set_focus(&drawables, None, false);
+    assert!(!field(&view).focused);
+}
+
+#[test]
+fn pointer_placing_the_cursor() {
+    let mut view = view(Editor::new(TextField::normal("hello world"), changed));
+    let drawables = view.build();
+
+    let [top, _, _, left] = field(&view).padding.resolve(&view.theme().tokens.spacing);
+    // Between the characters 2 and 3.
+    let position = [left as isize + 2 * 8 + 5, top as isize + 4];
+
+    view.pointer(&drawables, Input::Down {
+        position,
+        button: Button::Primary,
+        time: Duration::ZERO,
+    });
+
+    assert_eq!(view.focus().focused(), Some(1));
+    assert_eq!(field(&view).buffer.cursor(), 3);
+}
+
+#[test]
+fn built_caret_and_selection() {
+    let mut field = TextField::multi_line("abc\ndefg");
+    field.buffer.select(2..6);
+    field.focused = true;
+
+    let mut view = View::new(
+        Zone {
+            position: [0, 0],
+            size: [200, 100],
+        },
+        Layout::normal(Overflow::Ignore, Align::Left, Align::Top, Direction::Column, widgets![field]),
+    );
+    let drawables = view.build();
+
+    let origin = surfaces(&drawables, Component::Layout)[1].position;
+
+    // One rectangle per selected line, with the selected line break.
+    let selection = surfaces(&drawables, Component::Selection);
+    assert_eq!(selection.len(), 2);
+    assert_eq!(selection[0], Zone {
+        position: [origin[0] + 2 * 8, origin[1]],
+        size: [2 * 8, 16],
+    });
+    assert_eq!(selection[1], Zone {
+        position: [origin[0], origin[1] + 16],
+        size: [2 * 8, 16],
+    });
+
+    // The caret is at the cursor, the end of the selection.
+    let caret = surfaces(&drawables, Component::Caret);
+    assert_eq!(caret, [Zone {
+        position: [origin[0] + 2 * 8, origin[1] + 16],
+        size: [2, 16],
+    }]);
+
+    let lines: Vec<&str> = drawables
+        .iter()
+        .filter_map(|drawable| match &drawable.object {
+            Object::Label(label) if label.component == Component::Input => Some(label.text.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(lines, ["abc", "defg"]);
+
+    // The placeholder is shown without text.
+    view.layout.widgets_mut::<TextField>()[0].buffer.set_text("");
+    view.layout.widgets_mut::<TextField>()[0].placeholder = "name".to_string();
+    assert!(view.build().iter().any(|drawable| {
+        matches!(&drawable.object, Object::Label(label) if label.component == Component::Placeholder)
+    }));
+}
@@ -0,0 +1,104 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Loading and saving themes from/to TOML and JSON (requires the `serde`
+//! feature).
+
+#![cfg(feature = "serde")]
+
+use std::collections::HashMap;
+
+use haussmann::{
+    graphics::colours,
+    themes::{self, Format, LoadError, Theme},
+    Border,
+    Font,
+    FontFamily,
+    FontWeight,
+    Side,
+};
+
+fn theme() -> Theme {
+    let mut theme = themes::default(vec![FontFamily {
+        name: "Roboto".to_string(),
+        fonts: HashMap::from([(
+            FontWeight::Regular,
+            Font {
+                name: "Roboto Regular".to_string(),
+                path: "fonts/Roboto-Regular.ttf".to_string(),
+            },
+        )]),
+    }]);
+
+    theme.style.borders = Some([
+        None,
+        Some(Border::new(2, colours::RED, Side::Bottom)),
+        None,
+        None,
+    ]);
+
+    theme
+}
+
+fn assert_same(a: &Theme, b: &Theme) {
+    assert_eq!(a.fonts.len(), b.fonts.len());
+    assert_eq!(a.fonts[0].name, b.fonts[0].name);
+    assert_eq!(a.fonts[0].fonts, b.fonts[0].fonts);
+    assert_eq!(a.text_theme, b.text_theme);
+    assert_eq!(a.label_style, b.label_style);
+    assert_eq!(a.colour_scheme, b.colour_scheme);
+    assert_eq!(a.style.colour, b.style.colour);
+    assert_eq!(a.style.radius, b.style.radius);
+}
+
+#[test]
+fn round_trip() {
+    let theme = theme();
+
+    let toml = Theme::from_toml(&theme.to_toml().unwrap()).unwrap();
+    assert_same(&theme, &toml);
+
+    let json = Theme::from_json(&theme.to_json().unwrap()).unwrap();
+    assert_same(&theme, &json);
+
+    // Borders are written as a list of the defined borders.
+    let borders = json.style.borders.unwrap();
+    assert_eq!(borders[0], Some(Border::new(2, colours::RED, Side::Bottom)));
+    assert_eq!(borders[1..], [None, None, None]);
+}
+
+#[test]
+fn colours_as_strings() {
+    let toml = theme().to_toml().unwrap();
+    assert!(toml.contains(&format!("\"{}\"", theme().colour_scheme.primary)));
+
+    // Any colour string can be used.
+    let toml = toml.replacen(&format!("\"{}\"", theme().colour_scheme.primary), "\"hsl(0, 100%, 50%)\"", 1);
+    assert_eq!(Theme::from_toml(&toml).unwrap().colour_scheme.primary, colours::RED);
+}
+
+#[test]
+fn errors() {
+    let toml = theme().to_toml().unwrap();
+
+    // Wrong colour.
+    let wrong_colour = toml.replacen(&format!("\"{}\"", theme().colour_scheme.primary), "\"#12\"", 1);
+    match Theme::from_toml(&wrong_colour) {
+        Err(LoadError::Toml(error)) => assert!(error.to_string().contains("invalid hexadecimal colour `#12`")),
+        result => panic!("unexpected result {:?}", result.map(|_| ())),
+    }
+
+    // Invalid size.
+    let mut theme = theme();
+    theme.text_theme.heading2.size = -4;
+    let json = theme.to_json().unwrap();
+
+    match Theme::parse(&json, Format::Json) {
+        Err(LoadError::Invalid { field, .. }) => assert_eq!(field, "text_theme.heading2.size"),
+        result => panic!("unexpected result {:?}", result.map(|_| ())),
+    }
+
+    // Unknown file format.
+    assert!(matches!(Theme::load("theme.yaml"), Err(LoadError::UnknownFormat(_))));
+}
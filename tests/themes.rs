@@ -0,0 +1,207 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Colour schemes and style resolution from the global theme.
+
+use haussmann::{
+    controllers::tap,
+    graphics::{colours::{self, RGBA}, draw},
+    themes::{self, ButtonTheme, ColourScheme, Component, Mode, State, SurfaceTheme},
+    widgets::{Button, Label, Layout, Surface, View, Widget},
+    widgets,
+    Align,
+    Direction,
+    Overflow,
+    Radius,
+    Zone,
+};
+
+#[test]
+fn scheme_from_seed() {
+    let seed = RGBA::new(0, 120, 200, 255);
+
+    for mode in [Mode::Light, Mode::Dark, Mode::HighContrast] {
+        let scheme = ColourScheme::new(seed, mode);
+
+        assert_eq!(scheme.seed, seed);
+        assert!(scheme.on_primary.is_readable_on(scheme.primary));
+        assert!(scheme.on_surface.is_readable_on(scheme.surface));
+        assert!(scheme.on_background.is_readable_on(scheme.background));
+    }
+
+    let light = ColourScheme::light(seed);
+    let dark = ColourScheme::dark(seed);
+
+    assert!(light.background.luminance() > dark.background.luminance());
+    assert_ne!(light.primary, dark.primary);
+}
+
+#[test]
+fn grey_seed() {
+    // A grey has no hue, its schemes stay grey instead of taking the red of
+    // the hue 0, except the error colours.
+    let seed = RGBA::new(128, 128, 128, 255);
+    let grey = |colour: RGBA| colour.r == colour.g && colour.g == colour.b;
+
+    for mode in [Mode::Light, Mode::Dark, Mode::HighContrast] {
+        let scheme = ColourScheme::new(seed, mode);
+
+        assert!(grey(scheme.primary) && grey(scheme.primary_container));
+        assert!(grey(scheme.secondary) && grey(scheme.surface));
+        assert!(!grey(scheme.error));
+        assert!(scheme.on_primary.is_readable_on(scheme.primary));
+    }
+}
+
+#[test]
+fn button_role_colours() {
+    let theme = themes::seeded(vec![], colours::RED);
+
+    let view = View::themed(
+        Zone {
+            position: [0, 0],
+            size: [100, 100],
+        },
+        Layout::normal(
+            Overflow::Ignore,
+            Align::Center,
+            Align::Center,
+            Direction::Column,
+            widgets![Button::normal(Label::normal("button"))],
+        ),
+        theme.clone(),
+    );
+
+    let drawables = view.build();
+
+    // The view's layout, the button's surface and its label.
+    match (&drawables[0].object, &drawables[1].object, &drawables[2].object) {
+        (draw::Object::Surface(layout), draw::Object::Surface(button), draw::Object::Label(label)) => {
+            assert_eq!(layout.style(&theme).colour, Some(colours::TRANSPARENT));
+            assert_eq!(button.style(&theme).colour, Some(theme.colour_scheme.primary));
+            assert_eq!(label.component, Component::Button);
+            assert_eq!(label.label_style(&theme).colour, theme.colour_scheme.on_primary);
+        }
+        objects => panic!("unexpected drawn objects {:?}", objects),
+    }
+
+    // Resolved with the theme of the view.
+    assert_eq!(drawables[1].style.as_ref().unwrap().colour, Some(theme.colour_scheme.primary));
+    assert_eq!(drawables[2].label_style.as_ref().unwrap().colour, theme.colour_scheme.on_primary);
+}
+
+#[test]
+fn switch_mode() {
+    let mut view = View::themed(
+        Zone {
+            position: [0, 0],
+            size: [100, 100],
+        },
+        Layout::normal(
+            Overflow::Ignore,
+            Align::Center,
+            Align::Center,
+            Direction::Column,
+            widgets![Surface::normal(), Label::normal("label")],
+        ),
+        themes::default(vec![]),
+    );
+
+    let light = view.build();
+    let dark = view.set_theme_mode(Mode::Dark);
+
+    assert_eq!(view.theme().mode(), Mode::Dark);
+    assert_eq!(light[1].style.as_ref().unwrap().colour, Some(ColourScheme::light(themes::DEFAULT_SEED).surface));
+    assert_eq!(dark[1].style.as_ref().unwrap().colour, Some(ColourScheme::dark(themes::DEFAULT_SEED).surface));
+    assert_eq!(dark[2].label_style.as_ref().unwrap().colour, view.theme().colour_scheme.on_background);
+
+    // The overridden fields of the component themes and colours are kept.
+    let mut theme = themes::default(vec![]);
+    theme.button_theme.styles.normal.colour = Some(colours::BLUE);
+    theme.label_style.colour = colours::GREEN;
+    theme.set_mode(Mode::Dark);
+
+    assert_eq!(theme.button_theme.styles.normal.colour, Some(colours::BLUE));
+    assert_eq!(theme.label_style.colour, colours::GREEN);
+    assert_eq!(theme.style.colour, Some(theme.colour_scheme.surface));
+    assert_eq!(theme.surface_theme, SurfaceTheme::from_scheme(&theme.colour_scheme));
+
+    // Replaces the whole theme.
+    let drawables = view.set_theme(themes::seeded(vec![], colours::GREEN));
+    assert_eq!(view.theme().colour_scheme.seed, colours::GREEN);
+    assert_eq!(drawables[1].style.as_ref().unwrap().colour, Some(view.theme().colour_scheme.surface));
+}
+
+#[test]
+fn switch_mode_overridden_field() {
+    // Only the radius of the buttons is overridden, their colours still
+    // follow the mode.
+    let mut theme = themes::default(vec![]);
+    theme.button_theme.styles.normal.radius = Some(Radius::new(8.0));
+    theme.set_mode(Mode::Dark);
+
+    let mut dark = ButtonTheme::from_scheme(&theme.colour_scheme);
+    dark.styles.normal.radius = Some(Radius::new(8.0));
+    assert_eq!(theme.button_theme, dark);
+
+    // The fields overridden in a state style are kept too.
+    theme.button_theme.styles.hovered.as_mut().unwrap().colour = Some(colours::RED);
+    theme.set_mode(Mode::Light);
+
+    let hovered = theme.button_theme.styles.hovered.as_ref().unwrap();
+    assert_eq!(hovered.colour, Some(colours::RED));
+    assert_eq!(theme.button_theme.styles.normal.colour, Some(theme.colour_scheme.primary));
+    assert_eq!(theme.button_theme.label_style.colour, theme.colour_scheme.on_primary);
+    assert_eq!(theme.button_theme.styles.normal.radius, Some(Radius::new(8.0)));
+}
+
+#[test]
+fn themed_controllers() {
+    let mut view = View::themed(
+        Zone {
+            position: [0, 0],
+            size: [100, 100],
+        },
+        Layout::normal(
+            Overflow::Ignore,
+            Align::Center,
+            Align::Center,
+            Direction::Column,
+            widgets![tap::Detector::new(Button::normal(Label::normal("tap")), |button, theme| {
+                button.style_mut(theme).colour = Some(theme.colour_scheme.primary);
+            })],
+        ),
+        themes::default(vec![]),
+    );
+
+    // The controllers are tapped with the theme of the view.
+    let drawables = view.set_theme_mode(Mode::Dark);
+    view.themed_controllers::<tap::Detector<Button>>(drawables, |controller, theme| controller.on_tap(theme));
+
+    let primary = view.theme().colour_scheme.primary;
+    assert_eq!(view.build()[1].style.as_ref().unwrap().colour, Some(primary));
+    assert_ne!(primary, ColourScheme::light(themes::DEFAULT_SEED).primary);
+}
+
+#[test]
+fn component_themes() {
+    let mut theme = themes::default(vec![]);
+    theme.button_theme.styles.normal.colour = Some(colours::BLUE);
+    theme.button_theme.label_style.colour = colours::WHITE;
+    theme.style.radius = Some(Radius::new(4.0));
+
+    // Undefined properties come from the fallback style.
+    let style = theme.component_style(Component::Button);
+    assert_eq!(style.colour, Some(colours::BLUE));
+    assert_eq!(style.radius, Some(Radius::new(4.0)));
+    assert_eq!(theme.component_label_style(Component::Button).colour, colours::WHITE);
+
+    // States without their own style are the normal style.
+    theme.button_theme.styles.hovered = None;
+    assert_eq!(theme.component_style_in(Component::Button, State::Hovered), style);
+    assert_ne!(theme.component_style_in(Component::Button, State::Pressed), style);
+
+    // Layouts stay transparent.
+    assert_eq!(theme.component_style(Component::Layout).colour, Some(colours::TRANSPARENT));
+}
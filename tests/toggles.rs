@@ -0,0 +1,157 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Checkboxes, radios and switches toggled by their controllers.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use haussmann::{
+    controllers::{
+        keyboard::{self, Key},
+        toggle::{Group, Toggle, Toggler},
+    },
+    graphics::draw::Object,
+    themes::{self, Component},
+    widgets::{Check, Checkbox, Label, Layout, Radio, Switch, View, Widget},
+    widgets,
+    Align,
+    Direction,
+    Overflow,
+};
+
+use common::{centre, click, log, logged, view};
+
+fn layout(direction: Direction, widgets: Vec<Box<dyn Widget>>) -> Layout {
+    Layout::normal(Overflow::Ignore, Align::Center, Align::Center, direction, widgets)
+}
+
+#[test]
+fn tri_state_checkbox() {
+    let mut checkbox = Checkbox::normal(Check::Indeterminate);
+
+    for expected in [Check::Checked, Check::Unchecked, Check::Checked] {
+        checkbox.toggle();
+        assert_eq!(checkbox.value, expected);
+    }
+
+    let mut switch = Switch::normal(false);
+    switch.toggle();
+    assert!(switch.on);
+
+    // Toggling a radio only selects it.
+    let mut radio = Radio::normal(false);
+    assert!(radio.toggle());
+    assert!(!radio.toggle());
+    assert!(radio.selected);
+}
+
+#[test]
+fn toggled_by_controllers() {
+    let mut view = view([300, 100], Direction::Column, widgets![
+        Toggler::new(Checkbox::normal(Check::Unchecked), |checkbox, _| {
+            log(format!("checkbox {:?}", checkbox.value))
+        }),
+        Toggler::new(Switch::normal(false), |switch, _| log(format!("switch {}", switch.on))),
+    ]);
+    let drawables = view.build();
+
+    click(&mut view, &drawables, centre(&drawables, 1));
+    click(&mut view, &drawables, centre(&drawables, 2));
+    assert_eq!(logged(), ["checkbox Checked", "switch true"]);
+
+    // Space toggles the focused widget, enter does not.
+    view.set_focus(&drawables, Some(1), true);
+    view.key(&drawables, keyboard::Event::down(Key::Enter));
+    view.key(&drawables, keyboard::Event::down(Key::Space));
+    assert_eq!(logged(), ["checkbox Unchecked"]);
+}
+
+#[test]
+fn unchanged_radio() {
+    let mut view = view([300, 100], Direction::Column, widgets![
+        Toggler::new(Radio::normal(true), |radio, _| log(format!("radio {}", radio.selected))),
+        Label::normal("radio"),
+    ]);
+    let drawables = view.build();
+
+    // Nothing changed when a selected radio is clicked.
+    click(&mut view, &drawables, centre(&drawables, 1));
+    assert!(logged().is_empty());
+}
+
+#[test]
+fn exclusive_radios() {
+    let row = |label: &str| layout(Direction::Column, widgets![
+        Toggler::new(Radio::normal(false), |_, _| {}),
+        Label::normal(label),
+    ]);
+
+    let mut view = view([300, 100], Direction::Row, widgets![
+        Group::new(
+            layout(Direction::Row, widgets![
+                Toggler::new(Radio::normal(true), |_, _| {}),
+                row("b"),
+                row("c"),
+            ]),
+            |_, selected, _| log(format!("selected {}", selected)),
+        ),
+    ]);
+    let drawables = view.build();
+
+    // The group is 1, the radios are 2, 4 and 7.
+    click(&mut view, &drawables, centre(&drawables, 7));
+    assert_eq!(logged(), ["selected 2"]);
+
+    // The radios of the rows, in the layout of the group.
+    let selected = |view: &View| -> Vec<bool> {
+        let group = &view.layout.widgets::<Layout>()[0];
+        let mut selected = vec![group.widgets::<Radio>()[0].selected];
+
+        for row in group.widgets::<Layout>() {
+            selected.push(row.widgets::<Radio>()[0].selected);
+        }
+
+        selected
+    };
+    assert_eq!(selected(&view), [false, false, true]);
+
+    // Selecting the selected radio changes nothing.
+    click(&mut view, &drawables, centre(&drawables, 7));
+    assert!(logged().is_empty());
+
+    view.set_focus(&drawables, Some(2), true);
+    view.key(&drawables, keyboard::Event::down(Key::Space));
+    assert_eq!(logged(), ["selected 0"]);
+    assert_eq!(selected(&view), [true, false, false]);
+}
+
+#[test]
+fn themed_toggles() {
+    let theme = themes::default(vec![]);
+    let mut view = view([300, 100], Direction::Column, widgets![
+        Checkbox::normal(Check::Checked),
+        Switch::normal(false),
+    ]);
+    let drawables = view.build();
+
+    // The checked box is coloured with the primary colour, its mark is
+    // readable over it.
+    assert_eq!(drawables[1].style.as_ref().unwrap().colour, Some(theme.colour_scheme.primary));
+    match &drawables[2].object {
+        Object::Label(label) => assert_eq!(label.text, "✓"),
+        object => panic!("the mark should be a label, found {:?}", object),
+    }
+    assert_eq!(drawables[2].label_style.as_ref().unwrap().colour, theme.toggle_theme.mark_colour);
+
+    // The thumb of the switch is on its left side.
+    assert_eq!(drawables[3].style.as_ref().unwrap().colour, Some(theme.colour_scheme.surface_variant));
+    let thumb = &drawables[4];
+    assert!(matches!(&thumb.object, Object::Surface(surface) if surface.component == Component::ToggleMark));
+    assert!(thumb.zone.x() < drawables[5].zone.x());
+
+    view.layout.widgets_mut::<Switch>()[0].on = true;
+    let drawables = view.build();
+    assert!(matches!(&drawables[5].object, Object::Surface(surface) if surface.component == Component::ToggleMark));
+}
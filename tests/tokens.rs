@@ -0,0 +1,130 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Theme tokens referenced by styles, paddings, gaps and borders.
+
+use haussmann::{
+    graphics::colours,
+    themes::{self, Elevation, Scale, Spacing, Style, StyleTokens},
+    widgets::{Layout, Surface, View, Widget},
+    widgets,
+    Align,
+    Border,
+    Direction,
+    Overflow,
+    Padding,
+    Radius,
+    Side,
+    Zone,
+};
+
+fn view(layout: Layout) -> View {
+    View::new(
+        Zone {
+            position: [0, 0],
+            size: [100, 100],
+        },
+        layout,
+    )
+}
+
+#[test]
+fn padding_and_gap() {
+    let mut view = view(
+        Layout::normal(
+            Overflow::Ignore,
+            Align::Left,
+            Align::Top,
+            Direction::Column,
+            widgets![Surface::normal(), Surface::normal()],
+        )
+        .with_padding(Padding::all(Scale::Small))
+        .with_gap(4),
+    );
+
+    let drawables = view.build();
+
+    // The layout keeps the whole zone.
+    assert_eq!(drawables[0].zone.size, [100, 100]);
+    // (100 - 2 * 8 - 4) / 2
+    assert_eq!(drawables[1].zone.position, [8, 8]);
+    assert_eq!(drawables[1].zone.size, [40, 84]);
+    assert_eq!(drawables[2].zone.position, [52, 8]);
+
+    // Changing the token moves the widgets.
+    let mut theme = view.theme().clone();
+    theme.tokens.spacing.small = 10;
+    let drawables = view.set_theme(theme);
+
+    assert_eq!(drawables[1].zone.position, [10, 10]);
+    assert_eq!(drawables[1].zone.size, [38, 80]);
+    assert_eq!(drawables[2].zone.position, [52, 10]);
+}
+
+#[test]
+fn style_tokens() {
+    let style = Style {
+        borders: Some([Some(Border::new(Scale::ExtraSmall, colours::BLACK, Side::Top)), None, None, None]),
+        tokens: StyleTokens {
+            colour: Some("brand".to_string()),
+            radius: Some(Scale::Medium),
+            elevation: None,
+        },
+        ..Style::default()
+    };
+
+    let mut theme = themes::default(vec![]);
+    theme.tokens.colours.insert("brand".to_string(), colours::RED);
+
+    let resolved = theme.resolve_style(&style);
+    assert_eq!(resolved.colour, Some(colours::RED));
+    assert_eq!(resolved.radius, Some(Radius::new(12.0)));
+    assert_eq!(resolved.borders.unwrap()[0].as_ref().unwrap().width, Spacing::Pixels(4));
+
+    // Borders not resolved yet follow the spacing scale of the theme.
+    theme.tokens.spacing.extra_small = 6;
+    let border = style.borders.as_ref().unwrap()[0].as_ref().unwrap();
+    let zone = border.as_zone(&([0, 0], [100, 50]).into(), &theme.tokens.spacing);
+    assert_eq!(zone.size, [100, 6]);
+    assert_eq!(resolved.tokens, StyleTokens::default());
+
+    // Roles of the colour scheme are named colours too.
+    let primary = Style {
+        tokens: StyleTokens {
+            colour: Some("primary".to_string()),
+            ..StyleTokens::default()
+        },
+        ..Style::default()
+    };
+    assert_eq!(theme.resolve_style(&primary).colour, Some(theme.colour_scheme.primary));
+
+    // Elevated colours are tinted with the primary colour.
+    let elevated = Style {
+        colour: Some(colours::WHITE),
+        tokens: StyleTokens {
+            elevation: Some(Elevation::Level3),
+            ..StyleTokens::default()
+        },
+        ..Style::default()
+    };
+    assert_ne!(theme.resolve_style(&elevated).colour, Some(colours::WHITE));
+
+    // Resolved when building.
+    let mut view = view(Layout::normal(
+        Overflow::Ignore,
+        Align::Center,
+        Align::Center,
+        Direction::Column,
+        widgets![Surface::styled(Some(style))],
+    ));
+
+    let mut theme = themes::default(vec![]);
+    theme.tokens.colours.insert("brand".to_string(), colours::GREEN);
+    theme.tokens.radius.medium = 6.0;
+
+    let drawables = view.set_theme(theme);
+    let style = drawables[1].style.as_ref().unwrap();
+    assert_eq!(style.colour, Some(colours::GREEN));
+    assert_eq!(style.radius, Some(Radius::new(6.0)));
+}
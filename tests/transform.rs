@@ -0,0 +1,184 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Transformations of widgets and hit-testing on transformed zones.
+
+use haussmann::{
+    controllers::tap,
+    graphics::{draw::Object, Matrix, Transform},
+    widgets::{Column, Label, Layout, Stack, Surface, Table, Transformed, View, Widget},
+    widgets,
+    Align,
+    Direction,
+    Overflow,
+    Zone,
+};
+
+#[test]
+fn inverse() {
+    let matrix = Matrix::translation(10.0, 5.0) * Matrix::rotation(30.0) * Matrix::scaling(2.0, 3.0);
+    let point = matrix.inverse().unwrap().apply(matrix.apply([7.0, -4.0]));
+
+    assert!((point[0] - 7.0).abs() < 1e-4);
+    assert!((point[1] + 4.0).abs() < 1e-4);
+
+    assert_eq!(Matrix::scaling(0.0, 1.0).inverse(), None);
+}
+
+#[test]
+fn tapped_scaled_zone() {
+    let zone = Zone {
+        position: [10, 10],
+        size: [10, 10],
+    };
+    let matrix = Transform::scaled(2.0, 2.0).matrix(zone);
+
+    // Outside the zone, but inside the scaled zone.
+    assert!(tap::is_tapped([6, 6], zone, matrix));
+    assert!(!tap::is_tapped([6, 6], zone, Matrix::identity()));
+    // Outside the scaled zone.
+    assert!(!tap::is_tapped([30, 30], zone, matrix));
+}
+
+#[test]
+fn composed_down_the_tree() {
+    let view = View::new(
+        Zone {
+            position: [0, 0],
+            size: [100, 100],
+        },
+        Layout::normal(
+            Overflow::Ignore,
+            Align::Center,
+            Align::Center,
+            Direction::Column,
+            widgets![
+                Transformed::new(
+                    Transform::translated(5.0, 0.0),
+                    Layout::normal(
+                        Overflow::Ignore,
+                        Align::Center,
+                        Align::Center,
+                        Direction::Column,
+                        widgets![
+                            Transformed::new(
+                                Transform::translated(0.0, 7.0),
+                                Surface::normal(),
+                            ),
+                        ],
+                    ),
+                ),
+            ],
+        ),
+    );
+
+    let drawables = view.build();
+
+    // The view's layout, the transformed layout and its surface.
+    assert_eq!(drawables.len(), 3);
+    assert!(drawables[0].transform.is_identity());
+    assert_eq!(drawables[1].transform, Matrix::translation(5.0, 0.0));
+    assert_eq!(drawables[2].transform.apply([0.0, 0.0]), [5.0, 7.0]);
+}
+
+#[test]
+fn wrapped_widgets() {
+    let layout = |widgets| Layout::normal(
+        Overflow::Ignore,
+        Align::Center,
+        Align::Center,
+        Direction::Column,
+        widgets,
+    );
+    let moved = Transform::translated(5.0, 0.0);
+
+    let mut view = View::new(
+        Zone {
+            position: [0, 0],
+            size: [100, 100],
+        },
+        layout(widgets![
+            Transformed::new(moved, Transformed::new(moved, layout(widgets![
+                tap::Detector::new(Surface::normal(), |_, _| {}),
+                Label::normal("inner"),
+            ]))),
+            Transformed::new(moved, Stack::new().filled(Label::normal("stacked"))),
+            tap::Detector::new(Surface::normal(), |_, _| {}),
+        ]),
+    );
+
+    let drawables = view.build();
+
+    // The wrapped layout and stack are built like they would be without 
+    // their transformations.
+    let labels: Vec<(i32, Matrix)> = drawables
+        .iter()
+        .filter(|drawable| matches!(drawable.object, Object::Label(_)))
+        .map(|drawable| (drawable.group_id, drawable.transform))
+        .collect();
+
+    assert_eq!(labels, vec![
+        (3, Matrix::translation(10.0, 0.0)),
+        (4, Matrix::translation(5.0, 0.0)),
+    ]);
+
+    // The controllers in the wrapped layout are found with their identifiers.
+    assert_eq!(view.focus_order(&drawables), vec![2, 5]);
+}
+
+#[test]
+fn controlled_transformed_widgets() {
+    let layout = |widgets| Layout::normal(
+        Overflow::Ignore,
+        Align::Center,
+        Align::Center,
+        Direction::Column,
+        widgets,
+    );
+    let moved = Transform::translated(5.0, 0.0);
+    let table = Table::new(vec![Column::flex("a", 1.0), Column::flex("b", 1.0)], 1, |_, column| {
+        Label::normal(&format!("cell {}", column)).into()
+    });
+
+    let mut view = View::new(
+        Zone {
+            position: [0, 0],
+            size: [200, 100],
+        },
+        layout(widgets![
+            tap::Detector::new(Transformed::new(moved, layout(widgets![
+                tap::Detector::new(Surface::normal(), |_, _| {}),
+                Label::normal("inner"),
+            ])), |_, _| {}),
+            tap::Detector::new(Transformed::new(moved, table), |_, _| {}),
+            tap::Detector::new(Surface::normal(), |_, _| {}),
+        ]),
+    );
+
+    let drawables = view.build();
+
+    // The transformed layout and table are built like they would be without
+    // their transformations: the layout of the controller and its widgets, 
+    // then the table of the next controller and its headers and cells.
+    let labels: Vec<(String, i32)> = drawables
+        .iter()
+        .filter_map(|drawable| match &drawable.object {
+            Object::Label(label) => Some((label.text.clone(), drawable.group_id)),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(labels, vec![
+        ("inner".to_string(), 3),
+        ("a".to_string(), 5),
+        ("b".to_string(), 6),
+        ("cell 0".to_string(), 7),
+        ("cell 1".to_string(), 8),
+    ]);
+
+    // The walk through the controllers takes the same identifiers, so the 
+    // controller after the table has the identifier of its drawable.
+    assert_eq!(view.focus_order(&drawables), vec![1, 2, 4, 9]);
+    assert!(drawables.iter().any(|drawable| drawable.group_id == 9));
+}
@@ -0,0 +1,226 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Tree views expanded, loaded and selected by their navigator.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::collections::HashMap;
+
+use haussmann::{
+    controllers::{
+        keyboard::{self, Key},
+        tap,
+        tree::Navigator,
+    },
+    graphics::draw::{Drawable, Object},
+    widgets::{self, Label, TreeNode, TreeView, View, Widget},
+    Direction,
+};
+
+use common::{click_at, logged_in, view, Log};
+
+fn tree() -> TreeView {
+    TreeView::new(vec![
+        TreeNode::branch("src", vec![TreeNode::leaf("lib.rs"), TreeNode::lazy("widgets")]),
+        TreeNode::leaf("Cargo.toml"),
+    ])
+}
+
+/// A view of 200x200 with a tree over a button, with the log of its 
+/// navigator. The children of the lazy nodes are loaded from the files of 
+/// their directory.
+fn tree_view() -> (View, Log) {
+    let files = HashMap::from([("widgets", vec!["mod.rs", "tree_view.rs"])]);
+    let log = Log::default();
+    let (loads, selections) = (log.clone(), log.clone());
+
+    let navigator = Navigator::new(
+        tree(),
+        move |node, path| {
+            loads.borrow_mut().push(format!("load {} {:?}", node.label, path));
+            files[node.label.as_str()].iter().map(|file| TreeNode::leaf(file)).collect()
+        },
+        move |tree, _| selections.borrow_mut().push(format!("select {:?}", tree.selected)),
+    );
+
+    let view = view([200, 200], Direction::Row, haussmann::widgets![
+        navigator,
+        tap::Detector::new(widgets::Button::normal(Label::normal("tap")), |_, _| ()),
+    ]);
+
+    (view, log)
+}
+
+fn tree_of(view: &View) -> &TreeView {
+    view.layout.widgets[0].as_any().downcast_ref().unwrap()
+}
+
+/// Returns the texts of the built rows, with their drawables, without the 
+/// button under the tree.
+fn labels(drawables: &[Drawable]) -> Vec<(String, &Drawable)> {
+    drawables
+        .iter()
+        .filter_map(|drawable| match &drawable.object {
+            Object::Label(label) if label.text != "tap" => Some((label.text.clone(), drawable)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn rows() {
+    let mut tree = tree();
+    assert_eq!(tree.rows(), [vec![0], vec![1]]);
+
+    tree.roots[0].expanded = true;
+    assert_eq!(tree.rows(), [vec![0], vec![0, 0], vec![0, 1], vec![1]]);
+    assert_eq!(tree.node(&[0, 1]).unwrap().label, "widgets");
+    assert_eq!(tree.node(&[1, 0]), None);
+    assert_eq!(tree.row_at(50), Some(2));
+    assert_eq!(tree.row_at(100), None);
+
+    // Not loaded yet, the lazy node may have children.
+    assert!(tree.node(&[0, 1]).unwrap().has_children());
+    assert!(!tree.node(&[0, 0]).unwrap().has_children());
+
+    assert!(tree.row_layout(&[0, 1]).is_some());
+    assert!(tree.row_layout(&[0, 2]).is_none());
+}
+
+#[test]
+fn nested_layouts() {
+    let (mut view, _) = tree_view();
+    let navigator: &mut Navigator = view.layout.widgets[0].as_any_mut().downcast_mut().unwrap();
+    navigator.widget.roots[0].expanded = true;
+    let drawables = view.build();
+
+    // The rows of the children are in the layout of their parent, under its
+    // row.
+    let rows: Vec<&Drawable> = labels(&drawables)
+        .into_iter()
+        .filter(|(text, _)| ["src", "lib.rs", "Cargo.toml"].contains(&text.as_str()))
+        .map(|(_, drawable)| drawable)
+        .collect();
+    let parent = |drawable: &Drawable| drawables[drawable.parent.unwrap()].parent.unwrap();
+
+    assert_eq!(rows.iter().map(|row| row.zone.y()).collect::<Vec<_>>(), [0, 24, 72]);
+    assert_eq!(parent(rows[0]), drawables[parent(rows[1])].parent.unwrap());
+    assert_eq!(drawables[parent(rows[1])].zone, ([0, 24], [200, 48]).into());
+    assert_eq!(drawables[parent(rows[0])].parent, Some(parent(rows[2])));
+
+    // Each row takes the identifiers following the previous one, the rows 
+    // being controllers reached by the walk through the controllers.
+    let ids: Vec<i32> = rows.iter().map(|row| drawables[row.parent.unwrap()].group_id).collect();
+    assert!(ids.windows(2).all(|ids| ids[0] > 1 && ids[0] < ids[1]));
+
+    // The button takes the identifier following the ones of the rows.
+    let tap = 1 + tree_of(&view).id_count();
+    assert_eq!(common::texts(&drawables, tap), ["tap"]);
+    assert_eq!(view.focus_order(&drawables), [1, tap]);
+
+    // A selected path without node is not navigated.
+    let navigator: &mut Navigator = view.layout.widgets[0].as_any_mut().downcast_mut().unwrap();
+    navigator.widget.selected = Some(vec![5]);
+    view.set_focus(&drawables, Some(1), true);
+    view.key(&drawables, keyboard::Event::down(Key::Down));
+    assert_eq!(tree_of(&view).selected, Some(vec![5]));
+}
+
+#[test]
+fn deep_rows() {
+    let mut node = TreeNode::leaf("9");
+    for depth in (0..9).rev() {
+        node = TreeNode::branch(&depth.to_string(), vec![node]);
+        node.expanded = true;
+    }
+
+    let mut tree = TreeView::new(vec![node]);
+    tree.row_height = 19;
+
+    let log = Log::default();
+    let selections = log.clone();
+    let navigator = Navigator::new(
+        tree,
+        |_, _| vec![],
+        move |tree, _| selections.borrow_mut().push(format!("select {:?}", tree.selected)),
+    );
+    let mut view = view([200, 200], Direction::Row, haussmann::widgets![navigator]);
+    let drawables = view.build();
+
+    // Each row is as high as the row height, however deep it is.
+    let rows: Vec<&Drawable> = labels(&drawables)
+        .into_iter()
+        .filter(|(text, _)| text.parse::<usize>().is_ok())
+        .map(|(_, drawable)| &drawables[drawable.parent.unwrap()])
+        .collect();
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(row.zone, ([0, 19 * i as isize], [200, 19]).into());
+    }
+
+    // So a click on the deepest row selects it.
+    click_at(&mut view, &drawables, [190, 19 * 9 + 10], 0);
+    assert_eq!(logged_in(&log), [format!("select {:?}", Some(vec![0; 10]))]);
+}
+
+#[test]
+fn loaded_by_toggles() {
+    let (mut view, log) = tree_view();
+    let drawables = view.build();
+
+    click_at(&mut view, &drawables, [8, 10], 0);
+    let drawables = view.build();
+    click_at(&mut view, &drawables, [20, 60], 1);
+    assert_eq!(logged_in(&log), ["load widgets [0, 1]"]);
+
+    let drawables = view.build();
+    let texts: Vec<String> = labels(&drawables).into_iter().map(|(text, _)| text).collect();
+    assert_eq!(texts, [
+        "▾", "src", "", "lib.rs", "▾", "widgets", "", "mod.rs", "", "tree_view.rs", "", "Cargo.toml",
+    ]);
+
+    // Collapsed then expanded again without loading.
+    click_at(&mut view, &drawables, [20, 60], 2);
+    click_at(&mut view, &drawables, [20, 60], 3);
+    assert!(logged_in(&log).is_empty());
+    assert_eq!(tree_of(&view).rows().len(), 6);
+
+    // The rows are indented following their depth.
+    let (_, toggle) = labels(&drawables)[8];
+    assert_eq!(toggle.zone, ([32, 100], [16, 16]).into());
+    // Hidden out of its row and out of the tree.
+    assert_eq!(toggle.clip, Some(([0, 96], [200, 4]).into()));
+}
+
+#[test]
+fn selected() {
+    let (mut view, log) = tree_view();
+    let drawables = view.build();
+
+    click_at(&mut view, &drawables, [100, 10], 0);
+    assert_eq!(logged_in(&log), ["select Some([0])"]);
+
+    view.key(&drawables, keyboard::Event::down(Key::Right));
+    view.key(&drawables, keyboard::Event::down(Key::Right));
+    view.key(&drawables, keyboard::Event::down(Key::Down));
+    view.key(&drawables, keyboard::Event::down(Key::Right));
+    assert_eq!(logged_in(&log), ["select Some([0, 0])", "select Some([0, 1])", "load widgets [0, 1]"]);
+
+    view.key(&drawables, keyboard::Event::down(Key::End));
+    view.key(&drawables, keyboard::Event::down(Key::Up));
+    view.key(&drawables, keyboard::Event::down(Key::Left));
+    assert_eq!(logged_in(&log), ["select Some([1])", "select Some([0, 1, 1])", "select Some([0, 1])"]);
+
+    // Collapsing a node selects it instead of its hidden children.
+    view.key(&drawables, keyboard::Event::down(Key::Right));
+    click_at(&mut view, &drawables, [8, 10], 5);
+    assert_eq!(logged_in(&log), ["select Some([0, 1, 0])", "select Some([0])"]);
+    assert_eq!(tree_of(&view).rows(), [vec![0], vec![1]]);
+
+    // Double-clicking a row toggles it.
+    click_at(&mut view, &drawables, [100, 10], 10);
+    click_at(&mut view, &drawables, [100, 10], 10);
+    assert!(tree_of(&view).roots[0].expanded);
+}
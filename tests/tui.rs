@@ -0,0 +1,189 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Drawing the drawables on "tui", in a test terminal.
+
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    Terminal,
+};
+
+use haussmann::{
+    graphics::{colours::RGBA, draw::{Drawable, Object}},
+    widgets::{Chart, Layout, Series, View, TOLERANCE},
+    Align,
+    Direction,
+    Overflow,
+    Zone,
+};
+
+/// Size in pixels of a cell of the terminal.
+const CELL: [usize; 2] = [8, 16];
+
+const RED: RGBA = RGBA { r: 200, g: 0, b: 0, a: 255 };
+const BLUE: RGBA = RGBA { r: 0, g: 0, b: 200, a: 255 };
+
+/// Drawables drawn in the cells of a terminal.
+struct Drawables<'a>(&'a [Drawable]);
+
+impl tui::widgets::Widget for Drawables<'_> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        for drawable in self.0 {
+            // A terminal does not draw rotated rectangles, the transformed
+            // zone is approximated by its bounds.
+            let zone = drawable.transform.bounds(drawable.zone);
+
+            match &drawable.object {
+                Object::Surface(_) => {
+                    let colour = drawable.style.as_ref().unwrap().colour.unwrap();
+
+                    // The cells whose centre is in the zone.
+                    for y in area.top()..area.bottom() {
+                        for x in area.left()..area.right() {
+                            if contains(zone, centre(x, y)) {
+                                buffer.get_mut(x, y).set_bg(colour_of(colour));
+                            }
+                        }
+                    }
+                }
+                Object::Label(label) => {
+                    let colour = drawable.label_style.as_ref().unwrap().colour;
+                    let [x, y] = cell([zone.x() as f32, zone.y() as f32]);
+
+                    if x < area.right() && y < area.bottom() {
+                        buffer.set_stringn(
+                            x,
+                            y,
+                            &label.text,
+                            (area.right() - x) as usize,
+                            Style::default().fg(colour_of(colour)),
+                        );
+                    }
+                }
+                Object::Path(path) => {
+                    // The path is drawn as its outline, in the colour of its
+                    // stroke or of its fill.
+                    let colour = match (&path.stroke, path.fill) {
+                        (Some(stroke), _) => stroke.colour,
+                        (None, Some(fill)) => fill,
+                        (None, None) => continue,
+                    };
+
+                    for polyline in path.polylines_in(drawable.zone, TOLERANCE) {
+                        for (from, to) in polyline.lines() {
+                            let from = drawable.transform.apply(from);
+                            let to = drawable.transform.apply(to);
+
+                            // Marks a cell every half cell along the line.
+                            let length = (to[0] - from[0]).hypot(to[1] - from[1]);
+                            let steps = (length / (CELL[0] as f32 / 2.0)).ceil().max(1.0) as usize;
+
+                            for step in 0..=steps {
+                                let t = step as f32 / steps as f32;
+                                let [x, y] = cell([
+                                    from[0] + (to[0] - from[0]) * t,
+                                    from[1] + (to[1] - from[1]) * t,
+                                ]);
+
+                                if x < area.right() && y < area.bottom() {
+                                    buffer.get_mut(x, y).set_symbol("•").set_fg(colour_of(colour));
+                                }
+                            }
+                        }
+                    }
+                }
+                // The other drawables are not drawn in a terminal.
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Returns the colour of a terminal for a colour.
+fn colour_of(colour: RGBA) -> Color {
+    Color::Rgb(colour.r, colour.g, colour.b)
+}
+
+/// Returns the centre in pixels of a cell.
+fn centre(x: u16, y: u16) -> [isize; 2] {
+    [
+        (x as usize * CELL[0] + CELL[0] / 2) as isize,
+        (y as usize * CELL[1] + CELL[1] / 2) as isize,
+    ]
+}
+
+/// Returns the cell of a point in pixels.
+fn cell(point: [f32; 2]) -> [u16; 2] {
+    [
+        (point[0].max(0.0) as usize / CELL[0]) as u16,
+        (point[1].max(0.0) as usize / CELL[1]) as u16,
+    ]
+}
+
+fn contains(zone: Zone, [x, y]: [isize; 2]) -> bool {
+    x >= zone.x() && x < zone.x() + zone.width() as isize
+        && y >= zone.y() && y < zone.y() + zone.height() as isize
+}
+
+/// Draws a chart filling a terminal of `cells` cells, returns the buffer of
+/// the terminal.
+fn draw(cells: [u16; 2], chart: Chart) -> Buffer {
+    let size = [cells[0] as usize * CELL[0], cells[1] as usize * CELL[1]];
+    let view = View::new(([0, 0], size).into(), Layout::normal(
+        Overflow::Hide,
+        Align::Left,
+        Align::Top,
+        Direction::Row,
+        vec![chart.into()],
+    ));
+    let drawables = view.build();
+
+    let mut terminal = Terminal::new(TestBackend::new(cells[0], cells[1])).unwrap();
+    terminal
+        .draw(|frame| frame.render_widget(Drawables(&drawables), frame.size()))
+        .unwrap();
+
+    terminal.backend().buffer().clone()
+}
+
+/// Returns the text of a row of a buffer.
+fn row(buffer: &Buffer, y: u16) -> String {
+    (0..buffer.area.width)
+        .map(|x| buffer.get(x, y).symbol.clone())
+        .collect()
+}
+
+#[test]
+fn chart_on_tui() {
+    // The bars of the charts' tests, in a terminal of 31x9 cells.
+    let buffer = draw([31, 9], Chart::bar(&["a", "b"], vec![
+        Series::new("first", &[10.0, 30.0]).with_colour(RED),
+        Series::new("second", &[20.0, -10.0]).with_colour(BLUE),
+    ]));
+
+    // The legend, then the bars from zero, at 96 pixels.
+    assert!(row(&buffer, 0).starts_with("  first"));
+    assert!(row(&buffer, 0).contains("second"));
+    assert_eq!(buffer.get(0, 0).bg, colour_of(RED));
+    assert!((7..12).all(|x| (4..6).all(|y| buffer.get(x, y).bg == colour_of(RED))));
+    assert!((12..17).all(|x| (3..6).all(|y| buffer.get(x, y).bg == colour_of(BLUE))));
+    assert_ne!(buffer.get(9, 3).bg, colour_of(RED));
+    assert!((25..30).all(|x| buffer.get(x, 6).bg == colour_of(BLUE)));
+
+    // The categories under the plot.
+    assert!(row(&buffer, 7).contains('a') && row(&buffer, 7).contains('b'));
+
+    // The lines go through their values, in the middle of each category.
+    let buffer = draw([44, 9], Chart::line(&["a", "b", "c"], vec![
+        Series::new("first", &[0.0, 10.0, 20.0]).with_colour(RED),
+    ]));
+
+    for [x, y] in [[12, 7], [25, 4], [37, 1]] {
+        assert_eq!(buffer.get(x, y).symbol, "•");
+        assert_eq!(buffer.get(x, y).fg, colour_of(RED));
+    }
+}
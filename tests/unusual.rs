@@ -1,7 +1,8 @@
 //! Any usage of haussmann that might be unusual and that might go into panics
 
 use haussmann::{
-    widgets::{Layout, View, Surface, Widget}, 
+    graphics::{Size, Sizer},
+    widgets::{Container, Layout, View, Surface, Widget}, 
     widgets, 
     Align, 
     Overflow, 
@@ -48,3 +49,34 @@ fn void_view() {
 
     view.build();
 }
+
+#[test]
+fn only_containers() {
+    let layout = Layout::normal(
+        Overflow::Ignore,
+        Align::Left,
+        Align::Top,
+        Direction::Row,
+        widgets![
+            Container::new([20, 10], Surface::normal()),
+            Container::new([30, 10], Surface::normal())
+        ],
+    );
+
+    // The first size is the one of the layout, like with other widgets.
+    let sizes = Sizer::new(&layout).size_in([100, 100]);
+    assert_eq!(sizes, vec![Size::from([100, 100]), Size::from([20, 10]), Size::from([30, 10])]);
+
+    let view = View::new(
+        Zone {
+            position: [0, 0],
+            size: [100, 100]
+        },
+        layout,
+    );
+    let drawables = view.build();
+
+    assert_eq!(drawables[0].zone.size, Size::from([100, 100]));
+    assert_eq!(drawables[1].zone.size, Size::from([20, 10]));
+    assert_eq!(drawables[2].zone.size, Size::from([30, 10]));
+}
@@ -8,7 +8,7 @@ use haussmann_dev::Widget;
 
 use crate::{
     widgets,
-    Align, Direction, Overflow, themes::{Style, Theme},
+    Align, Direction, Overflow, Padding, themes::{Component, Spacing, Style, Theme},
 };
 
 use super::{DebugWidget, Label, Layout, Widget};
@@ -42,26 +42,33 @@ impl Widget for Button {
     fn build(&self) -> Box<dyn Widget> {
         Layout {
             style: self.style.clone(),
+            component: Component::Button,
             overflow: Overflow::Hide,
             wx_align: Align::Center,
             wy_align: Align::Center,
             direction: Direction::Column,
-            widgets: widgets![self.label.clone()],
+            padding: Padding::default(),
+            gap: Spacing::default(),
+            widgets: widgets![
+                Label {
+                    component: Component::Button,
+                    ..self.label.clone()
+                }
+            ],
         }
         .into()
     }
 
     fn style(&self, theme: &Theme) -> Style {
         match &self.style {
-            Some(style) => style,
-            None => &theme.style
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Button),
         }
-        .clone()
     }
 
     fn style_mut(&mut self, theme: &Theme) -> &mut Style {
         if self.style.is_none() {
-            self.style = Some(theme.style.clone()); 
+            self.style = Some(theme.component_style(Component::Button)); 
         }
 
         self.style.as_mut().unwrap()
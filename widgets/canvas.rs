@@ -0,0 +1,212 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use std::{fmt, rc::Rc};
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::colours::RGBA,
+    themes::{LabelStyle, Style, Theme},
+    Zone,
+};
+
+use super::{DebugWidget, Image, Path, Stroke, Vertex, Widget};
+
+/// Closure drawing a canvas in its zone, by recording commands into a
+/// context.
+pub type DrawFn = Rc<dyn Fn(Zone, &mut Context)>;
+
+/// Commands recorded by a [`Canvas`], in the order they are drawn.
+pub type DisplayList = Vec<Command>;
+
+/// Drawing command recorded by a [`Context`].
+///
+/// The positions are in the coordinates of the view, like the zones of the
+/// drawables, and are transformed with the drawable of the canvas.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Fills a rectangle with a colour.
+    Rect {
+        /// The filled rectangle.
+        zone: Zone,
+        /// The colour of the rectangle.
+        colour: RGBA,
+    },
+    /// Draws a straight line.
+    Line {
+        /// The start of the line.
+        from: Vertex,
+        /// The end of the line.
+        to: Vertex,
+        /// The colour and the width of the line.
+        stroke: Stroke,
+    },
+    /// Draws a path placed in a zone, like a [`Path`] widget.
+    Path {
+        /// The drawn path.
+        path: Path,
+        /// The zone the path is placed in.
+        zone: Zone,
+    },
+    /// Draws a text.
+    Text {
+        /// The zone of the text.
+        zone: Zone,
+        /// The drawn text.
+        text: String,
+        /// The style of the text, resolved with the theme.
+        label_style: LabelStyle,
+    },
+    /// Draws an image.
+    Image {
+        /// The zone of the image.
+        zone: Zone,
+        /// The drawn image, with its style resolved with the theme.
+        image: Box<Image>,
+    },
+}
+
+/// Recording draw context given to the [`DrawFn`] of a [`Canvas`].
+///
+/// Nothing is drawn by the context, the commands are kept in a
+/// [`DisplayList`] which the backends draw.
+#[derive(Debug)]
+pub struct Context<'a> {
+    /// The zone of the canvas.
+    zone: Zone,
+    /// The theme of the view, to resolve the styles.
+    theme: &'a Theme,
+    /// The recorded commands.
+    commands: DisplayList,
+}
+
+impl<'a> Context<'a> {
+    /// Creates a context for a canvas of zone `zone`, without commands.
+    pub fn new(zone: Zone, theme: &'a Theme) -> Self {
+        Self {
+            zone,
+            theme,
+            commands: vec![],
+        }
+    }
+
+    /// Returns the zone of the canvas.
+    pub fn zone(&self) -> Zone {
+        self.zone
+    }
+
+    /// Returns the theme of the view.
+    pub fn theme(&self) -> &Theme {
+        self.theme
+    }
+
+    /// Returns the recorded commands.
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Returns the display list of the recorded commands.
+    pub fn finish(self) -> DisplayList {
+        self.commands
+    }
+
+    /// Fills a rectangle with a colour.
+    pub fn rect(&mut self, zone: Zone, colour: RGBA) {
+        self.commands.push(Command::Rect { zone, colour });
+    }
+
+    /// Draws a straight line of a colour and a width.
+    pub fn line(&mut self, from: Vertex, to: Vertex, colour: RGBA, width: f32) {
+        self.commands.push(Command::Line {
+            from,
+            to,
+            stroke: Stroke { colour, width },
+        });
+    }
+
+    /// Draws a path placed in a zone.
+    pub fn path(&mut self, path: Path, zone: Zone) {
+        self.commands.push(Command::Path { path, zone });
+    }
+
+    /// Draws a text with the label style of the theme.
+    pub fn text(&mut self, zone: Zone, text: &str) {
+        let label_style = self.theme.label_style.clone();
+        self.styled_text(zone, text, label_style);
+    }
+
+    /// Draws a text with a label style.
+    pub fn styled_text(&mut self, zone: Zone, text: &str, label_style: LabelStyle) {
+        self.commands.push(Command::Text {
+            zone,
+            text: text.to_string(),
+            label_style,
+        });
+    }
+
+    /// Draws an image, its style resolved with the theme.
+    pub fn image(&mut self, zone: Zone, image: Image) {
+        let image = Image {
+            style: Some(image.style(self.theme)),
+            ..image
+        };
+
+        self.commands.push(Command::Image {
+            zone,
+            image: Box::new(image),
+        });
+    }
+}
+
+/// Canvas widget, drawn by a closure, which is not a "surfaced" widget.
+///
+/// When the view is built, the closure receives the final zone of the canvas
+/// and a [`Context`] recording its commands. Its drawable holds the
+/// recorded [`DisplayList`], so custom graphics do not need a new widget
+/// type. The styles of the commands are resolved when they are recorded, so
+/// the view has to be built again when its theme changes.
+#[derive(Clone, Widget)]
+pub struct Canvas {
+    /// Draws the canvas.
+    pub draw: DrawFn,
+}
+
+impl fmt::Debug for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Canvas").finish_non_exhaustive()
+    }
+}
+
+impl Widget for Canvas {
+    fn build(&self) -> Box<dyn Widget> {
+        self.clone().into()
+    }
+
+    fn style(&self, _: &Theme) -> Style {
+        panic!("canvases have the styles of their commands");
+    }
+
+    fn style_mut(&mut self, _: &Theme) -> &mut Style {
+        panic!("canvases have the styles of their commands");
+    }
+}
+
+impl Canvas {
+    /// Creates a canvas drawn by a closure, which can capture the data it
+    /// draws.
+    pub fn new(draw: impl Fn(Zone, &mut Context) + 'static) -> Self {
+        Self { draw: Rc::new(draw) }
+    }
+
+    /// Records the commands of the canvas in a zone, with the styles resolved
+    /// with a theme.
+    pub fn record(&self, zone: Zone, theme: &Theme) -> DisplayList {
+        let mut context = Context::new(zone, theme);
+        (self.draw)(zone, &mut context);
+        context.finish()
+    }
+}
@@ -0,0 +1,531 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::{colours::{HSL, RGBA}, Size},
+    style,
+    themes::{Component, Style, Theme},
+    Zone,
+};
+
+use super::{Child, DebugWidget, Label, Path, Stack, Surface, Widget};
+
+/// Default height of the legend of a chart.
+pub const LEGEND_HEIGHT: usize = 24;
+/// Default width of the value axis of a chart, on the left of its plot.
+pub const AXIS_WIDTH: usize = 48;
+/// Default height of the category axis of a chart, under its plot.
+pub const AXIS_HEIGHT: usize = 24;
+
+/// Height of the label of a tick of the value axis.
+const TICK_HEIGHT: usize = 16;
+/// Size of the coloured square of an entry of the legend.
+const SWATCH: usize = 12;
+/// Part of the width of a category taken by its bars.
+const BARS: f32 = 0.8;
+/// Width of the lines of a line chart.
+const LINE_WIDTH: f32 = 2.0;
+
+/// How the values of a [`Chart`] are plotted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Plot {
+    /// Each series is a line going through its values.
+    Line,
+    /// Each value is a bar, the bars of a category side by side.
+    Bar,
+    /// The values of the first series are the slices of a disc.
+    Pie,
+}
+
+/// Named values plotted by a [`Chart`], one for each category.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    /// The name of the series, in the legend.
+    pub name: String,
+    /// The values, in the order of the categories.
+    pub values: Vec<f32>,
+    /// The colour of the series.
+    ///
+    /// If set as `None`, a colour is generated from the primary colour of the
+    /// global theme.
+    pub colour: Option<RGBA>,
+}
+
+impl Series {
+    /// Creates a series without colour.
+    pub fn new(name: &str, values: &[f32]) -> Self {
+        Self {
+            name: name.to_string(),
+            values: values.to_vec(),
+            colour: None,
+        }
+    }
+
+    /// Returns the series with a colour.
+    pub fn with_colour(mut self, colour: RGBA) -> Self {
+        self.colour = Some(colour);
+        self
+    }
+}
+
+/// Configuration of the value axis of a [`Chart`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Axis {
+    /// The lowest value of the axis, otherwise the lowest plotted value.
+    pub min: Option<f32>,
+    /// The highest value of the axis, otherwise the highest plotted value.
+    pub max: Option<f32>,
+    /// The wanted number of ticks, the scale may have a few more or less.
+    pub ticks: usize,
+}
+
+impl Default for Axis {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            ticks: 5,
+        }
+    }
+}
+
+/// Scale of the value axis of a [`Chart`], going from a multiple of its step
+/// to another one.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ValueScale {
+    /// The value at the bottom of the axis.
+    pub min: f32,
+    /// The value at the top of the axis.
+    pub max: f32,
+    /// The difference between two ticks, 1, 2 or 5 times a power of ten.
+    pub step: f32,
+}
+
+impl ValueScale {
+    /// Creates a scale with about `ticks` ticks, covering the values from
+    /// `min` to `max`.
+    pub fn new(min: f32, max: f32, ticks: usize) -> Self {
+        let (min, max) = match max - min {
+            range if range > 0.0 => (min, max),
+            // The same value everywhere, in the middle of the axis.
+            _ if min == 0.0 => (0.0, 1.0),
+            _ => (min - min.abs() / 2.0, max + max.abs() / 2.0),
+        };
+
+        let range = nice(max - min, false);
+        let step = nice(range / (ticks.max(2) - 1) as f32, true);
+
+        Self {
+            min: (min / step).floor() * step,
+            max: (max / step).ceil() * step,
+            step,
+        }
+    }
+
+    /// Returns the values of the ticks, from the bottom to the top.
+    pub fn ticks(&self) -> Vec<f32> {
+        let count = ((self.max - self.min) / self.step).round() as usize;
+        (0..=count).map(|i| self.min + i as f32 * self.step).collect()
+    }
+
+    /// Returns the distance from the bottom of an axis of length `length` to
+    /// a value.
+    pub fn position(&self, value: f32, length: usize) -> f32 {
+        (value - self.min) / (self.max - self.min) * length as f32
+    }
+
+    /// Returns a value written with as many decimals as the step needs.
+    pub fn format(&self, value: f32) -> String {
+        let decimals = (-self.step.log10().floor()).max(0.0) as usize;
+        // Avoids writing "-0".
+        format!("{:.*}", decimals, value + 0.0)
+    }
+}
+
+/// Returns a number close to `value`, 1, 2 or 5 times a power of ten, rounded
+/// or at least as large as `value`.
+fn nice(value: f32, round: bool) -> f32 {
+    let exponent = value.log10().floor();
+    let fraction = value / 10f32.powf(exponent);
+
+    let nice = match round {
+        true if fraction < 1.5 => 1.0,
+        true if fraction < 3.0 => 2.0,
+        true if fraction < 7.0 => 5.0,
+        false if fraction <= 1.0 => 1.0,
+        false if fraction <= 2.0 => 2.0,
+        false if fraction <= 5.0 => 5.0,
+        _ => 10.0,
+    };
+
+    nice * 10f32.powf(exponent)
+}
+
+/// Chart widget, series of values plotted as lines, bars or a pie.
+///
+/// A line or bar chart has a value axis with ticks on its left and the
+/// categories under its plot, a pie has a slice for each category. Their
+/// legend is over them. The chart is built as surfaces, labels and
+/// [`Path`]s placed in its zone, so every backend drawing them draws the
+/// chart. They are part of the chart, so they are not browsed for
+/// controllers.
+#[derive(Debug, Clone, Widget)]
+pub struct Chart {
+    /// Independent style for the surface behind the chart.
+    ///
+    /// If set as `None`, the default layout style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// How the values are plotted.
+    pub plot: Plot,
+    /// The plotted series.
+    pub series: Vec<Series>,
+    /// The name of each category.
+    pub categories: Vec<String>,
+    /// The configuration of the value axis.
+    pub axis: Axis,
+    /// Whether the legend is shown.
+    pub legend: bool,
+}
+
+impl Widget for Chart {
+    /// Builds the surface behind the chart, like a layout.
+    fn build(&self) -> Box<dyn Widget> {
+        Surface {
+            style: self.style.clone(),
+            component: Component::Layout,
+        }.into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Layout),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Layout));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+
+    /// Visits the parts of the chart, sharing its identifier.
+    fn children(&self, size: Size, theme: &Theme, child: &mut dyn FnMut(Child)) {
+        self.stack_in(size, theme).shared_children(size, child);
+    }
+}
+
+impl Chart {
+    /// Creates a line chart without independent style, with a legend.
+    pub fn line(categories: &[&str], series: Vec<Series>) -> Self {
+        Self {
+            style: None,
+            plot: Plot::Line,
+            series,
+            categories: categories.iter().map(|category| category.to_string()).collect(),
+            axis: Axis::default(),
+            legend: true,
+        }
+    }
+
+    /// Creates a bar chart without independent style, with a legend.
+    pub fn bar(categories: &[&str], series: Vec<Series>) -> Self {
+        Self {
+            plot: Plot::Bar,
+            ..Self::line(categories, series)
+        }
+    }
+
+    /// Creates a pie chart without independent style, with a slice for each
+    /// category and a legend.
+    pub fn pie(categories: &[&str], values: &[f32]) -> Self {
+        Self {
+            plot: Plot::Pie,
+            ..Self::line(categories, vec![Series::new("", values)])
+        }
+    }
+
+    /// Returns the chart with another configuration of its value axis.
+    pub fn with_axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Returns the chart without legend.
+    pub fn without_legend(mut self) -> Self {
+        self.legend = false;
+        self
+    }
+
+    /// Returns the scale of the value axis, covering the values of the series
+    /// and zero for the bars.
+    pub fn scale(&self) -> ValueScale {
+        let values = self.series
+            .iter()
+            .flat_map(|series| series.values.iter().copied())
+            .filter(|value| value.is_finite());
+
+        let (mut min, mut max) = values.fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+
+        if self.plot == Plot::Bar || min > max {
+            min = min.min(0.0);
+            max = max.max(0.0);
+        }
+
+        ValueScale::new(
+            self.axis.min.unwrap_or(min),
+            self.axis.max.unwrap_or(max),
+            self.axis.ticks,
+        )
+    }
+
+    /// Returns the colour of a series, or of a slice for a pie.
+    ///
+    /// The colours without independent colour have the saturation and the
+    /// lightness of the primary colour of the theme, their hue turned by the
+    /// golden angle from the previous one.
+    pub fn colour(&self, theme: &Theme, i: usize) -> RGBA {
+        let colour = match self.plot {
+            Plot::Pie => None,
+            _ => self.series.get(i).and_then(|series| series.colour),
+        };
+
+        colour.unwrap_or_else(|| {
+            let primary = HSL::from(theme.colour_scheme.primary);
+
+            HSL {
+                h: (primary.h + 137.5 * i as f32) % 360.0,
+                ..primary
+            }
+            .into()
+        })
+    }
+
+    /// Returns the number of categories, which is the number of values of
+    /// the longest series when some categories have no name.
+    pub fn count(&self) -> usize {
+        self.series
+            .iter()
+            .map(|series| series.values.len())
+            .chain([self.categories.len()])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the entries of the legend: the categories of a pie, otherwise
+    /// the names of the series.
+    pub fn entries(&self) -> Vec<String> {
+        match self.plot {
+            Plot::Pie => self.categories.clone(),
+            _ => self.series.iter().map(|series| series.name.clone()).collect(),
+        }
+    }
+
+    /// Returns the zone of the plot, relative to the top-left corner of a
+    /// chart of size `size`.
+    pub fn plot_zone(&self, size: Size) -> Zone {
+        let top = match self.legend && !self.entries().is_empty() {
+            true => LEGEND_HEIGHT.min(size[1]),
+            false => 0,
+        };
+
+        let (left, bottom) = match self.plot {
+            Plot::Pie => (0, 0),
+            _ => (AXIS_WIDTH.min(size[0]), AXIS_HEIGHT),
+        };
+
+        Zone {
+            position: [left as isize, top as isize],
+            size: [size[0] - left, (size[1] - top).saturating_sub(bottom)],
+        }
+    }
+
+    /// Returns the stack of the parts of the chart in a chart of size `size`,
+    /// coloured from a theme.
+    pub fn stack_in(&self, size: Size, theme: &Theme) -> Stack {
+        let mut stack = Stack::new();
+
+        if self.legend {
+            self.stack_legend(&mut stack, size, theme);
+        }
+
+        match self.plot {
+            Plot::Pie => self.stack_pie(&mut stack, size, theme),
+            _ => self.stack_plot(&mut stack, size, theme),
+        }
+
+        stack
+    }
+
+    /// Adds the entries of the legend, sharing its width, each one with a
+    /// coloured square and a label.
+    fn stack_legend(&self, stack: &mut Stack, size: Size, theme: &Theme) {
+        let entries = self.entries();
+
+        if entries.is_empty() {
+            return;
+        }
+
+        let height = LEGEND_HEIGHT.min(size[1]);
+        let width = size[0] / entries.len();
+
+        for (i, entry) in entries.iter().enumerate() {
+            let x = (i * width) as isize;
+
+            stack.widgets.push((
+                Some(([x + 4, (height.saturating_sub(SWATCH) / 2) as isize], [SWATCH, SWATCH]).into()),
+                Surface::styled(Some(style!(colour: self.colour(theme, i)))).into(),
+            ));
+            stack.widgets.push((
+                Some(([x + (SWATCH + 8) as isize, 0], [width.saturating_sub(SWATCH + 8), height]).into()),
+                Label::normal(entry).into(),
+            ));
+        }
+    }
+
+    /// Adds the value axis with its ticks, the grid, the categories, and the
+    /// lines or the bars of the series.
+    fn stack_plot(&self, stack: &mut Stack, size: Size, theme: &Theme) {
+        let plot = self.plot_zone(size);
+        let [width, height] = plot.size;
+        let scale = self.scale();
+        let scheme = &theme.colour_scheme;
+
+        // The vertical position of a value in the stack.
+        let y = |value: f32| plot.y() + height as isize - scale.position(value, height).round() as isize;
+
+        for tick in scale.ticks() {
+            stack.widgets.push((
+                Some(([plot.x(), y(tick)], [width, 1]).into()),
+                Surface::styled(Some(style!(colour: scheme.surface_variant))).into(),
+            ));
+            stack.widgets.push((
+                Some(([0, y(tick) - (TICK_HEIGHT / 2) as isize], [plot.x().max(4) as usize - 4, TICK_HEIGHT]).into()),
+                Label::normal(&scale.format(tick)).into(),
+            ));
+        }
+
+        let count = self.count();
+        let slot = width as f32 / count.max(1) as f32;
+
+        for (i, category) in self.categories.iter().enumerate() {
+            stack.widgets.push((
+                Some(([plot.x() + (i as f32 * slot) as isize, plot.y() + height as isize], [slot as usize, AXIS_HEIGHT]).into()),
+                Label::normal(category).into(),
+            ));
+        }
+
+        // The bars go from zero, or from the nearest end of the axis.
+        let base = 0.0_f32.clamp(scale.min, scale.max);
+
+        match self.plot {
+            Plot::Bar => {
+                let bar = slot * BARS / self.series.len().max(1) as f32;
+
+                for (s, series) in self.series.iter().enumerate() {
+                    let colour = self.colour(theme, s);
+
+                    for (i, value) in series.values.iter().enumerate() {
+                        if !value.is_finite() {
+                            continue;
+                        }
+
+                        let x = plot.x() as f32 + i as f32 * slot + slot * (1.0 - BARS) / 2.0 + s as f32 * bar;
+                        let (top, bottom) = (y(value.clamp(scale.min, scale.max)), y(base));
+
+                        stack.widgets.push((
+                            Some(([x.round() as isize, top.min(bottom)], [bar.round() as usize, top.abs_diff(bottom)]).into()),
+                            Surface::styled(Some(style!(colour: colour))).into(),
+                        ));
+                    }
+                }
+            }
+            _ => {
+                for (s, series) in self.series.iter().enumerate() {
+                    let mut path = Path::new().with_stroke(self.colour(theme, s), LINE_WIDTH);
+                    let mut drawing = false;
+
+                    for (i, value) in series.values.iter().enumerate() {
+                        // A missing value breaks the line.
+                        if !value.is_finite() {
+                            drawing = false;
+                            continue;
+                        }
+
+                        let point = [(i as f32 + 0.5) * slot, height as f32 - scale.position(*value, height)];
+                        path = match drawing {
+                            true => path.line_to(point),
+                            false => path.move_to(point),
+                        };
+                        drawing = true;
+                    }
+
+                    stack.widgets.push((Some(plot), path.into()));
+                }
+            }
+        }
+
+        // The axes, over the grid.
+        stack.widgets.push((
+            Some(([plot.x(), plot.y()], [1, height]).into()),
+            Surface::styled(Some(style!(colour: scheme.outline))).into(),
+        ));
+        stack.widgets.push((
+            Some(([plot.x(), y(base)], [width, 1]).into()),
+            Surface::styled(Some(style!(colour: scheme.outline))).into(),
+        ));
+    }
+
+    /// Adds a slice for each positive value of the first series, clockwise
+    /// from the top of the disc.
+    fn stack_pie(&self, stack: &mut Stack, size: Size, theme: &Theme) {
+        let plot = self.plot_zone(size);
+        let values = self.series.first().map(|series| series.values.as_slice()).unwrap_or(&[]);
+        let total: f32 = values.iter().filter(|value| **value > 0.0).sum();
+
+        if total <= 0.0 {
+            return;
+        }
+
+        let radius = plot.width().min(plot.height()) as f32 / 2.0;
+        let centre = [plot.width() as f32 / 2.0, plot.height() as f32 / 2.0];
+        let point = |angle: f32| {
+            let (sin, cos) = (angle - 90.0).to_radians().sin_cos();
+            [centre[0] + radius * cos, centre[1] + radius * sin]
+        };
+
+        let mut start = 0.0;
+
+        for (i, value) in values.iter().enumerate() {
+            if *value <= 0.0 {
+                continue;
+            }
+
+            let sweep = value / total * 360.0;
+            let path = match sweep >= 360.0 {
+                // The only slice is the whole disc, as two halves.
+                true => Path::new()
+                    .move_to(point(0.0))
+                    .arc_to([radius, radius], 0.0, false, true, point(180.0))
+                    .arc_to([radius, radius], 0.0, false, true, point(0.0)),
+                false => Path::new()
+                    .move_to(centre)
+                    .line_to(point(start))
+                    .arc_to([radius, radius], 0.0, sweep > 180.0, true, point(start + sweep)),
+            };
+
+            stack.widgets.push((Some(plot), path.close().with_fill(self.colour(theme, i)).into()));
+            start += sweep;
+        }
+    }
+}
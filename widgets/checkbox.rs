@@ -0,0 +1,128 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    controllers::toggle::Toggle,
+    Align, Direction, Overflow, Padding, themes::{Component, Spacing, Style, Theme},
+};
+
+use super::{DebugWidget, Label, Layout, Widget};
+
+/// Value of a [`Checkbox`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum Check {
+    #[allow(missing_docs)]
+    #[default]
+    Unchecked,
+    #[allow(missing_docs)]
+    Checked,
+    /// Neither checked nor unchecked, like a checkbox for a group of options
+    /// where only some options are checked. Only set by the application, 
+    /// toggling it checks the checkbox.
+    Indeterminate,
+}
+
+/// Checkbox widget, checked, unchecked or indeterminate.
+/// 
+/// Fills the zone it is placed in, wrap it in a 
+/// [`Container`](super::Container) to give it a size. Toggled by a 
+/// [`toggle::Toggler`](crate::controllers::toggle::Toggler).
+#[derive(Debug, Clone, Widget)]
+pub struct Checkbox {
+    /// Independent style for the checkbox.
+    /// 
+    /// If set as `None`, the default toggle style from the global theme will 
+    /// be used.
+    pub style: Option<Style>,
+    #[allow(missing_docs)]
+    pub value: Check,
+}
+
+impl Widget for Checkbox {
+    /// Builds the box, with a check mark when checked or a dash when 
+    /// indeterminate.
+    fn build(&self) -> Box<dyn Widget> {
+        let mark = match self.value {
+            Check::Unchecked => None,
+            Check::Checked => Some("✓"),
+            Check::Indeterminate => Some("–"),
+        };
+
+        Layout {
+            style: self.style.clone(),
+            component: self.component(),
+            overflow: Overflow::Hide,
+            wx_align: Align::Center,
+            wy_align: Align::Center,
+            direction: Direction::Column,
+            padding: Padding::default(),
+            gap: Spacing::default(),
+            widgets: mark
+                .map(|mark| Label {
+                    style: None,
+                    component: Component::ToggleSelected,
+                    text: mark.to_string(),
+                }.into())
+                .into_iter()
+                .collect(),
+        }
+        .into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(self.component()),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(self.component())); 
+        }
+
+        self.style.as_mut().unwrap()
+    }
+}
+
+impl Toggle for Checkbox {
+    /// Unchecks a checked checkbox, checks it otherwise.
+    fn toggle(&mut self) -> bool {
+        self.value = match self.value {
+            Check::Checked => Check::Unchecked,
+            Check::Unchecked | Check::Indeterminate => Check::Checked,
+        };
+        true
+    }
+}
+
+impl Checkbox {
+    /// Creates a new checkbox with an independent style.
+    pub fn styled(style: Style, value: Check) -> Self {
+        Self {
+            style: Some(style),
+            value,
+        }
+    }
+
+    /// Creates a checkbox without independent style.
+    pub fn normal(value: Check) -> Self {
+        Self {
+            style: None,
+            value,
+        }
+    }
+
+    /// Returns the component the box is drawn for, following the value.
+    fn component(&self) -> Component {
+        match self.value {
+            Check::Unchecked => Component::Toggle,
+            Check::Checked | Check::Indeterminate => Component::ToggleSelected,
+        }
+    }
+}
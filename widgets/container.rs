@@ -7,8 +7,9 @@ use any::ToAny;
 use haussmann_dev::Widget;
 
 use crate::{
-    graphics::Size,
-    DebugWidget, Widget, themes::{Theme, Style},
+    controllers::Controller,
+    graphics::{Matrix, Size},
+    DebugWidget, Widget, themes::{State, Theme, Style}, Zone,
 };
 
 /// Wraps a widget giving it a fixed size.
@@ -40,6 +41,30 @@ impl Widget for Container {
     fn style_mut(&mut self, theme: &Theme) -> &mut Style {
         self.widget.style_mut(theme)
     }
+
+    fn transform(&self, zone: Zone) -> Matrix {
+        self.widget.transform(zone)
+    }
+
+    fn state(&self) -> State {
+        self.widget.state()
+    }
+
+    fn as_controller_mut(&mut self) -> Option<&mut dyn Controller> {
+        self.widget.as_controller_mut()
+    }
+
+    fn wrapped(&self) -> Option<&dyn Widget> {
+        Some(self.widget.as_ref())
+    }
+
+    fn wrapped_mut(&mut self) -> Option<&mut Box<dyn Widget>> {
+        Some(&mut self.widget)
+    }
+
+    fn id_count(&self) -> usize {
+        self.widget.id_count()
+    }
 }
 
 impl Container {
@@ -0,0 +1,220 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::colours::RGBA,
+    themes::{Component, LabelStyle, State, Style, Theme},
+    Zone,
+};
+
+use super::{DebugWidget, Path, PathError, Widget};
+
+/// Size of the square in which the paths of the [built-in icons](icons) are
+/// drawn.
+pub const VIEW_BOX: f32 = 24.0;
+
+/// What an [`Icon`] draws.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Glyph {
+    /// A character of an icon font.
+    Font {
+        /// The name of the font family, one of the fonts of the theme.
+        family: String,
+        /// The character of the icon in the font.
+        character: char,
+    },
+    /// Filled paths, as SVG path data.
+    Paths {
+        /// The data of each path, like the `d` attribute of an SVG path.
+        paths: Vec<String>,
+        /// The size of the square in which the paths are drawn, scaled to the
+        /// size of the icon.
+        view_box: f32,
+    },
+}
+
+/// Icon widget, a glyph of an icon font or a set of paths, which is not a
+/// "surfaced" widget.
+///
+/// The icon is a square as large as the text size of its label style, tinted
+/// by its colour, in the middle of the zone it is placed in. Its drawable
+/// covers this square.
+#[derive(Debug, Clone, PartialEq, Widget)]
+pub struct Icon {
+    /// An independent style from the global theme.
+    ///
+    /// If set as `None`, the style for labels defined in the global theme
+    /// will be used.
+    pub style: Option<LabelStyle>,
+    /// The kind of widget the icon is drawn on. Used to find a readable
+    /// style in the global theme when there is no independent style.
+    pub component: Component,
+    /// What the icon draws.
+    pub glyph: Glyph,
+}
+
+impl Widget for Icon {
+    fn build(&self) -> Box<dyn Widget> {
+        self.clone().into()
+    }
+
+    fn style(&self, _: &Theme) -> Style {
+        panic!("icons have label styles. check the `Icon::label_style()` function");
+    }
+
+    fn style_mut(&mut self, _: &Theme) -> &mut Style {
+        panic!("icons have label styles. check the `Icon::label_style_mut()` function");
+    }
+}
+
+impl Icon {
+    /// Creates an icon from a character of an icon font, without independent
+    /// style.
+    pub fn font(family: &str, character: char) -> Self {
+        Self {
+            style: None,
+            component: Component::Layout,
+            glyph: Glyph::Font {
+                family: family.to_string(),
+                character,
+            },
+        }
+    }
+
+    /// Creates an icon from SVG path data drawn in a square of
+    /// [`VIEW_BOX`], like the [built-in icons](icons), without independent
+    /// style.
+    pub fn paths(paths: &[&str]) -> Self {
+        Self {
+            style: None,
+            component: Component::Layout,
+            glyph: Glyph::Paths {
+                paths: paths.iter().map(|path| path.to_string()).collect(),
+                view_box: VIEW_BOX,
+            },
+        }
+    }
+
+    /// Returns the icon with an independent style.
+    pub fn with_style(mut self, style: LabelStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Returns the icon drawn on another kind of widget.
+    pub fn with_component(mut self, component: Component) -> Self {
+        self.component = component;
+        self
+    }
+
+    /// Same as [`Label::label_style()`](super::Label::label_style) for the
+    /// icon.
+    pub fn label_style(&self, theme: &Theme) -> LabelStyle {
+        match &self.style {
+            Some(label_style) => label_style.clone(),
+            None => theme.component_label_style(self.component),
+        }
+    }
+
+    /// Same as [`Self::label_style()`] but for the icon in an interaction
+    /// state.
+    pub fn label_style_in(&self, theme: &Theme, state: State) -> LabelStyle {
+        theme.label_style_in(&self.label_style(theme), state)
+    }
+
+    /// Same as [`Label::label_style_mut()`](super::Label::label_style_mut)
+    /// for the icon.
+    pub fn label_style_mut(&mut self, theme: &Theme) -> &mut LabelStyle {
+        if self.style.is_none() {
+            self.style = Some(theme.component_label_style(self.component));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+
+    /// Returns the size of the icon: the text size of its label style, or of
+    /// the paragraphs of the theme when the label style has no text style.
+    pub fn size(&self, theme: &Theme) -> usize {
+        let size = match self.label_style(theme).text_style {
+            Some(text_style) => text_style.size,
+            None => theme.text_theme.paragraph1.size,
+        };
+
+        size.max(0) as usize
+    }
+
+    /// Returns the paths of the icon filled with a colour, scaled from their
+    /// view box to the zone of the drawable of the icon. Icons of an icon
+    /// font have no paths.
+    pub fn to_paths(&self, colour: RGBA) -> Result<Vec<Path>, PathError> {
+        match &self.glyph {
+            Glyph::Font { .. } => Ok(vec![]),
+            Glyph::Paths { paths, view_box } => paths
+                .iter()
+                .map(|data| Ok(Path::parse(data)?
+                    .with_fill(colour)
+                    .with_view_box([*view_box, *view_box])
+                ))
+                .collect(),
+        }
+    }
+
+    /// Returns the square covered by an icon of size `size` in the middle of
+    /// a zone, smaller when the zone is.
+    pub fn zone_in(zone: Zone, size: usize) -> Zone {
+        let size = size.min(zone.width()).min(zone.height());
+
+        Zone {
+            position: [
+                zone.x() + (zone.width() - size) as isize / 2,
+                zone.y() + (zone.height() - size) as isize / 2,
+            ],
+            size: [size, size],
+        }
+    }
+}
+
+/// Built-in icons, as SVG path data drawn in a square of [`VIEW_BOX`]. Create
+/// an icon with [`Icon::paths()`].
+pub mod icons {
+    /// A plus sign.
+    pub const ADD: &[&str] = &["M11 5h2v6h6v2h-6v6h-2v-6H5v-2h6z"];
+    /// A minus sign.
+    pub const REMOVE: &[&str] = &["M5 11h14v2H5z"];
+    /// A cross.
+    pub const CLOSE: &[&str] = &["M6.4 5 12 10.6 17.6 5 19 6.4 13.4 12 19 17.6 17.6 19 12 13.4 6.4 19 5 17.6 10.6 12 5 6.4z"];
+    /// A check mark.
+    pub const CHECK: &[&str] = &["M9 16.2 4.8 12 3.4 13.4 9 19 21 7 19.6 5.6z"];
+    /// A chevron pointing up.
+    pub const CHEVRON_UP: &[&str] = &["M7.4 15.4 6 14l6-6 6 6-1.4 1.4-4.6-4.6z"];
+    /// A chevron pointing down.
+    pub const CHEVRON_DOWN: &[&str] = &["M7.4 8.6 6 10l6 6 6-6-1.4-1.4-4.6 4.6z"];
+    /// A chevron pointing left.
+    pub const CHEVRON_LEFT: &[&str] = &["M15.4 7.4 14 6l-6 6 6 6 1.4-1.4-4.6-4.6z"];
+    /// A chevron pointing right.
+    pub const CHEVRON_RIGHT: &[&str] = &["M8.6 7.4 10 6l6 6-6 6-1.4-1.4 4.6-4.6z"];
+    /// Three horizontal lines.
+    pub const MENU: &[&str] = &["M3 6h18v2H3z", "M3 11h18v2H3z", "M3 16h18v2H3z"];
+    /// A house.
+    pub const HOME: &[&str] = &["M12 3 2 12h3v8h5v-6h4v6h5v-8h3z"];
+    /// A magnifying glass.
+    pub const SEARCH: &[&str] = &[
+        "M10 3a7 7 0 1 0 0 14 7 7 0 1 0 0-14zm0 2a5 5 0 1 1 0 10 5 5 0 1 1 0-10z",
+        "M15.6 14.2 21 19.6 19.6 21 14.2 15.6z",
+    ];
+    /// A circle with the letter "i".
+    pub const INFO: &[&str] = &[
+        "M12 2a10 10 0 1 0 0 20 10 10 0 1 0 0-20zm0 2a8 8 0 1 1 0 16 8 8 0 1 1 0-16z",
+        "M11 7h2v2h-2z",
+        "M11 11h2v6h-2z",
+    ];
+    /// A triangle pointing right.
+    pub const PLAY: &[&str] = &["M8 5v14l11-7z"];
+    /// Two vertical bars.
+    pub const PAUSE: &[&str] = &["M6 5h4v14H6z", "M14 5h4v14h-4z"];
+}
@@ -13,7 +13,7 @@ use any::ToAny;
 
 use haussmann_dev::Widget;
 
-use crate::{ DebugWidget, Widget, themes::{Style, Theme} };
+use crate::{ DebugWidget, Widget, themes::{Component, State, Style, Theme} };
 
 /// Image widget.
 #[derive(Debug, Clone, PartialEq, Widget)]
@@ -34,17 +34,26 @@ impl Widget for Image {
 
     fn style(&self, theme: &Theme) -> Style {
         match &self.style {
-            Some(style) => style,
-            None => &theme.style
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Image),
         }
-        .clone()
     }
     
     fn style_mut(&mut self, theme: &Theme) -> &mut Style {
         if self.style.is_none() {
-            self.style = Some(theme.style.clone()); 
+            self.style = Some(theme.component_style(Component::Image)); 
         }
 
         self.style.as_mut().unwrap()
     }
 }
+
+impl Image {
+    /// Same as [`Widget::style()`] but for the image in an interaction state.
+    pub fn style_in(&self, theme: &Theme, state: State) -> Style {
+        match &self.style {
+            Some(style) => theme.independent_style_in(style, Component::Image, state),
+            None => theme.component_style_in(Component::Image, state),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use any::ToAny;
 use haussmann_dev::Widget;
 
 use crate::{
-    themes::{Theme, Style, LabelStyle},
+    themes::{Component, LabelStyle, State, Style, Theme},
 };
 
 use super::{DebugWidget, Widget};
@@ -23,6 +23,9 @@ pub struct Label {
     /// If set as `None`, the style for labels defined in the global theme 
     /// will be used.
     pub style: Option<LabelStyle>,
+    /// The kind of widget the label is drawn on. Used to find a readable 
+    /// style in the global theme when there is no independent style.
+    pub component: Component,
     /// The text string of the label.
     pub text: String,
 }
@@ -46,6 +49,7 @@ impl Label {
     pub fn styled(text: &str, style: LabelStyle) -> Self {
         Self {
             style: Some(style),
+            component: Component::Layout,
             text: text.to_string(),
         }
     }
@@ -54,6 +58,7 @@ impl Label {
     pub fn normal(text: &str) -> Self {
         Self {
             style: None,
+            component: Component::Layout,
             text: text.to_string(),
         }
     }
@@ -65,10 +70,15 @@ impl Label {
     /// Calling [`Label::style`] panics. 
     pub fn label_style(&self, theme: &Theme) -> LabelStyle {
         match &self.style {
-            Some(label_style) => label_style,
-            None => &theme.label_style
+            Some(label_style) => label_style.clone(),
+            None => theme.component_label_style(self.component),
         }
-        .clone()
+    }
+
+    /// Same as [`Label::label_style()`] but for the label in an interaction 
+    /// state.
+    pub fn label_style_in(&self, theme: &Theme, state: State) -> LabelStyle {
+        theme.label_style_in(&self.label_style(theme), state)
     }
 
     /// Same as [`Widget::style_mut()`] but labels return [`LabelStyle`] instead of
@@ -78,7 +88,7 @@ impl Label {
     /// Calling [`Label::style_mut`] panics. 
     pub fn label_style_mut(&mut self, theme: &Theme) -> &mut LabelStyle {
         if self.style.is_none() {
-            self.style = Some(theme.label_style.clone()); 
+            self.style = Some(theme.component_label_style(self.component)); 
         }
 
         self.style.as_mut().unwrap()
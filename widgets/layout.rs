@@ -6,7 +6,7 @@ use any::ToAny;
 
 use haussmann_dev::Widget;
 
-use crate::{ Align, Direction, Overflow, themes::{Theme, Style} };
+use crate::{ Align, Direction, Overflow, Padding, themes::{Component, Spacing, Theme, Style} };
 
 use super::{DebugWidget, Surface, Widget};
 
@@ -16,6 +16,12 @@ use super::{DebugWidget, Surface, Widget};
 pub struct Layout {
     /// The style of the layout.
     pub style: Option<Style>,
+    /// The kind of widget the layout is drawn for. Used to find the style in 
+    /// the global theme when there is no independent style.
+    /// 
+    /// It is [`Component::Layout`] unless the layout was built by another 
+    /// widget, like a [`Button`](super::Button).
+    pub component: Component,
     /// Rules about widget overflowing.
     pub overflow: Overflow,
     /// Widgets alignment on the X axis inside the layout.
@@ -24,6 +30,10 @@ pub struct Layout {
     pub wy_align: Align,
     /// The direction of the widgets arrangement.
     pub direction: Direction,
+    /// Space between the sides of the layout and its widgets.
+    pub padding: Padding,
+    /// Space between two widgets of the layout.
+    pub gap: Spacing,
     /// The widgets contained in the layout.
     pub widgets: Vec<Box<dyn Widget>>,
 }
@@ -32,10 +42,13 @@ impl Default for Layout {
     fn default() -> Self {
         Self {
             style: None,
+            component: Component::Layout,
             overflow: Overflow::Ignore,
             wx_align: Align::Center,
             wy_align: Align::Center,
             direction: Direction::Column,
+            padding: Padding::default(),
+            gap: Spacing::default(),
             widgets: vec![],
         }
     }
@@ -45,24 +58,29 @@ impl Widget for Layout {
     fn build(&self) -> Box<dyn Widget> {
         Surface {
             style: self.style.clone(),
+            component: self.component,
         }.into()
     }
     
     fn style(&self, theme: &Theme) -> Style {
         match &self.style {
-            Some(style) => style,
-            None => &theme.style
+            Some(style) => style.clone(),
+            None => theme.component_style(self.component),
         }
-        .clone()
     }
     
     fn style_mut(&mut self, theme: &Theme) -> &mut Style {
         if self.style.is_none() {
-            self.style = Some(theme.style.clone()); 
+            self.style = Some(theme.component_style(self.component)); 
         }
 
         self.style.as_mut().unwrap()
     }
+
+    /// Counts the identifier of the layout, then the ones of its widgets.
+    fn id_count(&self) -> usize {
+        1 + self.widgets.iter().map(|widget| widget.id_count()).sum::<usize>()
+    }
 }
 
 impl Layout {
@@ -77,10 +95,13 @@ impl Layout {
     ) -> Self {
         Self {
             style: Some(style),
+            component: Component::Layout,
             overflow,
             wx_align,
             wy_align,
             direction,
+            padding: Padding::default(),
+            gap: Spacing::default(),
             widgets,
         }
     }
@@ -94,14 +115,35 @@ impl Layout {
     ) -> Self {
         Self {
             style: None,
+            component: Component::Layout,
             overflow,
             wx_align,
             wy_align,
             direction,
+            padding: Padding::default(),
+            gap: Spacing::default(),
             widgets,
         }
     }
 
+    /// Returns the layout with a padding, in pixels or referencing the 
+    /// spacing scale of the theme.
+    pub fn with_padding(self, padding: Padding) -> Self {
+        Self {
+            padding,
+            ..self
+        }
+    }
+
+    /// Returns the layout with a gap between its widgets, in pixels or 
+    /// referencing the spacing scale of the theme.
+    pub fn with_gap<T: Into<Spacing>>(self, gap: T) -> Self {
+        Self {
+            gap: gap.into(),
+            ..self
+        }
+    }
+
     /// Returns all the widgets of type `T` from the `widgets` contained in the
     /// layout.
     ///
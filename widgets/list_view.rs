@@ -0,0 +1,371 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use std::{cell::{Ref, RefCell}, fmt, ops::Range, rc::Rc};
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::Size,
+    themes::{Component, Style, Theme},
+    Zone,
+};
+
+use super::{Child, DebugWidget, Surface, Widget};
+
+/// Default number of rows built before and after the visible rows of a list
+/// view.
+pub const BUFFER: usize = 2;
+
+/// Closure building the widget of a row of a list view.
+pub type ItemFn = Rc<dyn Fn(usize) -> Box<dyn Widget>>;
+
+/// Height of the rows of a [`ListView`].
+#[derive(Clone)]
+pub enum RowHeight {
+    /// All the rows have the same height.
+    Fixed(usize),
+    /// Each row has its own height, given by a closure.
+    Variable(Rc<dyn Fn(usize) -> usize>),
+}
+
+impl RowHeight {
+    /// Creates variable heights given by a closure.
+    pub fn variable(height: impl Fn(usize) -> usize + 'static) -> Self {
+        Self::Variable(Rc::new(height))
+    }
+}
+
+impl fmt::Debug for RowHeight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed(height) => f.debug_tuple("Fixed").field(height).finish(),
+            Self::Variable(_) => f.debug_tuple("Variable").finish_non_exhaustive(),
+        }
+    }
+}
+
+/// List of many rows, only building the rows in its zone.
+///
+/// The rows are built by a function from their index when the list is built,
+/// so a list of thousands of rows only builds the few rows it shows, plus a
+/// buffer of rows around them, and only creates drawables for the rows it
+/// shows. The rows are scrolled by an offset, and hidden out of the zone of
+/// the list. A
+/// [`scroll::Scroller`](crate::controllers::scroll::Scroller) scrolls the
+/// list.
+///
+/// Each built row takes the identifiers following the ones of the previous
+/// row, like the widgets of a layout, so the rows can contain controllers.
+/// The built rows are kept while they stay in the built rows, with the state
+/// of their controllers, and the rows entering them are built. Before the
+/// list is built, it has no rows.
+///
+/// The tops of the rows of variable heights are summed once and kept until
+/// the number of rows or their heights change, so finding the visible rows
+/// does not go through all the rows at each build.
+#[derive(Widget)]
+pub struct ListView {
+    /// Independent style for the surface behind the rows.
+    ///
+    /// If set as `None`, the default layout style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// The number of rows.
+    pub count: usize,
+    /// Builds the widget of a row. The built rows are not built again when
+    /// it changes, see [`Self::refresh_rows()`].
+    pub item: ItemFn,
+    /// The scrolled distance from the top of the first row, in pixels.
+    pub offset: usize,
+    /// The number of rows built before and after the visible rows.
+    pub buffer: usize,
+    /// The height of the rows.
+    height: RowHeight,
+    /// Distances from the top of the first row to the top of each row of
+    /// variable height, then to the bottom of the last row.
+    tops: RefCell<Vec<usize>>,
+    /// The built rows, with their indexes, in the order of the rows.
+    rows: RefCell<Vec<(usize, Box<dyn Widget>)>>,
+}
+
+impl Clone for ListView {
+    /// Clones the list without its built rows, which are built again.
+    fn clone(&self) -> Self {
+        Self {
+            style: self.style.clone(),
+            count: self.count,
+            item: self.item.clone(),
+            offset: self.offset,
+            buffer: self.buffer,
+            height: self.height.clone(),
+            tops: self.tops.clone(),
+            rows: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl fmt::Debug for ListView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListView")
+            .field("style", &self.style)
+            .field("count", &self.count)
+            .field("offset", &self.offset)
+            .field("buffer", &self.buffer)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Widget for ListView {
+    /// Builds the surface behind the rows, like a layout.
+    fn build(&self) -> Box<dyn Widget> {
+        Surface {
+            style: self.style.clone(),
+            component: Component::Layout,
+        }.into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Layout),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Layout));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+
+    /// Counts the identifier of the list, then the ones of the built rows.
+    fn id_count(&self) -> usize {
+        1 + self.rows.borrow().iter().map(|(_, row)| row.id_count()).sum::<usize>()
+    }
+
+    /// Visits the visible rows in a list of size `size`, each row in its zone
+    /// scrolled by the offset, after building the rows entering the built
+    /// rows. The rows of the buffer keep their identifiers but are not
+    /// visited, so they have no drawables.
+    fn children(&self, size: Size, _theme: &Theme, child: &mut dyn FnMut(Child)) {
+        self.build_rows(size[1]);
+
+        let visible = self.visible_rows(size[1]);
+        let rows = self.rows.borrow();
+        let mut top = match rows.first() {
+            Some(&(first, _)) => self.row_top(first) as isize - self.offset as isize,
+            None => 0,
+        };
+        let mut id = 1;
+
+        for (row, widget) in rows.iter() {
+            let height = self.row_height(*row);
+
+            if visible.contains(row) {
+                child(Child {
+                    id,
+                    zone: Zone {
+                        position: [0, top],
+                        size: [size[0], height],
+                    },
+                    widget: widget.as_ref(),
+                    shared: false,
+                });
+            }
+
+            top += height as isize;
+            id += widget.id_count();
+        }
+    }
+
+    /// Visits the rows built last.
+    fn children_mut(&mut self, child: &mut dyn FnMut(usize, &mut Box<dyn Widget>)) {
+        let mut id = 1;
+
+        for (_, widget) in self.rows.get_mut() {
+            let count = widget.id_count();
+            child(id, widget);
+            id += count;
+        }
+    }
+}
+
+impl ListView {
+    /// Creates a list view scrolled to its first row, without independent
+    /// style.
+    pub fn new(count: usize, height: RowHeight, item: impl Fn(usize) -> Box<dyn Widget> + 'static) -> Self {
+        Self {
+            style: None,
+            count,
+            item: Rc::new(item),
+            offset: 0,
+            buffer: BUFFER,
+            height,
+            tops: RefCell::new(vec![]),
+            rows: RefCell::new(vec![]),
+        }
+    }
+
+    /// Returns the height of the rows.
+    pub fn height(&self) -> &RowHeight {
+        &self.height
+    }
+
+    /// Sets the height of the rows.
+    pub fn set_height(&mut self, height: RowHeight) {
+        self.height = height;
+        self.refresh_heights();
+    }
+
+    /// Sums again the heights of the rows, to call when the closure of the
+    /// variable heights returns other heights for the same rows.
+    pub fn refresh_heights(&mut self) {
+        self.tops.get_mut().clear();
+    }
+
+    /// Drops the built rows, so they are built again by [`Self::item`], when
+    /// the data they show changed.
+    pub fn refresh_rows(&mut self) {
+        self.rows.get_mut().clear();
+    }
+
+    /// Returns the tops of the rows of variable heights, summed again if the
+    /// number of rows changed.
+    fn tops(&self, height: &dyn Fn(usize) -> usize) -> Ref<'_, Vec<usize>> {
+        if self.tops.borrow().len() != self.count + 1 {
+            let mut tops = self.tops.borrow_mut();
+            let mut top = 0;
+
+            tops.clear();
+            tops.push(0);
+
+            for row in 0..self.count {
+                top += height(row);
+                tops.push(top);
+            }
+        }
+
+        self.tops.borrow()
+    }
+
+    /// Returns the height of a row.
+    pub fn row_height(&self, row: usize) -> usize {
+        match &self.height {
+            RowHeight::Fixed(height) => *height,
+            RowHeight::Variable(height) => height(row),
+        }
+    }
+
+    /// Returns the distance from the top of the first row to the top of a
+    /// row.
+    pub fn row_top(&self, row: usize) -> usize {
+        match &self.height {
+            RowHeight::Fixed(height) => row * height,
+            RowHeight::Variable(height) => {
+                let tops = self.tops(height.as_ref());
+                tops[row.min(self.count)]
+            }
+        }
+    }
+
+    /// Returns the height of all the rows.
+    pub fn content_height(&self) -> usize {
+        self.row_top(self.count)
+    }
+
+    /// Returns the largest offset for a list of height `height`, showing its
+    /// last row at its bottom.
+    pub fn max_offset(&self, height: usize) -> usize {
+        self.content_height().saturating_sub(height)
+    }
+
+    /// Scrolls the list of height `height` by `delta` pixels, down when
+    /// positive, without going past its first and last rows. Returns whether
+    /// the offset changed.
+    pub fn scroll_by(&mut self, delta: isize, height: usize) -> bool {
+        let offset = (self.offset as isize + delta).clamp(0, self.max_offset(height) as isize) as usize;
+        let changed = offset != self.offset;
+
+        self.offset = offset;
+        changed
+    }
+
+    /// Returns the row at a distance `y` from the top of the list.
+    pub fn row_at(&self, y: isize) -> Option<usize> {
+        let y = self.offset as isize + y;
+
+        if y < 0 {
+            return None;
+        }
+
+        match &self.height {
+            RowHeight::Fixed(0) => None,
+            RowHeight::Fixed(height) => Some(y as usize / height).filter(|&row| row < self.count),
+            RowHeight::Variable(height) => {
+                // The row is the last one starting before the distance.
+                let tops = self.tops(height.as_ref());
+                let row = tops.partition_point(|&top| top <= y as usize) - 1;
+
+                Some(row).filter(|&row| row < self.count)
+            }
+        }
+    }
+
+    /// Returns the rows in a list of height `height`, even partially.
+    pub fn visible_rows(&self, height: usize) -> Range<usize> {
+        let bottom = self.offset + height;
+
+        match &self.height {
+            RowHeight::Fixed(0) => 0..0,
+            RowHeight::Fixed(row_height) => {
+                let first = (self.offset / row_height).min(self.count);
+                let last = bottom.div_ceil(*row_height).min(self.count);
+                first..last
+            }
+            RowHeight::Variable(height) => {
+                // The bottoms of the rows, the first visible row ending after
+                // the offset and the last one reaching the bottom.
+                let tops = self.tops(height.as_ref());
+                let bottoms = &tops[1..];
+                let first = bottoms.partition_point(|&row_bottom| row_bottom <= self.offset);
+                let last = (bottoms.partition_point(|&row_bottom| row_bottom < bottom) + 1).min(self.count);
+
+                first..last.max(first)
+            }
+        }
+    }
+
+    /// Returns the rows built in a list of height `height`: the visible rows
+    /// and the rows of the buffer around them.
+    pub fn built_rows(&self, height: usize) -> Range<usize> {
+        let visible = self.visible_rows(height);
+        visible.start.saturating_sub(self.buffer)..(visible.end + self.buffer).min(self.count)
+    }
+
+    /// Keeps the built rows still in the built rows of a list of height
+    /// `height`, and builds the rows entering them.
+    fn build_rows(&self, height: usize) {
+        let range = self.built_rows(height);
+        let mut rows = self.rows.borrow_mut();
+        let mut kept = rows
+            .drain(..)
+            .filter(|(row, _)| range.contains(row))
+            .peekable();
+
+        let built: Vec<(usize, Box<dyn Widget>)> = range.clone()
+            .map(|row| match kept.next_if(|(kept, _)| *kept == row) {
+                Some(kept) => kept,
+                None => (row, (self.item)(row)),
+            })
+            .collect();
+
+        drop(kept);
+        *rows = built;
+    }
+}
@@ -0,0 +1,160 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::{Point, Size},
+    Align, Direction, Overflow, Padding, themes::{Component, Scale, Spacing, Style, Theme},
+};
+
+use super::{DebugWidget, Label, Layout, Widget};
+
+/// Default height of the items of a menu.
+pub const ITEM_HEIGHT: usize = 24;
+
+/// Menu widget, a list of items to choose from, one of them highlighted.
+///
+/// Opened in a [`Popup`](super::Popup) of the overlay of the view, with the
+/// [size](Menu::size) of its items. Chosen with a
+/// [`menu::Chooser`](crate::controllers::menu::Chooser).
+#[derive(Debug, Clone, Widget)]
+pub struct Menu {
+    /// Independent style for the menu.
+    ///
+    /// If set as `None`, the default menu style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// The text of each item.
+    pub items: Vec<String>,
+    /// The item chosen when the menu is validated with the keyboard.
+    pub highlighted: Option<usize>,
+    /// Height of each item.
+    pub item_height: usize,
+}
+
+impl Widget for Menu {
+    /// Builds the items in rows, the highlighted one over a surface.
+    fn build(&self) -> Box<dyn Widget> {
+        let widgets = self.items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let highlighted = self.highlighted == Some(i);
+
+                Layout {
+                    style: None,
+                    component: match highlighted {
+                        true => Component::MenuHighlight,
+                        false => Component::Layout,
+                    },
+                    overflow: Overflow::Hide,
+                    wx_align: Align::Left,
+                    wy_align: Align::Center,
+                    direction: Direction::Column,
+                    padding: Padding::symmetric(Scale::None, Scale::Small),
+                    gap: Spacing::default(),
+                    widgets: vec![Label {
+                        style: None,
+                        component: match highlighted {
+                            true => Component::MenuHighlight,
+                            false => Component::Menu,
+                        },
+                        text: item.clone(),
+                    }.into()],
+                }
+                .into()
+            })
+            .collect();
+
+        Layout {
+            style: self.style.clone(),
+            component: Component::Menu,
+            overflow: Overflow::Hide,
+            wx_align: Align::Left,
+            wy_align: Align::Top,
+            direction: Direction::Row,
+            padding: Padding::default(),
+            gap: Spacing::default(),
+            widgets,
+        }
+        .into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Menu),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Menu));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+}
+
+impl Menu {
+    /// Creates a menu with an independent style.
+    pub fn styled(style: Style, items: &[&str]) -> Self {
+        Self {
+            style: Some(style),
+            ..Self::normal(items)
+        }
+    }
+
+    /// Creates a menu without independent style, nothing highlighted.
+    pub fn normal(items: &[&str]) -> Self {
+        Self {
+            style: None,
+            items: items.iter().map(|item| item.to_string()).collect(),
+            highlighted: None,
+            item_height: ITEM_HEIGHT,
+        }
+    }
+
+    /// Returns the menu with an item highlighted.
+    pub fn with_highlighted(mut self, highlighted: Option<usize>) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
+    /// Returns the size of a menu of width `width` showing all its items.
+    pub fn size(&self, width: usize) -> Size {
+        [width, self.items.len() * self.item_height]
+    }
+
+    /// Returns the item at a point relative to the top-left corner of the
+    /// menu.
+    pub fn item_at(&self, point: Point) -> Option<usize> {
+        if point[1] < 0 || self.item_height == 0 {
+            return None;
+        }
+
+        let item = point[1] as usize / self.item_height;
+        (item < self.items.len()).then_some(item)
+    }
+
+    /// Highlights the next item, or the previous one, going around. Highlights
+    /// the first or last item when nothing is highlighted.
+    pub fn highlight_next(&mut self, next: bool) {
+        let count = self.items.len();
+
+        if count == 0 {
+            return;
+        }
+
+        self.highlighted = Some(match (self.highlighted, next) {
+            (Some(item), true) => (item + 1) % count,
+            (Some(item), false) => (item + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        });
+    }
+}
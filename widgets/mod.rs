@@ -6,23 +6,67 @@
 
 use any::ToAny;
 
-use crate::{ themes::{Theme, Style} };
+use crate::{ controllers::Controller, graphics::{Matrix, Size}, themes::{State, Theme, Style}, Zone };
 
 mod button;
+mod canvas;
+mod chart;
+mod checkbox;
 mod container;
+mod icon;
 mod image;
 mod label;
 mod layout;
+mod list_view;
+mod menu;
+mod navigation_bar;
+mod path;
+mod popup;
+mod progress_bar;
+mod radio;
+mod select;
+mod slider;
+mod spinner;
+mod stack;
 mod surface;
+mod switch;
+mod table;
+mod tabs;
+mod text_field;
+mod transformed;
+mod tree_view;
 mod view;
+mod weighted;
 
 pub use button::Button;
+pub use canvas::{Canvas, Command, Context, DisplayList, DrawFn};
+pub use chart::{Axis, Chart, Plot, Series, ValueScale, AXIS_HEIGHT, AXIS_WIDTH, LEGEND_HEIGHT};
+pub use checkbox::{Check, Checkbox};
 pub use container::Container;
+pub use icon::{icons, Glyph, Icon};
 pub use image::Image;
 pub use label::Label;
 pub use layout::Layout;
+pub use list_view::{ItemFn, ListView, RowHeight};
+pub use menu::Menu;
+pub use navigation_bar::NavigationBar;
+pub use path::{Path, PathError, Polyline, Segment, Stroke, Vertex, TOLERANCE};
+pub use popup::{Placement, Popup};
+pub use progress_bar::ProgressBar;
+pub use radio::Radio;
+pub use select::Select;
+pub use slider::Slider;
+pub use spinner::Spinner;
+pub use stack::Stack;
 pub use surface::Surface;
+pub use switch::Switch;
+pub use table::{CellFn, Column, ColumnWidth, Part, Sort, Table};
+pub use tabs::Tabs;
+pub use text_field::TextField;
+pub use transformed::Transformed;
+pub use tree_view::{NodePath, TreeNode, TreeView};
 pub use view::View;
+pub use weighted::Weighted;
 
 /// Collection of the functions required to use and build widgets. All widgets 
 /// implement it. 
@@ -49,6 +93,105 @@ pub trait Widget: DebugWidget + ToAny {
     /// style from the global theme. So, the widget's style could only be `None`
     /// between the creation of the widget and the first call of this function.
     fn style_mut(&mut self, theme: &Theme) -> &mut Style;
+
+    /// Returns the transformation matrix of the widget placed in `zone`.
+    /// 
+    /// The matrix is composed with the ones of the parent widgets when 
+    /// building the drawables. Widgets are not transformed by default.
+    fn transform(&self, _zone: Zone) -> Matrix {
+        Matrix::identity()
+    }
+
+    /// Returns the interaction state of the widget.
+    /// 
+    /// The states are tracked by the [`controllers`](crate::controllers), 
+    /// other widgets are always in the normal state. The drawables built by 
+    /// the widget are styled for its state.
+    fn state(&self) -> State {
+        State::Normal
+    }
+
+    /// Returns the widget as a controller, when it is one or when it wraps 
+    /// one. 
+    /// 
+    /// Permits to find the controllers without knowing their types.
+    fn as_controller_mut(&mut self) -> Option<&mut dyn Controller> {
+        None
+    }
+
+    /// Returns the widget wrapped by this widget, when it only changes how 
+    /// the wrapped widget is placed.
+    /// 
+    /// The wrapped widget is built and walked through in place of this 
+    /// widget, like [`Transformed`] and [`Weighted`] do. A controller returns
+    /// the widget wrapped by its controlled widget, the controller itself 
+    /// being found before its widget is unwrapped.
+    fn wrapped(&self) -> Option<&dyn Widget> {
+        None
+    }
+
+    /// Same as [`Widget::wrapped()`] but the returned widget is mutable.
+    fn wrapped_mut(&mut self) -> Option<&mut Box<dyn Widget>> {
+        None
+    }
+
+    /// Returns the number of identifiers taken by the drawables of the 
+    /// widget: its own one, then the ones of its [children](Self::children())
+    /// and of the widgets they contain. 
+    /// 
+    /// The identifiers of the children which are not built are counted too,
+    /// the walk through the controllers skipping as many identifiers as the 
+    /// builder of the drawables. Widgets take one identifier by default.
+    fn id_count(&self) -> usize {
+        1
+    }
+
+    /// Calls `child` with each widget built over the widget in its zone of 
+    /// size `size`, hidden out of it, like the rows of a [`ListView`]. 
+    /// Widgets have no children by default.
+    fn children(&self, _size: Size, _theme: &Theme, _child: &mut dyn FnMut(Child)) {}
+
+    /// Calls `child` with each child which can contain controllers, with its
+    /// identifier counted from the one of the widget, in the order of the 
+    /// identifiers. 
+    /// 
+    /// The controllers are walked through in the children built last by 
+    /// [`Widget::children()`].
+    fn children_mut(&mut self, _child: &mut dyn FnMut(usize, &mut Box<dyn Widget>)) {}
+}
+
+/// Widget built over another widget, in its zone. See [`Widget::children()`].
+#[derive(Debug)]
+pub struct Child<'a> {
+    /// The identifier of the drawables of the child, counted from the one of
+    /// the widget. `0` when the child shares the identifier of the widget.
+    pub id: usize,
+    /// The zone of the child, relative to the position of the widget.
+    pub zone: Zone,
+    /// The child.
+    pub widget: &'a dyn Widget,
+    /// Whether the widgets contained by the child share its identifier, like
+    /// the widgets of a built widget, instead of taking the next identifiers
+    /// like the widgets of a layout.
+    pub shared: bool,
+}
+
+/// Returns the innermost widget wrapped by a widget, or the widget itself. 
+/// 
+/// See [`Widget::wrapped()`].
+pub(crate) fn unwrapped(widget: &dyn Widget) -> &dyn Widget {
+    match widget.wrapped() {
+        Some(wrapped) => unwrapped(wrapped),
+        None => widget,
+    }
+}
+
+/// Same as [`unwrapped()`] but the returned widget is mutable.
+pub(crate) fn unwrapped_mut(widget: &mut dyn Widget) -> &mut dyn Widget {
+    match widget.wrapped().is_some() {
+        true => unwrapped_mut(widget.wrapped_mut().unwrap().as_mut()),
+        false => widget,
+    }
 }
 
 /// Automatically implemented by the macro derives in [`haussmann_dev`].
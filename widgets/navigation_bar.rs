@@ -0,0 +1,163 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::{Point, Size},
+    themes::{Component, Scale, Spacing, Style, Theme},
+    Align,
+    Direction,
+    Overflow,
+    Padding,
+};
+
+use super::{DebugWidget, Label, Layout, Widget};
+
+/// Navigation bar widget, destinations side by side, one of them selected.
+///
+/// The bar is horizontal with [`Direction::Column`], where the widgets of a
+/// layout are placed side by side, and vertical with [`Direction::Row`], as a
+/// navigation rail. The destinations share the length of the bar. Switched by
+/// a [`tabs::Switcher`](crate::controllers::tabs::Switcher).
+#[derive(Debug, Clone, Widget)]
+pub struct NavigationBar {
+    /// Independent style for the bar.
+    ///
+    /// If set as `None`, the default navigation style from the global theme
+    /// will be used.
+    pub style: Option<Style>,
+    /// The text of each destination.
+    pub items: Vec<String>,
+    /// The selected destination.
+    pub selected: usize,
+    /// Whether the bar is horizontal or vertical.
+    pub direction: Direction,
+}
+
+impl Widget for NavigationBar {
+    /// Builds the destinations, the selected one over an indicator.
+    fn build(&self) -> Box<dyn Widget> {
+        self.layout().into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Navigation),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Navigation));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+}
+
+impl NavigationBar {
+    /// Creates a horizontal navigation bar with an independent style.
+    pub fn styled(style: Style, items: &[&str], selected: usize) -> Self {
+        Self {
+            style: Some(style),
+            ..Self::normal(items, selected)
+        }
+    }
+
+    /// Creates a horizontal navigation bar without independent style.
+    pub fn normal(items: &[&str], selected: usize) -> Self {
+        Self {
+            style: None,
+            items: items.iter().map(|item| item.to_string()).collect(),
+            selected,
+            direction: Direction::Column,
+        }
+    }
+
+    /// Creates a vertical navigation bar, a navigation rail, without
+    /// independent style.
+    pub fn rail(items: &[&str], selected: usize) -> Self {
+        Self {
+            direction: Direction::Row,
+            ..Self::normal(items, selected)
+        }
+    }
+
+    /// Selects a destination. Returns whether the selection changed.
+    pub fn select(&mut self, item: usize) -> bool {
+        if item >= self.items.len() || item == self.selected {
+            return false;
+        }
+
+        self.selected = item;
+        true
+    }
+
+    /// Returns the destination at a point relative to the top-left corner of
+    /// a bar of size `size`, none out of the bar.
+    pub fn item_at(&self, point: Point, size: Size) -> Option<usize> {
+        if point[0] < 0 || point[1] < 0 || point[0] as usize >= size[0] || point[1] as usize >= size[1] {
+            return None;
+        }
+
+        let (position, length) = match self.direction {
+            Direction::Column => (point[0] as usize, size[0]),
+            Direction::Row => (point[1] as usize, size[1]),
+        };
+
+        Some(position * self.items.len() / length)
+    }
+
+    /// Returns the layout of the bar: a label with the text of each 
+    /// destination, in a layout drawn as an indicator around the selected one.
+    /// 
+    /// The labels are part of the bar, not controllers: they take the styles
+    /// of the navigation theme, readable on the bar or on the indicator.
+    pub fn layout(&self) -> Layout {
+        let items = self.items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let component = match i == self.selected {
+                    true => Component::NavigationSelected,
+                    false => Component::Navigation,
+                };
+                let label = Label {
+                    style: None,
+                    component,
+                    text: item.clone(),
+                };
+
+                Layout {
+                    style: None,
+                    component,
+                    overflow: Overflow::Hide,
+                    wx_align: Align::Center,
+                    wy_align: Align::Center,
+                    direction: Direction::Column,
+                    padding: Padding::all(Scale::ExtraSmall),
+                    gap: Spacing::default(),
+                    widgets: vec![label.into()],
+                }
+                .into()
+            })
+            .collect();
+
+        Layout {
+            style: self.style.clone(),
+            component: Component::Navigation,
+            overflow: Overflow::Hide,
+            wx_align: Align::Center,
+            wy_align: Align::Center,
+            direction: self.direction.clone(),
+            padding: Padding::default(),
+            gap: Spacing::default(),
+            widgets: items,
+        }
+    }
+}
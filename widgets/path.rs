@@ -0,0 +1,702 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use std::{f32::consts::PI, fmt};
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::{colours::RGBA, Matrix},
+    themes::{Style, Theme},
+    Zone,
+};
+
+use super::{DebugWidget, Widget};
+
+/// Default largest distance between a flattened curve and the real curve.
+pub const TOLERANCE: f32 = 0.25;
+
+/// Point of a [`Path`], in floating coordinates `[x, y]`.
+pub type Vertex = [f32; 2];
+
+/// Segment of a [`Path`], going from the end of the previous segment.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Segment {
+    /// Starts a new subpath at a point.
+    MoveTo(Vertex),
+    /// Straight line to a point.
+    LineTo(Vertex),
+    /// Quadratic Bézier curve to a point, with a control point.
+    QuadTo(Vertex, Vertex),
+    /// Cubic Bézier curve to a point, with two control points.
+    CubicTo(Vertex, Vertex, Vertex),
+    /// Elliptical arc to a point, like the `A` command of the SVG paths.
+    ArcTo {
+        /// The horizontal and vertical radii of the ellipse.
+        radii: [f32; 2],
+        /// The rotation of the ellipse, in degrees.
+        rotation: f32,
+        /// Whether the arc is the largest of the two arcs going to the point.
+        large_arc: bool,
+        /// Whether the arc goes clockwise.
+        sweep: bool,
+        /// The end of the arc.
+        to: Vertex,
+    },
+    /// Straight line back to the start of the subpath, which is closed.
+    Close,
+}
+
+/// Stroke drawn along a [`Path`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stroke {
+    /// Colour of the stroke.
+    pub colour: RGBA,
+    /// Width of the stroke, in pixels.
+    pub width: f32,
+}
+
+/// Flattened subpath of a [`Path`], for the backends only drawing lines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    /// The points, the first one not repeated at the end of a closed
+    /// polyline.
+    pub points: Vec<Vertex>,
+    /// Whether the last point is joined to the first one.
+    pub closed: bool,
+}
+
+impl Polyline {
+    /// Returns the lines between the points, from the last point to the
+    /// first one included when the polyline is closed.
+    pub fn lines(&self) -> impl Iterator<Item = (Vertex, Vertex)> + '_ {
+        let closing = match (self.closed, self.points.first(), self.points.last()) {
+            (true, Some(&first), Some(&last)) if self.points.len() > 2 => Some((last, first)),
+            _ => None,
+        };
+
+        self.points
+            .windows(2)
+            .map(|points| (points[0], points[1]))
+            .chain(closing)
+    }
+}
+
+/// Error returned when SVG path data cannot be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathError {
+    /// A character is not a command of the SVG paths.
+    UnknownCommand {
+        /// The index of the character in the data.
+        index: usize,
+        /// The character.
+        character: char,
+    },
+    /// The data does not start with a move command.
+    MissingMove,
+    /// A command has a missing or malformed number.
+    ExpectedNumber {
+        /// The index in the data where the number was expected.
+        index: usize,
+    },
+    /// An arc has a flag which is neither `0` nor `1`.
+    ExpectedFlag {
+        /// The index in the data where the flag was expected.
+        index: usize,
+    },
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCommand { index, character } => write!(f, "unknown path command `{}` at {}", character, index),
+            Self::MissingMove => write!(f, "path data must start with a move command"),
+            Self::ExpectedNumber { index } => write!(f, "expected a number at {}", index),
+            Self::ExpectedFlag { index } => write!(f, "expected an arc flag, `0` or `1`, at {}", index),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// Path widget, lines and curves filled and stroked, which is not a
+/// "surfaced" widget.
+///
+/// The points are relative to the top-left corner of the zone the path is
+/// placed in, or to the top-left corner of its view box, scaled to the zone,
+/// when it has one. The path is filled following the non-zero rule.
+#[derive(Debug, Clone, PartialEq, Widget)]
+pub struct Path {
+    /// The segments, each subpath starting with a move.
+    pub segments: Vec<Segment>,
+    /// The size of the box scaled to the zone of the path, if any.
+    pub view_box: Option<[f32; 2]>,
+    /// The colour inside the path, if it is filled.
+    pub fill: Option<RGBA>,
+    /// The stroke along the path, if it is stroked.
+    pub stroke: Option<Stroke>,
+}
+
+impl Widget for Path {
+    fn build(&self) -> Box<dyn Widget> {
+        self.clone().into()
+    }
+
+    fn style(&self, _: &Theme) -> Style {
+        panic!("paths have their fill and their stroke as style");
+    }
+
+    fn style_mut(&mut self, _: &Theme) -> &mut Style {
+        panic!("paths have their fill and their stroke as style");
+    }
+}
+
+impl Path {
+    /// Creates a path without segments, neither filled nor stroked.
+    pub fn new() -> Self {
+        Self {
+            segments: vec![],
+            view_box: None,
+            fill: None,
+            stroke: None,
+        }
+    }
+
+    /// Creates a path from SVG path data, like the `d` attribute of an SVG
+    /// path.
+    pub fn parse(data: &str) -> Result<Self, PathError> {
+        Ok(Self {
+            segments: Parser::new(data).parse()?,
+            ..Self::new()
+        })
+    }
+
+    /// Returns the path filled with a colour.
+    pub fn with_fill(mut self, colour: RGBA) -> Self {
+        self.fill = Some(colour);
+        self
+    }
+
+    /// Returns the path stroked with a colour and a width.
+    pub fn with_stroke(mut self, colour: RGBA, width: f32) -> Self {
+        self.stroke = Some(Stroke { colour, width });
+        self
+    }
+
+    /// Returns the path with a box scaled to its zone.
+    pub fn with_view_box(mut self, view_box: [f32; 2]) -> Self {
+        self.view_box = Some(view_box);
+        self
+    }
+
+    /// Starts a new subpath.
+    pub fn move_to(mut self, to: Vertex) -> Self {
+        self.segments.push(Segment::MoveTo(to));
+        self
+    }
+
+    /// Adds a straight line.
+    pub fn line_to(mut self, to: Vertex) -> Self {
+        self.segments.push(Segment::LineTo(to));
+        self
+    }
+
+    /// Adds a quadratic Bézier curve.
+    pub fn quad_to(mut self, control: Vertex, to: Vertex) -> Self {
+        self.segments.push(Segment::QuadTo(control, to));
+        self
+    }
+
+    /// Adds a cubic Bézier curve.
+    pub fn cubic_to(mut self, first: Vertex, second: Vertex, to: Vertex) -> Self {
+        self.segments.push(Segment::CubicTo(first, second, to));
+        self
+    }
+
+    /// Adds an elliptical arc, like the `A` command of the SVG paths.
+    pub fn arc_to(mut self, radii: [f32; 2], rotation: f32, large_arc: bool, sweep: bool, to: Vertex) -> Self {
+        self.segments.push(Segment::ArcTo { radii, rotation, large_arc, sweep, to });
+        self
+    }
+
+    /// Closes the current subpath.
+    pub fn close(mut self) -> Self {
+        self.segments.push(Segment::Close);
+        self
+    }
+
+    /// Returns the path with its points transformed by a matrix. The arcs
+    /// become cubic Bézier curves, so they are transformed exactly.
+    pub fn transformed(&self, matrix: &Matrix) -> Self {
+        let mut segments = vec![];
+        let mut current = [0.0, 0.0];
+        let mut start = [0.0, 0.0];
+
+        for segment in &self.segments {
+            match *segment {
+                Segment::MoveTo(to) => {
+                    start = to;
+                    current = to;
+                    segments.push(Segment::MoveTo(matrix.apply(to)));
+                }
+                Segment::LineTo(to) => {
+                    current = to;
+                    segments.push(Segment::LineTo(matrix.apply(to)));
+                }
+                Segment::QuadTo(control, to) => {
+                    current = to;
+                    segments.push(Segment::QuadTo(matrix.apply(control), matrix.apply(to)));
+                }
+                Segment::CubicTo(first, second, to) => {
+                    current = to;
+                    segments.push(Segment::CubicTo(matrix.apply(first), matrix.apply(second), matrix.apply(to)));
+                }
+                Segment::ArcTo { radii, rotation, large_arc, sweep, to } => {
+                    for [first, second, to] in arc_to_cubics(current, radii, rotation, large_arc, sweep, to) {
+                        segments.push(Segment::CubicTo(matrix.apply(first), matrix.apply(second), matrix.apply(to)));
+                    }
+                    current = to;
+                }
+                Segment::Close => {
+                    current = start;
+                    segments.push(Segment::Close);
+                }
+            }
+        }
+
+        Self {
+            segments,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the matrix placing the points of the path in a zone: scaled
+    /// from its view box, if any, then moved to the zone.
+    pub fn matrix_in(&self, zone: Zone) -> Matrix {
+        let translation = Matrix::translation(zone.x() as f32, zone.y() as f32);
+
+        match self.view_box {
+            Some([width, height]) if width > 0.0 && height > 0.0 => translation * Matrix::scaling(
+                zone.width() as f32 / width,
+                zone.height() as f32 / height,
+            ),
+            _ => translation,
+        }
+    }
+
+    /// Flattens the path into polylines, one for each subpath, the curves
+    /// approximated by lines not further than `tolerance` from them.
+    pub fn flatten(&self, tolerance: f32) -> Vec<Polyline> {
+        let tolerance = tolerance.max(f32::EPSILON);
+        let mut polylines: Vec<Polyline> = vec![];
+        let mut current = [0.0, 0.0];
+
+        for segment in &self.segments {
+            // A segment drawn without a subpath starts one at the current
+            // point.
+            if !matches!(segment, Segment::MoveTo(_)) && polylines.last().is_none_or(|polyline| polyline.closed) {
+                polylines.push(Polyline {
+                    points: vec![current],
+                    closed: false,
+                });
+            }
+
+            let points = match *segment {
+                Segment::MoveTo(to) => {
+                    polylines.push(Polyline {
+                        points: vec![to],
+                        closed: false,
+                    });
+                    current = to;
+                    continue;
+                }
+                Segment::LineTo(to) => vec![to],
+                Segment::QuadTo(control, to) => {
+                    let count = (distance(current, control, to).sqrt() / (4.0 * tolerance).sqrt()).ceil();
+                    subdivide(count, |t| quad_at(current, control, to, t))
+                }
+                Segment::CubicTo(first, second, to) => {
+                    let deviation = distance(current, first, second).max(distance(first, second, to));
+                    let count = (0.75 * deviation / tolerance).sqrt().ceil();
+                    subdivide(count, |t| cubic_at(current, first, second, to, t))
+                }
+                Segment::ArcTo { radii, rotation, large_arc, sweep, to } => {
+                    arc_to_cubics(current, radii, rotation, large_arc, sweep, to)
+                        .into_iter()
+                        .scan(current, |from, [first, second, to]| {
+                            let deviation = distance(*from, first, second).max(distance(first, second, to));
+                            let count = (0.75 * deviation / tolerance).sqrt().ceil();
+                            let from_point = *from;
+                            *from = to;
+                            Some(subdivide(count, move |t| cubic_at(from_point, first, second, to, t)))
+                        })
+                        .flatten()
+                        .collect()
+                }
+                Segment::Close => {
+                    let polyline = polylines.last_mut().unwrap();
+                    polyline.closed = true;
+                    current = polyline.points[0];
+                    continue;
+                }
+            };
+
+            current = *points.last().unwrap_or(&current);
+            polylines.last_mut().unwrap().points.extend(points);
+        }
+
+        polylines
+    }
+
+    /// Flattens the path placed in a zone, like [`Self::flatten()`]. The
+    /// points are the ones to draw, before the transformation of the
+    /// drawable.
+    pub fn polylines_in(&self, zone: Zone, tolerance: f32) -> Vec<Polyline> {
+        let matrix = self.matrix_in(zone);
+        // The tolerance is in pixels, the flattening in the units of the
+        // view box.
+        let scale = matrix.a.abs().max(matrix.d.abs()).max(f32::EPSILON);
+
+        self.flatten(tolerance / scale)
+            .into_iter()
+            .map(|polyline| Polyline {
+                points: polyline.points.iter().map(|&point| matrix.apply(point)).collect(),
+                ..polyline
+            })
+            .collect()
+    }
+}
+
+impl Default for Path {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns `count` points of a curve, at the end of each equal part of its
+/// parameter.
+fn subdivide(count: f32, point_at: impl Fn(f32) -> Vertex) -> Vec<Vertex> {
+    let count = count.clamp(1.0, 1024.0) as usize;
+    (1..=count).map(|i| point_at(i as f32 / count as f32)).collect()
+}
+
+/// Returns how far a control point is from the middle of its neighbours,
+/// doubled, which bounds how far a curve is from its chord.
+fn distance(previous: Vertex, control: Vertex, next: Vertex) -> f32 {
+    let x = previous[0] - 2.0 * control[0] + next[0];
+    let y = previous[1] - 2.0 * control[1] + next[1];
+    (x * x + y * y).sqrt()
+}
+
+/// Returns the point of a quadratic Bézier curve at a parameter.
+fn quad_at(from: Vertex, control: Vertex, to: Vertex, t: f32) -> Vertex {
+    let u = 1.0 - t;
+    [0, 1].map(|i| u * u * from[i] + 2.0 * u * t * control[i] + t * t * to[i])
+}
+
+/// Returns the point of a cubic Bézier curve at a parameter.
+fn cubic_at(from: Vertex, first: Vertex, second: Vertex, to: Vertex, t: f32) -> Vertex {
+    let u = 1.0 - t;
+    [0, 1].map(|i| {
+        u * u * u * from[i] + 3.0 * u * u * t * first[i] + 3.0 * u * t * t * second[i] + t * t * t * to[i]
+    })
+}
+
+/// Returns the cubic Bézier curves approximating an arc, each of a quarter
+/// of a turn at most, as their two control points and their end.
+///
+/// An arc without radius is a straight line, and the radii too small to go to
+/// the end of the arc are scaled up, like the SVG paths do.
+fn arc_to_cubics(
+    from: Vertex,
+    radii: [f32; 2],
+    rotation: f32,
+    large_arc: bool,
+    sweep: bool,
+    to: Vertex,
+) -> Vec<[Vertex; 3]> {
+    let [mut rx, mut ry] = radii.map(f32::abs);
+
+    if from == to {
+        return vec![];
+    }
+
+    if rx == 0.0 || ry == 0.0 {
+        return vec![[from, to, to]];
+    }
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+
+    // The middle of the chord, in the axes of the ellipse.
+    let dx = (from[0] - to[0]) / 2.0;
+    let dy = (from[1] - to[1]) / 2.0;
+    let x = cos * dx + sin * dy;
+    let y = -sin * dx + cos * dy;
+
+    let scale = (x * x) / (rx * rx) + (y * y) / (ry * ry);
+    if scale > 1.0 {
+        rx *= scale.sqrt();
+        ry *= scale.sqrt();
+    }
+
+    // The centre of the ellipse, in its axes then in the coordinates of the
+    // path.
+    let numerator = (rx * rx * ry * ry - rx * rx * y * y - ry * ry * x * x).max(0.0);
+    let denominator = rx * rx * y * y + ry * ry * x * x;
+    let mut factor = (numerator / denominator).sqrt();
+    if large_arc == sweep {
+        factor = -factor;
+    }
+
+    let cx_axes = factor * rx * y / ry;
+    let cy_axes = -factor * ry * x / rx;
+    let cx = cos * cx_axes - sin * cy_axes + (from[0] + to[0]) / 2.0;
+    let cy = sin * cx_axes + cos * cy_axes + (from[1] + to[1]) / 2.0;
+
+    let angle = |ux: f32, uy: f32| uy.atan2(ux);
+    let start = angle((x - cx_axes) / rx, (y - cy_axes) / ry);
+    let end = angle((-x - cx_axes) / rx, (-y - cy_axes) / ry);
+
+    let mut sweep_angle = end - start;
+    if sweep && sweep_angle < 0.0 {
+        sweep_angle += 2.0 * PI;
+    } else if !sweep && sweep_angle > 0.0 {
+        sweep_angle -= 2.0 * PI;
+    }
+
+    let count = (sweep_angle.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+    let step = sweep_angle / count as f32;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+
+    // Point and derivative of the ellipse at an angle.
+    let point = |theta: f32| -> Vertex {
+        let (s, c) = theta.sin_cos();
+        [cx + rx * c * cos - ry * s * sin, cy + rx * c * sin + ry * s * cos]
+    };
+    let tangent = |theta: f32| -> Vertex {
+        let (s, c) = theta.sin_cos();
+        [-rx * s * cos - ry * c * sin, -rx * s * sin + ry * c * cos]
+    };
+
+    (0..count)
+        .map(|i| {
+            let theta1 = start + step * i as f32;
+            let theta2 = theta1 + step;
+            let (p1, d1) = (point(theta1), tangent(theta1));
+            let (d2, p2) = (tangent(theta2), match i + 1 == count {
+                true => to,
+                false => point(theta2),
+            });
+
+            [
+                [p1[0] + k * d1[0], p1[1] + k * d1[1]],
+                [p2[0] - k * d2[0], p2[1] - k * d2[1]],
+                p2,
+            ]
+        })
+        .collect()
+}
+
+/// Parser of SVG path data.
+struct Parser<'a> {
+    /// The data.
+    data: &'a [u8],
+    /// The index of the next character.
+    index: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(data: &'a str) -> Self {
+        Self {
+            data: data.as_bytes(),
+            index: 0,
+        }
+    }
+
+    /// Parses the whole data into absolute segments.
+    fn parse(mut self) -> Result<Vec<Segment>, PathError> {
+        let mut segments = vec![];
+        let mut current: Vertex = [0.0, 0.0];
+        let mut start: Vertex = [0.0, 0.0];
+        // The last control point, reflected by the smooth curves.
+        let mut control: Option<(u8, Vertex)> = None;
+        let mut command: Option<u8> = None;
+
+        loop {
+            self.skip_separators();
+
+            let Some(&character) = self.data.get(self.index) else {
+                break;
+            };
+
+            // A command, or the numbers of the previous command repeated.
+            if character.is_ascii_alphabetic() {
+                if !b"MmLlHhVvCcSsQqTtAaZz".contains(&character) {
+                    return Err(PathError::UnknownCommand {
+                        index: self.index,
+                        character: character as char,
+                    });
+                }
+
+                self.index += 1;
+                command = Some(character);
+            } else if command.is_none() {
+                return Err(PathError::MissingMove);
+            }
+
+            let command_char = command.unwrap();
+
+            if segments.is_empty() && !matches!(command_char, b'M' | b'm') {
+                return Err(PathError::MissingMove);
+            }
+
+            let relative = command_char.is_ascii_lowercase();
+            let origin = if relative { current } else { [0.0, 0.0] };
+            let at = |point: Vertex| [origin[0] + point[0], origin[1] + point[1]];
+
+            let segment = match command_char.to_ascii_uppercase() {
+                b'M' => {
+                    let to = at(self.pair()?);
+                    start = to;
+                    // The next pairs are lines.
+                    command = Some(if relative { b'l' } else { b'L' });
+                    Segment::MoveTo(to)
+                }
+                b'L' => Segment::LineTo(at(self.pair()?)),
+                b'H' => {
+                    let x = self.number()? + origin[0];
+                    Segment::LineTo([x, current[1]])
+                }
+                b'V' => {
+                    let y = self.number()? + origin[1];
+                    Segment::LineTo([current[0], y])
+                }
+                b'C' => Segment::CubicTo(at(self.pair()?), at(self.pair()?), at(self.pair()?)),
+                b'S' => {
+                    let first = reflect(control, b'C', current);
+                    Segment::CubicTo(first, at(self.pair()?), at(self.pair()?))
+                }
+                b'Q' => Segment::QuadTo(at(self.pair()?), at(self.pair()?)),
+                b'T' => Segment::QuadTo(reflect(control, b'Q', current), at(self.pair()?)),
+                b'A' => {
+                    let rx = self.number()?;
+                    let ry = self.number()?;
+                    let rotation = self.number()?;
+                    let large_arc = self.flag()?;
+                    let sweep = self.flag()?;
+
+                    Segment::ArcTo {
+                        radii: [rx, ry],
+                        rotation,
+                        large_arc,
+                        sweep,
+                        to: at(self.pair()?),
+                    }
+                }
+                _ => {
+                    // Closes the subpath, a new command is expected.
+                    command = None;
+                    Segment::Close
+                }
+            };
+
+            (current, control) = match segment {
+                Segment::MoveTo(to) | Segment::LineTo(to) | Segment::ArcTo { to, .. } => (to, None),
+                Segment::QuadTo(first, to) => (to, Some((b'Q', first))),
+                Segment::CubicTo(_, second, to) => (to, Some((b'C', second))),
+                Segment::Close => (start, None),
+            };
+
+            segments.push(segment);
+        }
+
+        Ok(segments)
+    }
+
+    /// Skips the spaces and the commas.
+    fn skip_separators(&mut self) {
+        while self.data.get(self.index).is_some_and(|c| c.is_ascii_whitespace() || *c == b',') {
+            self.index += 1;
+        }
+    }
+
+    /// Parses a number, like `-1.5`, `.5` or `2e-3`.
+    fn number(&mut self) -> Result<f32, PathError> {
+        self.skip_separators();
+
+        let start = self.index;
+        let digits = |parser: &mut Self| {
+            let from = parser.index;
+            while parser.data.get(parser.index).is_some_and(u8::is_ascii_digit) {
+                parser.index += 1;
+            }
+            parser.index > from
+        };
+
+        if matches!(self.data.get(self.index), Some(b'+' | b'-')) {
+            self.index += 1;
+        }
+
+        let mut any = digits(self);
+
+        if self.data.get(self.index) == Some(&b'.') {
+            self.index += 1;
+            any |= digits(self);
+        }
+
+        if !any {
+            return Err(PathError::ExpectedNumber { index: start });
+        }
+
+        if matches!(self.data.get(self.index), Some(b'e' | b'E')) {
+            let exponent = self.index;
+            self.index += 1;
+
+            if matches!(self.data.get(self.index), Some(b'+' | b'-')) {
+                self.index += 1;
+            }
+
+            // Not an exponent, like in `1e` followed by another command.
+            if !digits(self) {
+                self.index = exponent;
+            }
+        }
+
+        std::str::from_utf8(&self.data[start..self.index])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or(PathError::ExpectedNumber { index: start })
+    }
+
+    /// Parses a pair of numbers.
+    fn pair(&mut self) -> Result<Vertex, PathError> {
+        Ok([self.number()?, self.number()?])
+    }
+
+    /// Parses a flag of an arc, a single `0` or `1`.
+    fn flag(&mut self) -> Result<bool, PathError> {
+        self.skip_separators();
+
+        let flag = match self.data.get(self.index) {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(PathError::ExpectedFlag { index: self.index }),
+        };
+
+        self.index += 1;
+        Ok(flag)
+    }
+}
+
+/// Returns the first control point of a smooth curve: the last control point
+/// reflected around the current point when the previous segment is the same
+/// kind of curve, otherwise the current point.
+fn reflect(control: Option<(u8, Vertex)>, kind: u8, current: Vertex) -> Vertex {
+    match control {
+        Some((previous, point)) if previous == kind => [2.0 * current[0] - point[0], 2.0 * current[1] - point[1]],
+        _ => current,
+    }
+}
@@ -0,0 +1,172 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use crate::{graphics::Size, Widget, Zone};
+
+/// Side of the anchor where a [`Popup`] is placed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum Placement {
+    /// Under the anchor, aligned on its left side.
+    #[default]
+    Below,
+    /// Over the anchor, aligned on its left side.
+    Above,
+    /// At the right of the anchor, aligned on its top side.
+    Right,
+    /// At the left of the anchor, aligned on its top side.
+    Left,
+    /// In the middle of the view, without anchor.
+    Centre,
+    /// At the bottom of the view, in the middle of its width, without anchor.
+    Bottom,
+}
+
+/// Space between a popup placed at the bottom of the view and the bottom
+/// edge.
+const MARGIN: isize = 16;
+
+/// Widget opened over the other widgets of a [`View`](super::View), in its
+/// overlay, like a menu.
+///
+/// The popup is placed next to its anchor, which is the zone of the widget
+/// opening it. It goes to the other side of the anchor when it does not fit
+/// in the view on its side and there is more space on the other side, and it
+/// is moved to stay in the view. It is not hidden by the layouts hiding their
+/// overflowing widgets.
+///
+/// Pressing out of a dismissible popup closes it. A modal popup is drawn over
+/// a barrier covering the view, which blocks the pointer events to the
+/// widgets under it.
+#[derive(Debug)]
+pub struct Popup {
+    /// The zone the popup is placed next to.
+    pub anchor: Zone,
+    /// The side of the anchor where the popup is placed.
+    pub placement: Placement,
+    /// The size of the popup.
+    pub size: Size,
+    /// The widget drawn in the popup.
+    pub widget: Box<dyn Widget>,
+    /// Whether the popup is drawn over a barrier blocking the widgets under
+    /// it.
+    pub modal: bool,
+    /// Whether pressing out of the popup closes it.
+    pub dismissible: bool,
+    /// The identifier of the controller which opened the popup, told when it
+    /// is closed.
+    pub(crate) owner: Option<usize>,
+    /// The identifier of the controller focused before the popup took the 
+    /// focus, which gets it back when the popup is closed.
+    pub(crate) focus_back: Option<usize>,
+}
+
+impl Popup {
+    /// Creates a new dismissible popup placed next to an anchor.
+    pub fn new<T: Widget + 'static>(anchor: Zone, placement: Placement, size: Size, widget: T) -> Self {
+        Self {
+            anchor,
+            placement,
+            size,
+            widget: Box::new(widget),
+            modal: false,
+            dismissible: true,
+            owner: None,
+            focus_back: None,
+        }
+    }
+
+    /// Makes the popup modal, and not dismissible.
+    pub fn modal(mut self) -> Self {
+        self.modal = true;
+        self.dismissible = false;
+        self
+    }
+
+    /// Changes whether pressing out of the popup closes it.
+    pub fn with_dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    /// Returns the zone of the popup in the zone of a view.
+    pub fn zone_in(&self, view: Zone) -> Zone {
+        let anchor = self.anchor;
+        let width = self.size[0] as isize;
+        let height = self.size[1] as isize;
+
+        let horizontal = Span::new(anchor.x(), anchor.width(), view.x(), view.width());
+        let vertical = Span::new(anchor.y(), anchor.height(), view.y(), view.height());
+
+        let (x, y) = match self.placement {
+            Placement::Below => (horizontal.align(width), vertical.place(height, true)),
+            Placement::Above => (horizontal.align(width), vertical.place(height, false)),
+            Placement::Right => (horizontal.place(width, true), vertical.align(height)),
+            Placement::Left => (horizontal.place(width, false), vertical.align(height)),
+            Placement::Centre => (horizontal.centre(width), vertical.centre(height)),
+            Placement::Bottom => (horizontal.centre(width), vertical.view_end - height - MARGIN),
+        };
+
+        Zone {
+            position: [horizontal.keep(x, width), vertical.keep(y, height)],
+            size: self.size,
+        }
+    }
+}
+
+/// The anchor and the view along an axis.
+struct Span {
+    start: isize,
+    end: isize,
+    view_start: isize,
+    view_end: isize,
+}
+
+impl Span {
+    fn new(start: isize, length: usize, view_start: isize, view_length: usize) -> Self {
+        Self {
+            start,
+            end: start + length as isize,
+            view_start,
+            view_end: view_start + view_length as isize,
+        }
+    }
+
+    /// Returns the start of a popup of length `length` placed after the
+    /// anchor, or before it. It goes to the other side when it does not fit
+    /// and there is more space there.
+    fn place(&self, length: isize, after: bool) -> isize {
+        let space_after = self.view_end - self.end;
+        let space_before = self.start - self.view_start;
+
+        let after = match after {
+            true => length <= space_after || space_after >= space_before,
+            false => length > space_before && space_after > space_before,
+        };
+
+        match after {
+            true => self.end,
+            false => self.start - length,
+        }
+    }
+
+    /// Returns the start of a popup of length `length` aligned on the start
+    /// of the anchor, or on its end when it does not fit.
+    fn align(&self, length: isize) -> isize {
+        match self.start + length <= self.view_end {
+            true => self.start,
+            false => self.end - length,
+        }
+    }
+
+    /// Returns the start of a popup of length `length` in the middle of the
+    /// view.
+    fn centre(&self, length: isize) -> isize {
+        self.view_start + (self.view_end - self.view_start - length) / 2
+    }
+
+    /// Moves the start of a popup of length `length` to keep it in the view.
+    fn keep(&self, start: isize, length: isize) -> isize {
+        start.min(self.view_end - length).max(self.view_start)
+    }
+}
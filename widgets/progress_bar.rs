@@ -0,0 +1,143 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use std::time::Duration;
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    Align, Direction, Overflow, Padding, themes::{Component, Spacing, Style, Theme},
+};
+
+use super::{DebugWidget, Layout, Surface, Weighted, Widget};
+
+/// Time taken by the indicator of an indeterminate progress to go through the
+/// track.
+pub const PERIOD: Duration = Duration::from_millis(1500);
+
+/// Length of the indicator of an indeterminate progress, in fraction of the
+/// track.
+const INDETERMINATE_LENGTH: f32 = 0.3;
+
+/// Progress bar widget, showing the progress of a task.
+///
+/// A determinate progress is filled from the start of the track. An
+/// indeterminate progress has an indicator going through the track, moved
+/// with [`ProgressBar::advance()`]. The bar is horizontal with
+/// [`Direction::Column`] and vertical with [`Direction::Row`], filled from
+/// the bottom.
+#[derive(Debug, Clone, Widget)]
+pub struct ProgressBar {
+    /// Independent style for the track.
+    ///
+    /// If set as `None`, the default track style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// The progress between `0.0` and `1.0`, or `None` when indeterminate.
+    pub value: Option<f32>,
+    /// Whether the bar is horizontal or vertical.
+    pub direction: Direction,
+    /// Position of the indicator of an indeterminate progress, between `0.0`
+    /// and `1.0`.
+    pub phase: f32,
+}
+
+impl Widget for ProgressBar {
+    /// Builds the track with the indicator and the empty parts as weighted
+    /// surfaces.
+    fn build(&self) -> Box<dyn Widget> {
+        let indicator = |weight: f32| Weighted::new(weight, Surface {
+            style: None,
+            component: Component::Indicator,
+        });
+        let empty = |weight: f32| Weighted::new(weight, Surface {
+            style: None,
+            component: Component::Layout,
+        });
+
+        let mut widgets: Vec<Box<dyn Widget>> = match self.value {
+            Some(value) => {
+                let value = value.clamp(0.0, 1.0);
+                vec![indicator(value).into(), empty(1.0 - value).into()]
+            }
+            None => {
+                let space = 1.0 - INDETERMINATE_LENGTH;
+
+                vec![
+                    empty(self.phase * space).into(),
+                    indicator(INDETERMINATE_LENGTH).into(),
+                    empty((1.0 - self.phase) * space).into(),
+                ]
+            }
+        };
+
+        // A vertical bar is filled from the bottom.
+        if self.direction == Direction::Row {
+            widgets.reverse();
+        }
+
+        Layout {
+            style: self.style.clone(),
+            component: Component::Track,
+            overflow: Overflow::Hide,
+            wx_align: Align::Center,
+            wy_align: Align::Center,
+            direction: self.direction.clone(),
+            padding: Padding::default(),
+            gap: Spacing::default(),
+            widgets,
+        }
+        .into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Track),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Track));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+}
+
+impl ProgressBar {
+    /// Creates a horizontal bar for a determinate progress, between `0.0`
+    /// and `1.0`.
+    pub fn determinate(value: f32) -> Self {
+        Self {
+            style: None,
+            value: Some(value),
+            direction: Direction::Column,
+            phase: 0.0,
+        }
+    }
+
+    /// Creates a horizontal bar for an indeterminate progress.
+    pub fn indeterminate() -> Self {
+        Self {
+            value: None,
+            ..Self::determinate(0.0)
+        }
+    }
+
+    /// Makes the bar vertical, filled from the bottom.
+    pub fn vertical(mut self) -> Self {
+        self.direction = Direction::Row;
+        self
+    }
+
+    /// Moves the indicator of an indeterminate progress following the time
+    /// passed, going back to the start after [`PERIOD`].
+    pub fn advance(&mut self, elapsed: Duration) {
+        self.phase = (self.phase + elapsed.as_secs_f32() / PERIOD.as_secs_f32()).fract();
+    }
+}
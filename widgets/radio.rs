@@ -0,0 +1,107 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    controllers::toggle::Toggle,
+    widgets,
+    Align, Direction, Overflow, Padding, themes::{Component, Scale, Spacing, Style, Theme},
+};
+
+use super::{DebugWidget, Layout, Surface, Widget};
+
+/// Radio button widget, selected or not.
+///
+/// Only one radio of a group is selected, the radios are grouped by wrapping
+/// their layout in a [`toggle::Group`](crate::controllers::toggle::Group).
+/// Fills the zone it is placed in, wrap it in a
+/// [`Container`](super::Container) to give it a size.
+#[derive(Debug, Clone, Widget)]
+pub struct Radio {
+    /// Independent style for the radio.
+    ///
+    /// If set as `None`, the default toggle style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    #[allow(missing_docs)]
+    pub selected: bool,
+}
+
+impl Widget for Radio {
+    /// Builds the button, with a dot in the middle when selected.
+    fn build(&self) -> Box<dyn Widget> {
+        Layout {
+            style: self.style.clone(),
+            component: self.component(),
+            overflow: Overflow::Hide,
+            wx_align: Align::Center,
+            wy_align: Align::Center,
+            direction: Direction::Column,
+            padding: Padding::all(Scale::Small),
+            gap: Spacing::default(),
+            widgets: match self.selected {
+                true => widgets![Surface {
+                    style: None,
+                    component: Component::ToggleMark,
+                }],
+                false => vec![],
+            },
+        }
+        .into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(self.component()),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(self.component()));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+}
+
+impl Toggle for Radio {
+    /// Selects the radio. A radio is deselected by selecting another radio of
+    /// its group.
+    fn toggle(&mut self) -> bool {
+        let changed = !self.selected;
+        self.selected = true;
+        changed
+    }
+}
+
+impl Radio {
+    /// Creates a new radio with an independent style.
+    pub fn styled(style: Style, selected: bool) -> Self {
+        Self {
+            style: Some(style),
+            selected,
+        }
+    }
+
+    /// Creates a radio without independent style.
+    pub fn normal(selected: bool) -> Self {
+        Self {
+            style: None,
+            selected,
+        }
+    }
+
+    /// Returns the component the button is drawn for, following the value.
+    fn component(&self) -> Component {
+        match self.selected {
+            true => Component::ToggleSelected,
+            false => Component::Toggle,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    Align, Direction, Overflow, Padding, themes::{Component, Scale, Spacing, Style, Theme},
+};
+
+use super::{Container, DebugWidget, Label, Layout, Menu, Widget};
+
+/// Size of the arrow of a select.
+const ARROW_SIZE: usize = 16;
+
+/// Select widget, showing the option chosen among a list of options.
+///
+/// Opened by a [`menu::Dropdown`](crate::controllers::menu::Dropdown), which
+/// shows the options in a [`Menu`] under the select.
+#[derive(Debug, Clone, Widget)]
+pub struct Select {
+    /// Independent style for the select.
+    ///
+    /// If set as `None`, the default input style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// The text of each option.
+    pub options: Vec<String>,
+    /// The chosen option.
+    pub selected: Option<usize>,
+    /// The text shown while no option is chosen.
+    pub placeholder: String,
+    /// Whether the options are shown.
+    pub open: bool,
+}
+
+impl Widget for Select {
+    /// Builds the chosen option, or the placeholder, with an arrow showing
+    /// whether the options are shown.
+    fn build(&self) -> Box<dyn Widget> {
+        let label = match self.selected.and_then(|selected| self.options.get(selected)) {
+            Some(option) => Label {
+                style: None,
+                component: Component::Input,
+                text: option.clone(),
+            },
+            None => Label {
+                style: None,
+                component: Component::Placeholder,
+                text: self.placeholder.clone(),
+            },
+        };
+
+        let arrow = Label {
+            style: None,
+            component: Component::Input,
+            text: if self.open { "▴" } else { "▾" }.to_string(),
+        };
+
+        Layout {
+            style: self.style.clone(),
+            component: Component::Input,
+            overflow: Overflow::Hide,
+            wx_align: Align::Left,
+            wy_align: Align::Center,
+            direction: Direction::Column,
+            padding: Padding::symmetric(Scale::ExtraSmall, Scale::Small),
+            gap: Spacing::default(),
+            widgets: vec![label.into(), Container::new([ARROW_SIZE, ARROW_SIZE], arrow).into()],
+        }
+        .into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Input),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Input));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+}
+
+impl Select {
+    /// Creates a select with an independent style.
+    pub fn styled(style: Style, options: &[&str], selected: Option<usize>) -> Self {
+        Self {
+            style: Some(style),
+            ..Self::normal(options, selected)
+        }
+    }
+
+    /// Creates a select without independent style.
+    pub fn normal(options: &[&str], selected: Option<usize>) -> Self {
+        Self {
+            style: None,
+            options: options.iter().map(|option| option.to_string()).collect(),
+            selected,
+            placeholder: String::new(),
+            open: false,
+        }
+    }
+
+    /// Returns the select with a text shown while no option is chosen.
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    /// Returns the menu of the options, the chosen one highlighted.
+    pub fn menu(&self) -> Menu {
+        Menu {
+            style: None,
+            items: self.options.clone(),
+            highlighted: self.selected,
+            item_height: super::menu::ITEM_HEIGHT,
+        }
+    }
+}
@@ -0,0 +1,302 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::Point,
+    widgets,
+    Align, Direction, Overflow, Padding, themes::{Component, Spacing, Style, Theme},
+};
+
+use super::{Container, DebugWidget, Layout, Surface, Weighted, Widget};
+
+/// Default size of the thumbs of a slider.
+pub const THUMB_SIZE: usize = 16;
+
+/// Slider widget, to choose a value or a range of values between a minimum
+/// and a maximum, continuous or by steps.
+///
+/// The slider has one thumb for a value, or two thumbs for a range. It is
+/// horizontal with [`Direction::Column`], where the widgets of a layout are
+/// placed side by side, and vertical with [`Direction::Row`]. A vertical
+/// slider has its minimum at the bottom.
+///
+/// The track is a third of the thickness of the slider, wrap the slider in a
+/// [`Container`] as thick as its thumbs. Moved by a
+/// [`slide::Dragger`](crate::controllers::slide::Dragger).
+#[derive(Debug, Clone, Widget)]
+pub struct Slider {
+    /// Independent style for the track.
+    ///
+    /// If set as `None`, the default track style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// The smallest value.
+    pub min: f32,
+    /// The largest value.
+    pub max: f32,
+    /// The difference between two successive values, continuous when `None`.
+    pub step: Option<f32>,
+    /// The value of each thumb, in increasing order, never empty.
+    values: Vec<f32>,
+    /// Whether the slider is horizontal or vertical.
+    pub direction: Direction,
+    /// Size of the thumbs.
+    pub thumb_size: usize,
+}
+
+impl Widget for Slider {
+    /// Builds the segments of the track between the thumbs, the segment
+    /// between the two thumbs of a range or before the thumb of a value is
+    /// drawn as an indicator.
+    fn build(&self) -> Box<dyn Widget> {
+        let mut widgets: Vec<Box<dyn Widget>> = vec![];
+        let mut previous = 0.0;
+
+        for (i, value) in self.values.iter().enumerate() {
+            let fraction = self.fraction(*value);
+            let selected = (self.values.len() == 1 && i == 0) || i == 1;
+
+            widgets.push(self.segment(fraction - previous, selected).into());
+            widgets.push(Container::new([self.thumb_size, self.thumb_size], Surface {
+                style: None,
+                component: Component::Thumb,
+            }).into());
+
+            previous = fraction;
+        }
+
+        widgets.push(self.segment(1.0 - previous, false).into());
+
+        // The minimum is at the bottom of a vertical slider.
+        if self.direction == Direction::Row {
+            widgets.reverse();
+        }
+
+        Layout {
+            style: None,
+            component: Component::Layout,
+            overflow: Overflow::Ignore,
+            wx_align: Align::Center,
+            wy_align: Align::Center,
+            direction: self.direction.clone(),
+            padding: Padding::default(),
+            gap: Spacing::default(),
+            widgets,
+        }
+        .into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Track),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Track));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+}
+
+impl Slider {
+    /// Creates a horizontal slider for a value, with an independent style
+    /// for the track.
+    pub fn styled(style: Style, min: f32, max: f32, value: f32) -> Self {
+        Self {
+            style: Some(style),
+            ..Self::normal(min, max, value)
+        }
+    }
+
+    /// Creates a horizontal slider for a value, without independent style.
+    ///
+    /// A maximum under the minimum keeps the value at the minimum.
+    pub fn normal(min: f32, max: f32, value: f32) -> Self {
+        Self {
+            style: None,
+            min,
+            max,
+            step: None,
+            values: vec![value.clamp(min, max.max(min))],
+            direction: Direction::Column,
+            thumb_size: THUMB_SIZE,
+        }
+    }
+
+    /// Creates a horizontal slider for a range of values, without independent
+    /// style.
+    ///
+    /// A maximum under the minimum keeps the values at the minimum.
+    pub fn range(min: f32, max: f32, range: [f32; 2]) -> Self {
+        let start = range[0].clamp(min, max.max(min));
+
+        Self {
+            values: vec![start, range[1].clamp(start, max.max(start))],
+            ..Self::normal(min, max, min)
+        }
+    }
+
+    /// Changes the values by steps.
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+
+        for i in 0..self.values.len() {
+            self.set_value(i, self.values[i]);
+        }
+
+        self
+    }
+
+    /// Makes the slider vertical, with the minimum at the bottom.
+    pub fn vertical(mut self) -> Self {
+        self.direction = Direction::Row;
+        self
+    }
+
+    /// Returns the value of each thumb, in increasing order.
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Sets the value of a thumb, on a step and between the values of the
+    /// other thumb and the bounds. Returns whether the value changed, `false`
+    /// when the slider has no such thumb.
+    ///
+    /// When the maximum is not on a step, a stepped value does not go past
+    /// the last step before it.
+    pub fn set_value(&mut self, thumb: usize, value: f32) -> bool {
+        if thumb >= self.values.len() {
+            return false;
+        }
+
+        let mut value = match self.step {
+            Some(step) if step > 0.0 => {
+                // The tolerance keeps a maximum on a step despite the
+                // rounding of the division.
+                let last = self.min + ((self.max - self.min) / step + 1e-4).floor() * step;
+                (self.min + ((value - self.min) / step).round() * step).min(last)
+            }
+            _ => value,
+        };
+
+        // The thumbs of a range do not cross.
+        let low = if thumb > 0 { self.values[thumb - 1] } else { self.min };
+        let high = self.values.get(thumb + 1).copied().unwrap_or(self.max);
+        value = value.clamp(low, high.max(low));
+
+        let changed = self.values[thumb] != value;
+        self.values[thumb] = value;
+
+        changed
+    }
+
+    /// Returns the position of a value between the minimum (`0.0`) and the
+    /// maximum (`1.0`).
+    pub fn fraction(&self, value: f32) -> f32 {
+        match self.max > self.min {
+            true => ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0),
+            false => 0.0,
+        }
+    }
+
+    /// Returns the value a thumb takes at a point relative to the top-left
+    /// corner of a slider of length `length`, following its direction.
+    ///
+    /// Like the built track, the segments share the length left by all the
+    /// thumbs: the centre of the thumb `thumb` goes from the thumbs before it
+    /// plus half a thumb, to the length minus the thumbs after it minus half
+    /// a thumb.
+    pub fn value_at(&self, point: Point, length: usize, thumb: usize) -> f32 {
+        let length = length as f32;
+        // From the minimum, at the bottom of a vertical slider.
+        let position = match self.direction {
+            Direction::Column => point[0] as f32,
+            Direction::Row => length - point[1] as f32,
+        };
+
+        let size = self.thumb_size as f32;
+        let free = length - size * self.values.len() as f32;
+        let fraction = match free > 0.0 {
+            true => ((position - size * thumb as f32 - size / 2.0) / free).clamp(0.0, 1.0),
+            false => 0.0,
+        };
+
+        self.min + fraction * (self.max - self.min)
+    }
+
+    /// Returns the thumb the closest to a point relative to the top-left
+    /// corner of a slider of length `length`, with the value it takes there.
+    ///
+    /// See [`Slider::value_at()`] and [`Slider::closest_thumb()`].
+    pub fn closest_thumb_at(&self, point: Point, length: usize) -> (usize, f32) {
+        let mut closest = (0, self.value_at(point, length, 0));
+
+        for (i, thumb) in self.values.iter().enumerate().skip(1) {
+            let value = self.value_at(point, length, i);
+            let distance = (thumb - value).abs();
+            let closest_distance = (self.values[closest.0] - closest.1).abs();
+
+            if distance < closest_distance || (distance == closest_distance && value > *thumb) {
+                closest = (i, value);
+            }
+        }
+
+        closest
+    }
+
+    /// Returns the thumb the closest to a value.
+    pub fn closest_thumb(&self, value: f32) -> usize {
+        let mut closest = 0;
+
+        for (i, thumb) in self.values.iter().enumerate() {
+            let distance = (thumb - value).abs();
+            let closest_distance = (self.values[closest] - value).abs();
+
+            // On a tie, the thumbs are at the same value: the last one wins
+            // when the value is larger, so it can be moved away.
+            if distance < closest_distance || (distance == closest_distance && value > *thumb) {
+                closest = i;
+            }
+        }
+
+        closest
+    }
+
+    /// Returns the segment of the track of length `fraction`, a third of the
+    /// thickness of the slider.
+    fn segment(&self, fraction: f32, selected: bool) -> Weighted {
+        let track = Surface {
+            style: if selected { None } else { self.style.clone() },
+            component: if selected { Component::Indicator } else { Component::Track },
+        };
+        let empty = || Surface {
+            style: None,
+            component: Component::Layout,
+        };
+
+        Weighted::new(fraction.max(0.0), Layout {
+            style: None,
+            component: Component::Layout,
+            overflow: Overflow::Ignore,
+            wx_align: Align::Center,
+            wy_align: Align::Center,
+            // Across the slider.
+            direction: match self.direction {
+                Direction::Column => Direction::Row,
+                Direction::Row => Direction::Column,
+            },
+            padding: Padding::default(),
+            gap: Spacing::default(),
+            widgets: widgets![empty(), track, empty()],
+        })
+    }
+}
@@ -0,0 +1,153 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use std::time::Duration;
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::Transform,
+    widgets,
+    Align, Direction, Overflow, Padding, themes::{Component, Spacing, Style, Theme},
+};
+
+use super::{DebugWidget, Layout, Stack, Surface, Transformed, Weighted, Widget};
+
+/// Time taken by the ticks of an indeterminate spinner to go around.
+pub const PERIOD: Duration = Duration::from_millis(1000);
+
+/// Default number of ticks of a spinner.
+pub const TICKS: usize = 12;
+
+/// Number of ticks drawn as indicators in an indeterminate spinner.
+const INDETERMINATE_TICKS: usize = 3;
+
+/// Spinner widget, showing the progress of a task with ticks around a
+/// circle.
+///
+/// A determinate progress draws the ticks from the top, clockwise. An
+/// indeterminate progress draws a few ticks going around, moved with
+/// [`Spinner::advance()`].
+#[derive(Debug, Clone, Widget)]
+pub struct Spinner {
+    /// Independent style for the ticks out of the progress.
+    ///
+    /// If set as `None`, the default track style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// The progress between `0.0` and `1.0`, or `None` when indeterminate.
+    pub value: Option<f32>,
+    /// Number of ticks around the circle.
+    pub ticks: usize,
+    /// Position of the ticks of an indeterminate progress around the circle,
+    /// between `0.0` and `1.0`.
+    pub phase: f32,
+}
+
+impl Widget for Spinner {
+    /// Builds a stack of ticks, each one a layout filling the spinner with a
+    /// surface at its top, rotated around the centre.
+    fn build(&self) -> Box<dyn Widget> {
+        let mut stack = Stack::new();
+
+        for i in 0..self.ticks {
+            let tick = Surface {
+                style: if self.is_indicator(i) { None } else { self.style.clone() },
+                component: match self.is_indicator(i) {
+                    true => Component::Indicator,
+                    false => Component::Track,
+                },
+            };
+
+            stack = stack.filled(Transformed::new(
+                Transform::rotated(360.0 * i as f32 / self.ticks as f32),
+                tick_layout(tick),
+            ));
+        }
+
+        stack.into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Track),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Track));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+}
+
+impl Spinner {
+    /// Creates a spinner for a determinate progress, between `0.0` and `1.0`.
+    pub fn determinate(value: f32) -> Self {
+        Self {
+            style: None,
+            value: Some(value),
+            ticks: TICKS,
+            phase: 0.0,
+        }
+    }
+
+    /// Creates a spinner for an indeterminate progress.
+    pub fn indeterminate() -> Self {
+        Self {
+            value: None,
+            ..Self::determinate(0.0)
+        }
+    }
+
+    /// Moves the ticks of an indeterminate progress following the time
+    /// passed, going around in [`PERIOD`].
+    pub fn advance(&mut self, elapsed: Duration) {
+        self.phase = (self.phase + elapsed.as_secs_f32() / PERIOD.as_secs_f32()).fract();
+    }
+
+    /// Whether a tick is drawn as an indicator.
+    fn is_indicator(&self, tick: usize) -> bool {
+        match self.value {
+            Some(value) => {
+                let filled = (value.clamp(0.0, 1.0) * self.ticks as f32).round() as usize;
+                tick < filled
+            }
+            None => {
+                let head = (self.phase * self.ticks as f32) as usize;
+                (tick + self.ticks - head) % self.ticks < INDETERMINATE_TICKS
+            }
+        }
+    }
+}
+
+/// Returns a layout placing a tick in the middle of its top third.
+fn tick_layout(tick: Surface) -> Layout {
+    let empty = |weight: f32| Weighted::new(weight, Surface {
+        style: None,
+        component: Component::Layout,
+    });
+    let layout = |direction: Direction, widgets: Vec<Box<dyn Widget>>| Layout {
+        style: None,
+        component: Component::Layout,
+        overflow: Overflow::Ignore,
+        wx_align: Align::Center,
+        wy_align: Align::Center,
+        direction,
+        padding: Padding::default(),
+        gap: Spacing::default(),
+        widgets,
+    };
+
+    layout(Direction::Column, widgets![
+        empty(9.0),
+        Weighted::new(2.0, layout(Direction::Row, widgets![tick, empty(2.0)])),
+        empty(9.0),
+    ])
+}
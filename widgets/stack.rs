@@ -0,0 +1,87 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::Size,
+    themes::{Component, Style, Theme},
+    Zone,
+};
+
+use super::{Child, DebugWidget, Surface, Widget};
+
+/// Widgets placed in the zone of the stack without being sized nor aligned,
+/// each one in its own zone. The widgets built after the others are drawn
+/// over them.
+///
+/// The zones of the widgets are relative to the position of the stack, a 
+/// widget without zone fills the stack. The widgets are part of the widget 
+/// building the stack, so they are not browsed for controllers.
+#[derive(Debug, Widget)]
+pub struct Stack {
+    /// The widgets, with their zones.
+    pub widgets: Vec<(Option<Zone>, Box<dyn Widget>)>,
+}
+
+impl Widget for Stack {
+    /// Builds the transparent surface behind the widgets, like a layout.
+    fn build(&self) -> Box<dyn Widget> {
+        Surface {
+            style: None,
+            component: Component::Layout,
+        }.into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        theme.component_style(Component::Layout)
+    }
+
+    fn style_mut(&mut self, _: &Theme) -> &mut Style {
+        panic!("stacks have no style, they are transparent");
+    }
+}
+
+impl Stack {
+    /// Creates a stack without widgets.
+    pub fn new() -> Self {
+        Self {
+            widgets: vec![],
+        }
+    }
+
+    /// Adds a widget over the other widgets, at a zone relative to the
+    /// position of the stack.
+    pub fn with<T: Widget + 'static>(mut self, zone: Zone, widget: T) -> Self {
+        self.widgets.push((Some(zone), Box::new(widget)));
+        self
+    }
+
+    /// Adds a widget over the other widgets, filling the stack.
+    pub fn filled<T: Widget + 'static>(mut self, widget: T) -> Self {
+        self.widgets.push((None, Box::new(widget)));
+        self
+    }
+
+    /// Calls `child` with each widget of a stack of size `size` built as the
+    /// children of a widget, sharing its identifier.
+    pub(crate) fn shared_children(&self, size: Size, child: &mut dyn FnMut(Child)) {
+        for (zone, widget) in &self.widgets {
+            child(Child {
+                id: 0,
+                zone: zone.unwrap_or(Zone { position: [0, 0], size }),
+                widget: widget.as_ref(),
+                shared: true,
+            });
+        }
+    }
+}
+
+impl Default for Stack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
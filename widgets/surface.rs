@@ -8,7 +8,7 @@ use haussmann_dev::Widget;
 
 use crate::{
     DebugWidget, 
-    Widget, themes::{Theme, Style}, 
+    Widget, themes::{Component, State, Theme, Style}, 
 };
 
 /// A drawable zone which can be coloured, bordered or both.
@@ -19,6 +19,9 @@ pub struct Surface {
     /// If set as `None`, the default widget style from the global theme will 
     /// be used.
     pub style: Option<Style>,
+    /// The kind of widget the surface is drawn for. Used to find the style in
+    /// the global theme when there is no independent style.
+    pub component: Component,
 }
 
 impl Widget for Surface {
@@ -28,15 +31,14 @@ impl Widget for Surface {
     
     fn style(&self, theme: &Theme) -> Style {
         match &self.style {
-            Some(style) => style,
-            None => &theme.style
+            Some(style) => style.clone(),
+            None => theme.component_style(self.component),
         }
-        .clone()
     }
 
     fn style_mut(&mut self, theme: &Theme) -> &mut Style {
         if self.style.is_none() {
-            self.style = Some(theme.style.clone()); 
+            self.style = Some(theme.component_style(self.component)); 
         }
 
         self.style.as_mut().unwrap()
@@ -46,11 +48,26 @@ impl Widget for Surface {
 impl Surface {
     /// Creates a new surface with an independent style.
     pub fn styled(style: Option<Style>) -> Self {
-        Self { style }
+        Self { 
+            style, 
+            component: Component::Surface,
+        }
     }
 
     /// Creates a new surface without independent style.
     pub fn normal() -> Self {
-        Self { style: None }
+        Self { 
+            style: None, 
+            component: Component::Surface,
+        }
+    }
+
+    /// Same as [`Widget::style()`] but for the surface in an interaction 
+    /// state.
+    pub fn style_in(&self, theme: &Theme, state: State) -> Style {
+        match &self.style {
+            Some(style) => theme.independent_style_in(style, self.component, state),
+            None => theme.component_style_in(self.component, state),
+        }
     }
 }
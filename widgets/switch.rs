@@ -0,0 +1,112 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    controllers::toggle::Toggle,
+    widgets,
+    Align, Direction, Overflow, Padding, themes::{Component, Scale, Spacing, Style, Theme},
+};
+
+use super::{DebugWidget, Layout, Surface, Widget};
+
+/// Switch widget, on or off.
+/// 
+/// The thumb is on the left side of the track when the switch is off, and on
+/// its right side when it is on. Fills the zone it is placed in, wrap it in a 
+/// [`Container`](super::Container) to give it a size. Toggled by a 
+/// [`toggle::Toggler`](crate::controllers::toggle::Toggler).
+#[derive(Debug, Clone, Widget)]
+pub struct Switch {
+    /// Independent style for the switch.
+    /// 
+    /// If set as `None`, the default toggle style from the global theme will 
+    /// be used.
+    pub style: Option<Style>,
+    #[allow(missing_docs)]
+    pub on: bool,
+}
+
+impl Widget for Switch {
+    /// Builds the track, with the thumb in one half and a transparent surface
+    /// in the other half.
+    fn build(&self) -> Box<dyn Widget> {
+        let thumb = Surface {
+            style: None,
+            component: Component::ToggleMark,
+        };
+        let empty = Surface {
+            style: None,
+            component: Component::Layout,
+        };
+
+        Layout {
+            style: self.style.clone(),
+            component: self.component(),
+            overflow: Overflow::Hide,
+            wx_align: Align::Center,
+            wy_align: Align::Center,
+            direction: Direction::Column,
+            padding: Padding::all(Scale::ExtraSmall),
+            gap: Spacing::default(),
+            widgets: match self.on {
+                true => widgets![empty, thumb],
+                false => widgets![thumb, empty],
+            },
+        }
+        .into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(self.component()),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(self.component())); 
+        }
+
+        self.style.as_mut().unwrap()
+    }
+}
+
+impl Toggle for Switch {
+    /// Turns the switch on or off.
+    fn toggle(&mut self) -> bool {
+        self.on = !self.on;
+        true
+    }
+}
+
+impl Switch {
+    /// Creates a new switch with an independent style.
+    pub fn styled(style: Style, on: bool) -> Self {
+        Self {
+            style: Some(style),
+            on,
+        }
+    }
+
+    /// Creates a switch without independent style.
+    pub fn normal(on: bool) -> Self {
+        Self {
+            style: None,
+            on,
+        }
+    }
+
+    /// Returns the component the track is drawn for, following the value.
+    fn component(&self) -> Component {
+        match self.on {
+            true => Component::ToggleSelected,
+            false => Component::Toggle,
+        }
+    }
+}
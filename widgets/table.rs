@@ -0,0 +1,393 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use std::{fmt, rc::Rc};
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::{Point, Size},
+    themes::{Component, Scale, Spacing, Style, Theme},
+    Align,
+    Direction,
+    Overflow,
+    Padding,
+    Zone,
+};
+
+use super::{Child, Container, DebugWidget, Label, Layout, Surface, Widget};
+
+/// Default height of the header and of the rows of a table.
+pub const ROW_HEIGHT: usize = 24;
+
+/// Smallest width of a column resized by dragging its border.
+pub const MIN_WIDTH: usize = 24;
+
+/// Distance from the right border of a header in which it is dragged to
+/// resize its column.
+const BORDER: isize = 4;
+
+/// Size of the sort indicator of a header.
+const INDICATOR_SIZE: usize = 16;
+
+/// Closure building the widget of a cell of a table, from its row and its 
+/// column.
+pub type CellFn = Rc<dyn Fn(usize, usize) -> Box<dyn Widget>>;
+
+/// Width of a [`Column`] of a table.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ColumnWidth {
+    /// A width in pixels.
+    Fixed(usize),
+    /// A share of the width left by the fixed columns, like a
+    /// [`Weighted`](super::Weighted) widget.
+    Flex(f32),
+}
+
+/// Order of the rows of a table sorted by a column.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Sort {
+    #[allow(missing_docs)]
+    Ascending,
+    #[allow(missing_docs)]
+    Descending,
+}
+
+/// Column of a [`Table`], with its header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    /// The title in the header.
+    pub title: String,
+    /// The width of the column.
+    pub width: ColumnWidth,
+    /// The order of the rows when they are sorted by the column.
+    pub sort: Option<Sort>,
+}
+
+impl Column {
+    /// Creates a column of a width in pixels.
+    pub fn fixed(title: &str, width: usize) -> Self {
+        Self {
+            title: title.to_string(),
+            width: ColumnWidth::Fixed(width),
+            sort: None,
+        }
+    }
+
+    /// Creates a column sharing the width left by the fixed columns.
+    pub fn flex(title: &str, weight: f32) -> Self {
+        Self {
+            title: title.to_string(),
+            width: ColumnWidth::Flex(weight),
+            sort: None,
+        }
+    }
+}
+
+/// Part of a [`Table`] at a point.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Part {
+    /// The header of a column.
+    Header(usize),
+    /// The right border of the header of a column, dragged to resize it.
+    Border(usize),
+    /// A cell, at a row and a column.
+    Cell(usize, usize),
+}
+
+/// Table widget, rows of cells under a header with the title of each column.
+///
+/// The cells are built by a closure from their row and their column. Each
+/// header and each cell is built with its own identifier, following the
+/// identifier of the table: the headers first, then the cells row by row.
+/// See [`Table::cell_id()`]. Sorted, selected and resized by a
+/// [`table::Grid`](crate::controllers::table::Grid).
+///
+/// Only the rows in the zone of the table are built, the identifiers of the
+/// other rows are skipped. A table does not scroll its rows: the rows of a
+/// long table are shown by a [`ListView`](super::ListView).
+#[derive(Clone, Widget)]
+pub struct Table {
+    /// Independent style for the surface behind the cells.
+    ///
+    /// If set as `None`, the default layout style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// The columns.
+    pub columns: Vec<Column>,
+    /// The number of rows.
+    pub rows: usize,
+    /// Builds the widget of a cell.
+    pub cell: CellFn,
+    /// The selected row.
+    pub selected: Option<usize>,
+    /// Height of the header.
+    pub header_height: usize,
+    /// Height of each row.
+    pub row_height: usize,
+}
+
+impl fmt::Debug for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Table")
+            .field("style", &self.style)
+            .field("columns", &self.columns)
+            .field("rows", &self.rows)
+            .field("selected", &self.selected)
+            .field("header_height", &self.header_height)
+            .field("row_height", &self.row_height)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Widget for Table {
+    /// Builds the surface behind the cells, like a layout.
+    fn build(&self) -> Box<dyn Widget> {
+        Surface {
+            style: self.style.clone(),
+            component: Component::Layout,
+        }.into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Layout),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Layout));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+
+    /// Counts the identifier of the table, one for each header and one for 
+    /// each cell.
+    fn id_count(&self) -> usize {
+        1 + self.columns.len() * (self.rows + 1)
+    }
+
+    /// Visits the headers, then the cells row by row. The rows under the 
+    /// table are not built, their identifiers are skipped.
+    fn children(&self, size: Size, _theme: &Theme, child: &mut dyn FnMut(Child)) {
+        let widths = self.column_widths(size[0]);
+
+        // The headers are the first row.
+        for row in 0..=self.rows {
+            let (y, height) = match row {
+                0 => (0, self.header_height),
+                row => (self.header_height + (row - 1) * self.row_height, self.row_height),
+            };
+
+            if y >= size[1] {
+                break;
+            }
+
+            let mut x = 0;
+
+            for (column, &width) in widths.iter().enumerate() {
+                let (id, layout) = match row {
+                    0 => (self.header_id(0, column), self.header_layout(column)),
+                    row => (self.cell_id(0, row - 1, column), self.cell_layout(row - 1, column)),
+                };
+
+                child(Child {
+                    id,
+                    zone: Zone {
+                        position: [x as isize, y as isize],
+                        size: [width, height],
+                    },
+                    widget: &layout,
+                    shared: true,
+                });
+
+                x += width;
+            }
+        }
+    }
+}
+
+impl Table {
+    /// Creates a table without independent style, nothing selected.
+    pub fn new(columns: Vec<Column>, rows: usize, cell: impl Fn(usize, usize) -> Box<dyn Widget> + 'static) -> Self {
+        Self {
+            style: None,
+            columns,
+            rows,
+            cell: Rc::new(cell),
+            selected: None,
+            header_height: ROW_HEIGHT,
+            row_height: ROW_HEIGHT,
+        }
+    }
+
+    /// Returns the identifier of the drawables of the header of a column, in
+    /// a table of identifier `id`.
+    pub fn header_id(&self, id: usize, column: usize) -> usize {
+        id + 1 + column
+    }
+
+    /// Returns the identifier of the drawables of a cell, in a table of
+    /// identifier `id`.
+    pub fn cell_id(&self, id: usize, row: usize, column: usize) -> usize {
+        id + 1 + self.columns.len() * (row + 1) + column
+    }
+
+    /// Returns the width of each column in a table of width `width`. The
+    /// fixed columns take their width, the others share what is left.
+    pub fn column_widths(&self, width: usize) -> Vec<usize> {
+        let fixed: usize = self.columns
+            .iter()
+            .filter_map(|column| match column.width {
+                ColumnWidth::Fixed(width) => Some(width),
+                ColumnWidth::Flex(_) => None,
+            })
+            .sum();
+        let weights: f32 = self.columns
+            .iter()
+            .filter_map(|column| match column.width {
+                ColumnWidth::Flex(weight) => Some(weight.max(0.0)),
+                ColumnWidth::Fixed(_) => None,
+            })
+            .sum();
+        let left = width.saturating_sub(fixed) as f32;
+
+        self.columns
+            .iter()
+            .map(|column| match column.width {
+                ColumnWidth::Fixed(width) => width,
+                ColumnWidth::Flex(_) if weights <= 0.0 => 0,
+                ColumnWidth::Flex(weight) => (left * weight.max(0.0) / weights) as usize,
+            })
+            .collect()
+    }
+
+    /// Returns the part of a table of width `width` at a point relative to
+    /// its top-left corner.
+    pub fn part_at(&self, point: Point, width: usize) -> Option<Part> {
+        let [x, y] = point;
+
+        if x < 0 || y < 0 {
+            return None;
+        }
+
+        let mut right = 0;
+        let column = self.column_widths(width).iter().position(|&width| {
+            right += width as isize;
+            x < right
+        })?;
+
+        if y < self.header_height as isize {
+            return match x >= right - BORDER {
+                true => Some(Part::Border(column)),
+                false => Some(Part::Header(column)),
+            };
+        }
+
+        if self.row_height == 0 {
+            return None;
+        }
+
+        let row = (y as usize - self.header_height) / self.row_height;
+        (row < self.rows).then_some(Part::Cell(row, column))
+    }
+
+    /// Sorts the rows by a column, in the ascending order, or in the
+    /// descending order when they already are in the ascending order. The
+    /// other columns are not sorted anymore. Returns the new order, or 
+    /// `None` without the column.
+    pub fn sort_by(&mut self, column: usize) -> Option<Sort> {
+        let sort = match self.columns.get(column)?.sort {
+            Some(Sort::Ascending) => Sort::Descending,
+            _ => Sort::Ascending,
+        };
+
+        for (i, other) in self.columns.iter_mut().enumerate() {
+            other.sort = (i == column).then_some(sort);
+        }
+
+        Some(sort)
+    }
+
+    /// Resizes a column of a table of width `width` by `delta` pixels, giving
+    /// it a fixed width of at least [`MIN_WIDTH`]. Returns whether the column
+    /// exists.
+    pub fn resize(&mut self, column: usize, delta: isize, width: usize) -> bool {
+        let Some(&current) = self.column_widths(width).get(column) else {
+            return false;
+        };
+
+        self.set_width(column, current as isize + delta)
+    }
+
+    /// Gives a column a fixed width of `width` pixels, at least 
+    /// [`MIN_WIDTH`]. Returns whether the column exists.
+    pub fn set_width(&mut self, column: usize, width: isize) -> bool {
+        let Some(column) = self.columns.get_mut(column) else {
+            return false;
+        };
+
+        column.width = ColumnWidth::Fixed(width.max(MIN_WIDTH as isize) as usize);
+        true
+    }
+
+    /// Returns the layout of the header of a column, with the sort indicator.
+    pub fn header_layout(&self, column: usize) -> Layout {
+        let column = &self.columns[column];
+        let mut widgets: Vec<Box<dyn Widget>> = vec![Label {
+            style: None,
+            component: Component::TableHeader,
+            text: column.title.clone(),
+        }.into()];
+
+        if let Some(sort) = column.sort {
+            let indicator = Label {
+                style: None,
+                component: Component::TableHeader,
+                text: match sort {
+                    Sort::Ascending => "▴",
+                    Sort::Descending => "▾",
+                }.to_string(),
+            };
+
+            widgets.push(Container::new([INDICATOR_SIZE, INDICATOR_SIZE], indicator).into());
+        }
+
+        Layout {
+            style: None,
+            component: Component::TableHeader,
+            overflow: Overflow::Hide,
+            wx_align: Align::Left,
+            wy_align: Align::Center,
+            direction: Direction::Column,
+            padding: Padding::symmetric(Scale::None, Scale::Small),
+            gap: Spacing::default(),
+            widgets,
+        }
+    }
+
+    /// Returns the layout of a cell, over the selection when its row is
+    /// selected.
+    pub fn cell_layout(&self, row: usize, column: usize) -> Layout {
+        Layout {
+            style: None,
+            component: match self.selected == Some(row) {
+                true => Component::TableSelected,
+                false => Component::Layout,
+            },
+            overflow: Overflow::Hide,
+            wx_align: Align::Left,
+            wy_align: Align::Center,
+            direction: Direction::Column,
+            padding: Padding::symmetric(Scale::None, Scale::Small),
+            gap: Spacing::default(),
+            widgets: vec![(self.cell)(row, column)],
+        }
+    }
+}
@@ -0,0 +1,149 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::Size,
+    themes::{Component, Style, Theme},
+    Zone,
+};
+
+use super::{Child, DebugWidget, NavigationBar, Surface, Widget};
+
+/// Default height of the bar of tabs.
+pub const BAR_HEIGHT: usize = 40;
+
+/// Tabs widget, a bar with the title of each page over the selected page.
+///
+/// Only the selected page is built, under the bar, with the identifier
+/// following the one of the bar. The pages can contain controllers, like the
+/// widgets of a layout. Switched by a
+/// [`tabs::Switcher`](crate::controllers::tabs::Switcher).
+#[derive(Debug, Widget)]
+pub struct Tabs {
+    /// Independent style for the surface behind the selected page.
+    ///
+    /// If set as `None`, the default layout style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// The bar with the title of each page, and the selected page.
+    pub bar: NavigationBar,
+    /// The pages, in the order of their titles.
+    pub pages: Vec<Box<dyn Widget>>,
+    /// Height of the bar.
+    pub bar_height: usize,
+}
+
+impl Widget for Tabs {
+    /// Builds the surface behind the selected page, like a layout.
+    fn build(&self) -> Box<dyn Widget> {
+        Surface {
+            style: self.style.clone(),
+            component: Component::Layout,
+        }.into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Layout),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Layout));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+
+    /// Counts the identifier of the tabs, the one of the bar, then the ones
+    /// of the selected page or one without page.
+    fn id_count(&self) -> usize {
+        2 + self.page().map_or(1, |page| page.id_count())
+    }
+
+    /// Visits the bar, then the selected page under it.
+    fn children(&self, size: Size, _theme: &Theme, child: &mut dyn FnMut(Child)) {
+        let zone = Zone {
+            position: [0, 0],
+            size,
+        };
+
+        child(Child {
+            id: 1,
+            zone: self.bar_zone(zone),
+            widget: &self.bar.layout(),
+            shared: true,
+        });
+
+        if let Some(page) = self.page() {
+            child(Child {
+                id: 2,
+                zone: self.page_zone(zone),
+                widget: page,
+                shared: false,
+            });
+        }
+    }
+
+    /// Visits the selected page.
+    fn children_mut(&mut self, child: &mut dyn FnMut(usize, &mut Box<dyn Widget>)) {
+        if let Some(page) = self.page_mut() {
+            child(2, page);
+        }
+    }
+}
+
+impl Tabs {
+    /// Creates tabs without independent style, with the title and the widget
+    /// of each page, the first page selected.
+    pub fn new(pages: Vec<(&str, Box<dyn Widget>)>) -> Self {
+        let (titles, pages): (Vec<&str>, Vec<Box<dyn Widget>>) = pages.into_iter().unzip();
+
+        Self {
+            style: None,
+            bar: NavigationBar::normal(&titles, 0),
+            pages,
+            bar_height: BAR_HEIGHT,
+        }
+    }
+
+    /// Returns the index of the selected page.
+    pub fn selected(&self) -> usize {
+        self.bar.selected
+    }
+
+    /// Returns the selected page.
+    pub fn page(&self) -> Option<&dyn Widget> {
+        self.pages.get(self.bar.selected).map(|page| page.as_ref())
+    }
+
+    /// Same as [`Self::page`] but the returned page is mutable.
+    pub fn page_mut(&mut self) -> Option<&mut Box<dyn Widget>> {
+        self.pages.get_mut(self.bar.selected)
+    }
+
+    /// Returns the zone of the bar in tabs of zone `zone`.
+    pub fn bar_zone(&self, zone: Zone) -> Zone {
+        Zone {
+            position: zone.position,
+            size: [zone.width(), self.bar_height.min(zone.height())],
+        }
+    }
+
+    /// Returns the zone of the selected page in tabs of zone `zone`.
+    pub fn page_zone(&self, zone: Zone) -> Zone {
+        let bar = self.bar_zone(zone).height();
+
+        Zone {
+            position: [zone.x(), zone.y() + bar as isize],
+            size: [zone.width(), zone.height() - bar],
+        }
+    }
+}
@@ -0,0 +1,237 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    graphics::{Point, Size},
+    text::Buffer,
+    themes::{Component, Scale, Spacing, Style, Theme},
+    Align, Direction, Overflow, Padding, Zone,
+};
+
+use super::{DebugWidget, Label, Layout, Stack, Surface, Widget};
+
+/// Default size of a character of the text, for monospace fonts.
+pub const CELL_SIZE: Size = [8, 16];
+
+/// Editable text input, drawing its text, its caret and its selection.
+///
+/// The text is edited through its [`Buffer`], by a
+/// [`text::Editor`](crate::controllers::text::Editor) when the field is
+/// focused. The characters are placed in cells of [`TextField::cell_size`],
+/// one line of text per row of cells.
+#[derive(Debug, Clone, Widget)]
+pub struct TextField {
+    /// Independent style for the field.
+    ///
+    /// If set as `None`, the default input style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// The edited text.
+    pub buffer: Buffer,
+    /// Text shown while there is no text.
+    pub placeholder: String,
+    /// Size of a character of the text. Terminal backends use `[1, 1]`.
+    pub cell_size: Size,
+    /// Space between the sides of the field and its text.
+    pub padding: Padding,
+    /// Whether the field has the keyboard focus, so the caret is drawn.
+    pub focused: bool,
+}
+
+impl Widget for TextField {
+    /// Builds the selection rectangles, the lines of the text, the
+    /// composition underline and the caret, in this order over the field's
+    /// surface.
+    fn build(&self) -> Box<dyn Widget> {
+        let [cw, ch] = self.cell_size;
+        let mut stack = Stack::new();
+
+        // Rectangles behind the selected characters of every line.
+        if let Some(selection) = self.buffer.selection() {
+            let (first, start) = self.buffer.line_column(selection.start);
+            let (last, end) = self.buffer.line_column(selection.end);
+
+            for (line, text) in self.buffer.lines().enumerate().take(last + 1).skip(first) {
+                let from = if line == first { start } else { 0 };
+                let to = if line == last {
+                    end
+                } else {
+                    // The selected line break.
+                    text.chars().count() + 1
+                };
+
+                if to > from {
+                    stack = stack.with(
+                        cells(self.cell_size, line, from, to - from),
+                        Surface {
+                            style: None,
+                            component: Component::Selection,
+                        },
+                    );
+                }
+            }
+        }
+
+        let (text, cursor) = self.buffer.composed();
+
+        // The lines of the text, with the composition.
+        for (line, text) in text.split('\n').enumerate() {
+            if text.is_empty() {
+                continue;
+            }
+
+            stack = stack.with(
+                cells(self.cell_size, line, 0, text.chars().count()),
+                Label {
+                    style: None,
+                    component: Component::Input,
+                    text: text.to_string(),
+                },
+            );
+        }
+
+        if self.buffer.is_empty() && self.buffer.composition().is_none() {
+            stack = stack.with(
+                cells(self.cell_size, 0, 0, self.placeholder.chars().count()),
+                Label {
+                    style: None,
+                    component: Component::Placeholder,
+                    text: self.placeholder.clone(),
+                },
+            );
+        }
+
+        // Line under the composed text.
+        if let Some(composition) = self.buffer.composition() {
+            let (line, column) = self.buffer.line_column(self.buffer.cursor());
+            let mut zone = cells(self.cell_size, line, column, composition.text.chars().count());
+            zone.position[1] += ch as isize - 1;
+            zone.size[1] = 1;
+
+            stack = stack.with(zone, Surface {
+                style: None,
+                component: Component::Caret,
+            });
+        }
+
+        if self.focused {
+            let (line, column) = line_column(&text, cursor);
+            let mut zone = cells(self.cell_size, line, column, 1);
+            zone.size[0] = (cw / 4).max(1);
+
+            stack = stack.with(zone, Surface {
+                style: None,
+                component: Component::Caret,
+            });
+        }
+
+        Layout {
+            style: self.style.clone(),
+            component: Component::Input,
+            overflow: Overflow::Hide,
+            wx_align: Align::Left,
+            wy_align: Align::Top,
+            direction: Direction::Column,
+            padding: self.padding,
+            gap: Spacing::default(),
+            widgets: vec![stack.into()],
+        }
+        .into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Input),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Input));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+}
+
+impl TextField {
+    /// Creates a single-line field with an independent style.
+    pub fn styled(style: Style, text: &str) -> Self {
+        Self {
+            style: Some(style),
+            ..Self::normal(text)
+        }
+    }
+
+    /// Creates a single-line field without independent style.
+    pub fn normal(text: &str) -> Self {
+        Self {
+            style: None,
+            buffer: Buffer::new(text, false),
+            placeholder: String::new(),
+            cell_size: CELL_SIZE,
+            padding: Padding::symmetric(Scale::ExtraSmall, Scale::Small),
+            focused: false,
+        }
+    }
+
+    /// Creates a multi-line field without independent style.
+    pub fn multi_line(text: &str) -> Self {
+        Self {
+            buffer: Buffer::new(text, true),
+            ..Self::normal(text)
+        }
+    }
+
+    /// Shows a text while there is no text.
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    /// Sets the size of a character of the text.
+    pub fn with_cell_size(mut self, cell_size: Size) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Returns the position in the text of the character at a point relative
+    /// to the top-left corner of the text, for the pointer to place the
+    /// cursor.
+    pub fn index_at(&self, point: Point) -> usize {
+        let [cw, ch] = self.cell_size;
+        let [x, y] = point;
+
+        let line = y.max(0) as usize / ch.max(1);
+        // Closer to the end of the character than to its start is after it.
+        let column = (x.max(0) as usize + cw / 2) / cw.max(1);
+
+        self.buffer.index(line, column)
+    }
+}
+
+/// Returns the zone of `count` cells from a column of a line.
+fn cells(cell_size: Size, line: usize, column: usize, count: usize) -> Zone {
+    let [cw, ch] = cell_size;
+
+    Zone {
+        position: [(column * cw) as isize, (line * ch) as isize],
+        size: [count * cw, ch],
+    }
+}
+
+/// Returns the line and the column of a position in a text.
+fn line_column(text: &str, index: usize) -> (usize, usize) {
+    text.chars().take(index).fold((0, 0), |(line, column), character| {
+        match character {
+            '\n' => (line + 1, 0),
+            _ => (line, column + 1),
+        }
+    })
+}
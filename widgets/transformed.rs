@@ -0,0 +1,83 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    controllers::Controller,
+    graphics::{Matrix, Transform},
+    DebugWidget, Widget, themes::{State, Theme, Style}, Zone,
+};
+
+/// Wraps a widget giving it a [`Transform`] (translation, scale, rotation).
+///
+/// The transformation does not change the zone given to the widget by its
+/// layout, it is only applied to the drawables of the widget. The transformed
+/// widget is built like it would be without the transformation.
+#[derive(Debug, Widget)]
+pub struct Transformed {
+    /// The transformation of the widget.
+    pub transform: Transform,
+    /// The transformed widget.
+    pub widget: Box<dyn Widget>,
+}
+
+/// Creates a new transformed widget like its [`new`](Transformed::new)
+/// function.
+#[macro_export]
+macro_rules! transformed {
+    (transform: $transform:expr, widget: $widget:expr $(,)?) => {
+        Transformed::new($transform, $widget)
+    };
+}
+
+impl Widget for Transformed {
+    fn build(&self) -> Box<dyn Widget> {
+        self.widget.build()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        self.widget.style(theme)
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        self.widget.style_mut(theme)
+    }
+
+    fn transform(&self, zone: Zone) -> Matrix {
+        self.transform.matrix(zone) * self.widget.transform(zone)
+    }
+
+    fn state(&self) -> State {
+        self.widget.state()
+    }
+
+    fn as_controller_mut(&mut self) -> Option<&mut dyn Controller> {
+        self.widget.as_controller_mut()
+    }
+
+    fn wrapped(&self) -> Option<&dyn Widget> {
+        Some(self.widget.as_ref())
+    }
+
+    fn wrapped_mut(&mut self) -> Option<&mut Box<dyn Widget>> {
+        Some(&mut self.widget)
+    }
+
+    fn id_count(&self) -> usize {
+        self.widget.id_count()
+    }
+}
+
+impl Transformed {
+    /// Creates a new transformed widget.
+    pub fn new<T: Widget + 'static>(transform: Transform, widget: T) -> Self {
+        Self {
+            transform,
+            widget: Box::new(widget),
+        }
+    }
+}
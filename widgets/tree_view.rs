@@ -0,0 +1,380 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use std::{cell::RefCell, fmt};
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    controllers::tree::{Expander, Row},
+    graphics::Size,
+    themes::{Component, Scale, Spacing, Style, Theme},
+    Align,
+    Direction,
+    Overflow,
+    Padding,
+    Zone,
+};
+
+use super::{Child, Container, DebugWidget, Label, Layout, Surface, Widget};
+
+/// Default height of the rows of a tree view.
+pub const ROW_HEIGHT: usize = 24;
+
+/// Default indentation of the children of a node, in pixels.
+pub const INDENT: usize = 16;
+
+/// Size of the expand and collapse toggle of a row.
+pub const TOGGLE_SIZE: usize = 16;
+
+/// Node of a [`TreeView`], with its children.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeNode {
+    /// The text of the node.
+    pub label: String,
+    /// The children of the node, `None` until they are loaded.
+    pub children: Option<Vec<TreeNode>>,
+    /// Whether the children of the node are shown.
+    pub expanded: bool,
+}
+
+impl TreeNode {
+    /// Creates a node without children.
+    pub fn leaf(label: &str) -> Self {
+        Self::branch(label, vec![])
+    }
+
+    /// Creates a collapsed node with its children.
+    pub fn branch(label: &str, children: Vec<TreeNode>) -> Self {
+        Self {
+            label: label.to_string(),
+            children: Some(children),
+            expanded: false,
+        }
+    }
+
+    /// Creates a collapsed node whose children are loaded when it is
+    /// expanded.
+    pub fn lazy(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            children: None,
+            expanded: false,
+        }
+    }
+
+    /// Whether the node has children, or children to load.
+    pub fn has_children(&self) -> bool {
+        self.children.as_ref().is_none_or(|children| !children.is_empty())
+    }
+}
+
+/// Row of a [`TreeView`]: the path to its node, the index of each node from
+/// the roots.
+pub type NodePath = Vec<usize>;
+
+/// Tree of nodes shown in rows, the children of the expanded nodes under
+/// them, indented.
+///
+/// Each row is a layout with a toggle expanding or collapsing the node and
+/// its text. The row of an expanded node is built over a nested layout of
+/// the rows of its children. The rows are built in the zone of the tree and
+/// hidden out of it, like the widgets of a layout: each row is a 
+/// [`tree::Row`](crate::controllers::tree::Row) and each toggle is a 
+/// [`tree::Expander`](crate::controllers::tree::Expander), through which a
+/// [`tree::Navigator`](crate::controllers::tree::Navigator) expands, loads
+/// and selects the nodes.
+/// 
+/// The nested layout is kept until the shown rows change, with the state of
+/// the controllers of the rows.
+#[derive(Widget)]
+pub struct TreeView {
+    /// Independent style for the surface behind the rows.
+    ///
+    /// If set as `None`, the default layout style from the global theme will
+    /// be used.
+    pub style: Option<Style>,
+    /// The nodes at the top of the tree.
+    pub roots: Vec<TreeNode>,
+    /// The path to the selected node.
+    pub selected: Option<NodePath>,
+    /// Indentation of the children of a node, in pixels.
+    pub indent: usize,
+    /// Height of each row.
+    pub row_height: usize,
+    /// The nested layout of the roots built last, with what it shows.
+    built: RefCell<Option<(Shape, Box<dyn Widget>)>>,
+}
+
+/// What the nested layout of the roots of a tree view shows, built again when
+/// it changes.
+#[derive(Debug, Clone, PartialEq)]
+struct Shape {
+    width: usize,
+    indent: usize,
+    row_height: usize,
+    selected: Option<NodePath>,
+    /// The depth, the text, whether it is expanded and whether it has 
+    /// children, of each shown node.
+    nodes: Vec<(usize, String, bool, bool)>,
+}
+
+impl fmt::Debug for TreeView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeView")
+            .field("style", &self.style)
+            .field("roots", &self.roots)
+            .field("selected", &self.selected)
+            .field("indent", &self.indent)
+            .field("row_height", &self.row_height)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Clone for TreeView {
+    /// Clones the tree without its nested layout, which is built again.
+    fn clone(&self) -> Self {
+        Self {
+            style: self.style.clone(),
+            roots: self.roots.clone(),
+            selected: self.selected.clone(),
+            indent: self.indent,
+            row_height: self.row_height,
+            built: RefCell::new(None),
+        }
+    }
+}
+
+impl Widget for TreeView {
+    /// Builds the surface behind the rows, like a layout.
+    fn build(&self) -> Box<dyn Widget> {
+        Surface {
+            style: self.style.clone(),
+            component: Component::Layout,
+        }.into()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match &self.style {
+            Some(style) => style.clone(),
+            None => theme.component_style(Component::Layout),
+        }
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        if self.style.is_none() {
+            self.style = Some(theme.component_style(Component::Layout));
+        }
+
+        self.style.as_mut().unwrap()
+    }
+
+    /// Counts the identifier of the tree, then the ones of the nested layout
+    /// of the roots.
+    fn id_count(&self) -> usize {
+        1 + self.built.borrow().as_ref().map_or(0, |(_, layout)| layout.id_count())
+    }
+
+    /// Visits the nested layout of the roots in a tree of size `size`, in a 
+    /// zone of the height of all the rows, after building it again when the
+    /// shown rows changed.
+    fn children(&self, size: Size, _theme: &Theme, child: &mut dyn FnMut(Child)) {
+        let shape = self.shape(size[0]);
+        let rows = shape.nodes.len();
+
+        let stale = self.built.borrow().as_ref().is_none_or(|(built, _)| *built != shape);
+        if stale {
+            *self.built.borrow_mut() = match self.roots.is_empty() {
+                true => None,
+                false => Some((shape, self.nodes_layout(&self.roots, &mut vec![], size[0]).into())),
+            };
+        }
+
+        if let Some((_, layout)) = self.built.borrow().as_ref() {
+            child(Child {
+                id: 1,
+                zone: Zone {
+                    position: [0, 0],
+                    size: [size[0], rows * self.row_height],
+                },
+                widget: layout.as_ref(),
+                shared: false,
+            });
+        }
+    }
+
+    /// Visits the nested layout of the roots built last.
+    fn children_mut(&mut self, child: &mut dyn FnMut(usize, &mut Box<dyn Widget>)) {
+        if let Some((_, layout)) = self.built.get_mut() {
+            child(1, layout);
+        }
+    }
+}
+
+impl TreeView {
+    /// Creates a tree view without independent style, nothing selected.
+    pub fn new(roots: Vec<TreeNode>) -> Self {
+        Self {
+            style: None,
+            roots,
+            selected: None,
+            indent: INDENT,
+            row_height: ROW_HEIGHT,
+            built: RefCell::new(None),
+        }
+    }
+
+    /// Returns the node at a path.
+    pub fn node(&self, path: &[usize]) -> Option<&TreeNode> {
+        let (first, rest) = path.split_first()?;
+
+        rest.iter().try_fold(self.roots.get(*first)?, |node, &i| node.children.as_ref()?.get(i))
+    }
+
+    /// Same as [`Self::node`] but the returned node is mutable.
+    pub fn node_mut(&mut self, path: &[usize]) -> Option<&mut TreeNode> {
+        let (first, rest) = path.split_first()?;
+
+        rest.iter().try_fold(self.roots.get_mut(*first)?, |node, &i| node.children.as_mut()?.get_mut(i))
+    }
+
+    /// Returns the paths of the shown nodes, in the order of the rows: each
+    /// node, then the children of the expanded nodes.
+    pub fn rows(&self) -> Vec<NodePath> {
+        let mut rows = vec![];
+        push_rows(&self.roots, &mut vec![], &mut rows);
+        rows
+    }
+
+    /// Returns the row at a distance `y` from the top of the tree.
+    pub fn row_at(&self, y: isize) -> Option<usize> {
+        if y < 0 || self.row_height == 0 {
+            return None;
+        }
+
+        let row = y as usize / self.row_height;
+        (row < self.rows().len()).then_some(row)
+    }
+
+    /// Returns the layout of the row of the node at a path: its toggle, if it
+    /// has children, then its text, indented following its depth.
+    pub fn row_layout(&self, path: &[usize]) -> Option<Layout> {
+        Some(self.node_layout(self.node(path)?, path))
+    }
+
+    /// Returns the layout of the row of a node at a path.
+    fn node_layout(&self, node: &TreeNode, path: &[usize]) -> Layout {
+        let selected = self.selected.as_deref() == Some(path);
+        let component = match selected {
+            true => Component::MenuHighlight,
+            false => Component::Layout,
+        };
+
+        let toggle = Label {
+            style: None,
+            component,
+            text: match (node.has_children(), node.expanded) {
+                (false, _) => "",
+                (true, false) => "▸",
+                (true, true) => "▾",
+            }.to_string(),
+        };
+        let label = Label {
+            style: None,
+            component,
+            text: node.label.clone(),
+        };
+
+        Layout {
+            style: None,
+            component,
+            overflow: Overflow::Hide,
+            wx_align: Align::Left,
+            wy_align: Align::Center,
+            direction: Direction::Column,
+            padding: Padding {
+                left: Spacing::from((path.len() - 1) * self.indent),
+                ..Padding::default()
+            },
+            gap: Spacing::from(Scale::ExtraSmall),
+            widgets: vec![Container::new([TOGGLE_SIZE, TOGGLE_SIZE], Expander::new(toggle)).into(), label.into()],
+        }
+    }
+
+    /// Returns the nested layout of nodes whose parent is at the path 
+    /// `parent`, in a tree of width `width`: the row of each node, the row of
+    /// an expanded node over the layout of its children. Each row is 
+    /// contained in the row height, like [`row_at()`](Self::row_at) expects.
+    fn nodes_layout(&self, nodes: &[TreeNode], parent: &mut NodePath, width: usize) -> Layout {
+        let mut widgets: Vec<Box<dyn Widget>> = vec![];
+
+        for (i, node) in nodes.iter().enumerate() {
+            parent.push(i);
+            let row = Container::new([width, self.row_height], Row::new(parent.clone(), self.node_layout(node, parent)));
+
+            match (node.expanded, &node.children) {
+                (true, Some(children)) if !children.is_empty() => {
+                    let rows = row_count(children);
+                    let children = self.nodes_layout(children, parent, width);
+
+                    widgets.push(Container::new([width, (rows + 1) * self.row_height], Layout::normal(
+                        Overflow::Hide,
+                        Align::Left,
+                        Align::Top,
+                        Direction::Row,
+                        vec![row.into(), Container::new([width, rows * self.row_height], children).into()],
+                    )).into());
+                }
+                _ => widgets.push(row.into()),
+            }
+
+            parent.pop();
+        }
+
+        Layout::normal(Overflow::Hide, Align::Left, Align::Top, Direction::Row, widgets)
+    }
+
+    /// Returns what the nested layout of the roots shows in a tree of width
+    /// `width`.
+    fn shape(&self, width: usize) -> Shape {
+        Shape {
+            width,
+            indent: self.indent,
+            row_height: self.row_height,
+            selected: self.selected.clone(),
+            nodes: self.rows()
+                .iter()
+                .filter_map(|path| self.node(path).map(|node| (path.len(), node.label.clone(), node.expanded, node.has_children())))
+                .collect(),
+        }
+    }
+}
+
+/// Returns the number of rows of the shown nodes of `nodes`.
+fn row_count(nodes: &[TreeNode]) -> usize {
+    nodes
+        .iter()
+        .map(|node| match (node.expanded, &node.children) {
+            (true, Some(children)) => 1 + row_count(children),
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Pushes the paths of the shown nodes of `nodes`, the path of their parent
+/// being `parent`.
+fn push_rows(nodes: &[TreeNode], parent: &mut NodePath, rows: &mut Vec<NodePath>) {
+    for (i, node) in nodes.iter().enumerate() {
+        parent.push(i);
+        rows.push(parent.clone());
+
+        if let (true, Some(children)) = (node.expanded, &node.children) {
+            push_rows(children, parent, rows);
+        }
+
+        parent.pop();
+    }
+}
@@ -2,13 +2,26 @@
 // Under the MIT License
 // Copyright (c) 2023 Antonin Hérault
 
+use std::{cell::{Cell, RefCell}, collections::VecDeque, time::Duration};
+
 use crate::{
-    controllers::{Controller, ControllersBrowser},
-    graphics::{draw::{Drawable, self}, Point, Size},
+    controllers::{
+        self,
+        dialog::{Dialog, Toast},
+        focus,
+        keyboard,
+        pointer,
+        propagation::{self, Propagation},
+        Controller,
+        ControllersBrowser,
+        Snapshot,
+    },
+    graphics::{draw::{Drawable, self}, hit::{self, Hit}, Point, Size},
+    themes::{self, Mode, Theme},
     Zone,
 };
 
-use super::Layout;
+use super::{Layout, Placement, Popup};
 
 /// Wraps a [`Layout`] and permit to build [`Drawable`]s from the widgets in.
 /// 
@@ -20,23 +33,81 @@ pub struct View {
     zone: Zone,
     /// The layout for the view.
     pub layout: Layout,
+    /// The popups opened over the layout, from the bottom one to the top one,
+    /// then the shown toast.
+    overlay: Vec<Popup>,
+    /// The toasts waiting to be shown.
+    toasts: VecDeque<Toast>,
+    /// The time left before the shown toast is closed, `None` when no toast 
+    /// is shown.
+    toast_left: Option<Duration>,
+    /// The active theme, used to resolve the styles of the drawables.
+    theme: Theme,
+    /// Turns the pointer inputs into events for the controllers.
+    dispatcher: pointer::Dispatcher,
+    /// Tracks the controller having the keyboard focus.
+    focus: focus::Manager,
+    /// Spatial index of the last built drawables.
+    index: RefCell<hit::Index>,
 }
 
 impl View {
-    /// Creates a new view at a certain point with a specified size.
+    /// Creates a new view at a certain point with a specified size, using the
+    /// [`default`](themes::default) theme without fonts, the text being drawn
+    /// with the font chosen by the renderer.
     pub fn new(zone: Zone, layout: Layout) -> Self {
+        Self::themed(zone, layout, themes::default(vec![]))
+    }
+
+    /// Creates a new view at a certain point with a specified size, using the
+    /// given theme.
+    pub fn themed(zone: Zone, layout: Layout, theme: Theme) -> Self {
         Self {
             zone,
             layout,
+            overlay: vec![],
+            toasts: VecDeque::new(),
+            toast_left: None,
+            theme,
+            dispatcher: pointer::Dispatcher::default(),
+            focus: focus::Manager::default(),
+            index: RefCell::new(hit::Index::new(&[], zone)),
         }
     }
 
+    /// Returns the active theme.
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Replaces the active theme, and builds again to resolve the styles of 
+    /// the drawables with the new theme.
+    /// 
+    /// The widgets are kept as they are, only the styles coming from the 
+    /// theme change.
+    pub fn set_theme(&mut self, theme: Theme) -> Vec<Drawable> {
+        self.theme = theme;
+        self.build()
+    }
+
+    /// Switches the active theme to another variant (light, dark...), and 
+    /// builds again to resolve the styles of the drawables with it.
+    pub fn set_theme_mode(&mut self, mode: Mode) -> Vec<Drawable> {
+        self.theme.set_mode(mode);
+        self.build()
+    }
+
     /// Creates drawables for the widgets in the view's layout.
+    /// 
+    /// The spatial [`hit::Index`] of the drawables is created too, to find 
+    /// the drawables under the pointer.
     pub fn build(&self) -> Vec<Drawable> {
         // Creates a builder.
-        let mut builder = draw::Builder::new(self.zone);
+        let mut builder = draw::Builder::new(self.zone, &self.theme);
         // Builds the widgets of the view's layout.
         builder.build_view(self);
+        // Indexes the created drawables.
+        *self.index.borrow_mut() = hit::Index::new(&builder.drawables, self.zone);
         // Returns the created drawables.
         builder.drawables
     }
@@ -58,7 +129,548 @@ impl View {
     where 
         T: Controller + 'static,
     {
-        let mut browser = ControllersBrowser::new(drawables, callback);
-        browser.browse_layout(&mut self.layout);
+        self.themed_controllers::<T>(drawables, |controller, _| callback(controller));
+    }
+
+    /// Same as [`Self::controllers()`] but the callback also receives the 
+    /// active theme, to call the functions of the controllers taking it.
+    pub fn themed_controllers<T>(&mut self, drawables: Vec<Drawable>, callback: impl Fn(&mut T, &Theme)) 
+    where 
+        T: Controller + 'static,
+    {
+        let mut browser = ControllersBrowser::new(drawables, &self.theme, callback);
+        browser.browse_layout(&mut self.layout, &mut self.overlay);
+    }
+
+    /// Updates the interaction states of the controllers of type `T` following
+    /// a pointer at `pointer`, pressed or not. Returns whether a state 
+    /// changed, so the view has to be built again to restyle the drawables.
+    /// 
+    /// See [`Controller::track()`].
+    pub fn track<T>(&mut self, drawables: Vec<Drawable>, pointer: Point, pressed: bool) -> bool
+    where
+        T: Controller + 'static,
+    {
+        let changed = Cell::new(false);
+
+        self.controllers::<T>(drawables, |controller| {
+            if controller.track(pointer, pressed) {
+                changed.set(true);
+            }
+        });
+
+        changed.get()
+    }
+
+    /// Returns the topmost drawable at `point`, with the drawables of the
+    /// layouts containing it.
+    /// 
+    /// Follows the drawing order, the transformations of the drawables and 
+    /// the zones of the layouts hiding their overflow. Uses the spatial 
+    /// [`hit::Index`] created by the last [`build`](Self::build), drawables 
+    /// which were not built last, like copies of them, are indexed again at
+    /// each call. The drawables built last are not indexed again when they 
+    /// are changed.
+    pub fn hit_test(&self, drawables: &[Drawable], point: Point) -> Option<Hit> {
+        let index = self.index.borrow();
+
+        match index.is_for(drawables) {
+            true => index.hit(drawables, point),
+            false => hit::Index::new(drawables, self.zone).hit(drawables, point),
+        }
+    }
+
+    /// Returns the pointer dispatcher, to change its delays and threshold.
+    pub fn dispatcher_mut(&mut self) -> &mut pointer::Dispatcher {
+        &mut self.dispatcher
+    }
+
+    /// Dispatches a raw pointer input of the backend to the controllers. 
+    /// Returns the dispatched events with the identifiers of the controllers
+    /// they were dispatched to.
+    /// 
+    /// The controller under the pointer is the closest controller to the
+    /// [hit](Self::hit_test) drawable, from the drawable itself to the 
+    /// layouts containing it. Each event [propagates](propagation) through 
+    /// the controllers containing its target, then the target does its 
+    /// default action. The interaction states of the controllers are 
+    /// updated, so the view has to be built again.
+    /// 
+    /// A button pressing a controller focuses the closest focusable 
+    /// controller containing it, without visible focus. Pressing out of the 
+    /// controllers removes the focus. Pressing out of the dismissible popups
+    /// of the overlay only closes them. The barrier of a modal popup blocks 
+    /// the pointer for the widgets under it.
+    pub fn pointer(&mut self, drawables: &[Drawable], input: pointer::Input) -> Vec<(usize, pointer::Event)> {
+        let snapshot = Snapshot::new(&mut self.layout, &mut self.overlay, drawables);
+        let hit = input.position().and_then(|position| self.hit_test(drawables, position));
+
+        // Pressing out of the dismissible popups closes them, the press is 
+        // not dispatched. The popups over a non-dismissible popup stay open.
+        if matches!(input, pointer::Input::Down { .. }) {
+            let level = hit.as_ref().map_or(0, |hit| self.level(&snapshot, hit));
+            let from = (level..self.popups())
+                .rev()
+                .take_while(|&popup| self.overlay[popup].dismissible)
+                .last();
+
+            if let Some(from) = from {
+                self.close(drawables, from, None);
+                return vec![];
+            }
+        }
+
+        // Finds the controller under the pointer.
+        let target = hit
+            .and_then(|hit| {
+                hit.chain()
+                    .map(|i| drawables[i].group_id as usize)
+                    .find(|id| snapshot.is_controller(*id))
+            });
+
+        let events = self.dispatcher.dispatch(input, target);
+
+        // The controllers containing the target are hovered with it.
+        let hovered = match target {
+            Some(target) => Propagation::new(drawables, &snapshot, target, true).path().to_vec(),
+            None => vec![],
+        };
+
+        // Time passing does not change the interaction states.
+        let track = !matches!(input, pointer::Input::Tick { .. });
+        let pressed = self.dispatcher.is_pressed();
+        let focused = self.focus.focused();
+        let visible = self.focus.is_visible();
+
+        if track {
+            controllers::browse_controllers(&mut self.layout, &mut self.overlay, drawables, &mut |controller, id| {
+                controller.hover(hovered.contains(&id), pressed);
+                controller.focus(focused == Some(id), visible);
+            });
+        }
+
+        // Pressing out of the controllers.
+        if target.is_none() && matches!(input, pointer::Input::Down { .. }) {
+            self.set_focus(drawables, None, false);
+        }
+
+        for (target, event) in &events {
+            let theme = &self.theme;
+            let mut propagation = Propagation::new(drawables, &snapshot, *target, event.kind.bubbles());
+
+            propagation::propagate(&mut self.layout, &mut self.overlay, drawables, &mut propagation, &mut |controller, propagation| {
+                controller.on_pointer(event, propagation, theme);
+            });
+
+            if propagation.is_default_prevented() {
+                continue;
+            }
+
+            controllers::browse_controllers(&mut self.layout, &mut self.overlay, drawables, &mut |controller, id| {
+                if id == *target {
+                    controller.on_pointer_default(event, theme);
+                }
+            });
+
+            if event.kind == pointer::Kind::Down {
+                let focused = propagation.path()
+                    .iter()
+                    .rev()
+                    .find(|id| snapshot.focusable().contains(id))
+                    .copied();
+
+                self.set_focus(drawables, focused, false);
+            }
+        }
+
+        self.update_overlay(drawables, &snapshot);
+
+        events
+    }
+
+    /// Returns the popups opened over the layout, from the bottom one to the
+    /// top one.
+    pub fn overlay(&self) -> &[Popup] {
+        &self.overlay[..self.popups()]
+    }
+
+    /// Returns the number of popups opened over the layout, the shown toast 
+    /// being after them in the overlay.
+    fn popups(&self) -> usize {
+        match self.toast_left {
+            Some(_) => self.overlay.len() - 1,
+            None => self.overlay.len(),
+        }
+    }
+
+    /// Opens a popup over the layout and the other popups. The view has to 
+    /// be built again to draw it. The first focusable controller of the 
+    /// popup takes the focus, so the keyboard reaches its items.
+    /// 
+    /// The controllers open popups with 
+    /// [`Handler::take_popup()`](controllers::Handler::take_popup), the view 
+    /// opens them after dispatching each event.
+    pub fn open(&mut self, popup: Popup) {
+        let popups = self.popups();
+        self.overlay.insert(popups, popup);
+
+        self.focus_popup(popups);
+    }
+
+    /// Gives the focus to the first focusable controller of a popup, which 
+    /// is not built yet. The focus stays visible when it was, and goes back 
+    /// to the previously focused controller when the popup is closed.
+    fn focus_popup(&mut self, popup: usize) {
+        let mut first = None;
+
+        controllers::browse_unbuilt(&mut self.layout, &mut self.overlay, &mut |controller, id, index| {
+            if first.is_none() && index == Some(popup) && controllers::is_focusable(controller) {
+                first = Some(id);
+            }
+        });
+
+        let Some(first) = first else {
+            return;
+        };
+
+        let previous = self.focus.focused();
+        self.overlay[popup].focus_back = previous;
+        self.focus.set(Some(first), self.focus.is_visible());
+        let visible = self.focus.is_visible();
+        let theme = &self.theme;
+
+        controllers::browse_unbuilt(&mut self.layout, &mut self.overlay, &mut |controller, id, _| {
+            tell_focus(controller, id, previous, Some(first), visible, theme);
+        });
+    }
+
+    /// Opens a modal popup in the middle of the view, with a dialog. The view
+    /// has to be built again to draw it.
+    pub fn show_dialog(&mut self, dialog: Dialog) {
+        let size = dialog.size;
+        let dismissible = dialog.dismissible;
+
+        self.open(Popup::new(self.zone, Placement::Centre, size, dialog)
+            .modal()
+            .with_dismissible(dismissible));
+    }
+
+    /// Shows a toast at the bottom of the view, after the toasts already 
+    /// shown or waiting. The view has to be built again to draw it.
+    pub fn show_toast(&mut self, toast: Toast) {
+        self.toasts.push_back(toast);
+
+        if self.toast_left.is_none() {
+            self.show_next_toast();
+        }
+    }
+
+    /// Returns the shown toast, over the popups.
+    pub fn toast(&self) -> Option<&Popup> {
+        self.toast_left.and(self.overlay.last())
+    }
+
+    /// Shows the next waiting toast, if any.
+    fn show_next_toast(&mut self) {
+        if let Some(toast) = self.toasts.pop_front() {
+            self.toast_left = Some(toast.duration);
+            self.overlay.push(Popup::new(self.zone, Placement::Bottom, toast.size, toast).with_dismissible(false));
+        }
+    }
+
+    /// Lets some time pass for the shown toast. Returns whether it was closed
+    /// because its duration passed, so the view has to be built again to 
+    /// draw the next one.
+    pub fn advance(&mut self, drawables: &[Drawable], elapsed: Duration) -> bool {
+        match self.toast_left {
+            Some(left) if elapsed >= left => {
+                let snapshot = Snapshot::new(&mut self.layout, &mut self.overlay, drawables);
+                self.close_toast(drawables, &snapshot, None);
+                true
+            }
+            Some(left) => {
+                self.toast_left = Some(left - elapsed);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Closes the shown toast, tells it about the chosen action, then shows
+    /// the next one. The focus is removed when it was in the toast.
+    fn close_toast(&mut self, drawables: &[Drawable], snapshot: &Snapshot, choice: Option<usize>) {
+        if self.toast_left.take().is_none() {
+            return;
+        }
+
+        let mut toast = self.overlay.pop().unwrap();
+
+        if let Some(controller) = toast.widget.as_controller_mut() {
+            controller.on_popup_close(choice, &self.theme);
+        }
+
+        let focused = self.focus.focused();
+
+        if focused.and_then(|focused| snapshot.popup_of(focused)) == Some(self.overlay.len()) {
+            self.set_focus(drawables, None, false);
+        }
+
+        self.show_next_toast();
+    }
+
+    /// Closes all the popups. The controllers which opened them are told with
+    /// [`Handler::on_popup_close()`](controllers::Handler::on_popup_close).
+    pub fn dismiss(&mut self, drawables: &[Drawable]) {
+        self.close(drawables, 0, None);
+    }
+
+    /// Closes a popup and the popups over it. The controller which opened the
+    /// popup and the controller drawn as the popup are told about the chosen
+    /// item, the others are dismissed.
+    /// 
+    /// The focus goes back to the controller focused before the popup, or to
+    /// the controller which opened it, when it was in the closed popups, even
+    /// when they were not built yet.
+    fn close(&mut self, drawables: &[Drawable], from: usize, choice: Option<usize>) {
+        let popups = self.popups();
+
+        if from >= popups {
+            return;
+        }
+
+        let focused = self.focus.focused();
+        let focused_popup = focused.and_then(|focused| self.popup_of_unbuilt(focused));
+
+        let mut closed: Vec<Popup> = self.overlay.drain(from..popups).collect();
+        let theme = &self.theme;
+
+        // The top popups are closed first.
+        for (i, popup) in closed.iter_mut().enumerate().rev() {
+            let choice = if i == 0 { choice } else { None };
+
+            if let Some(controller) = popup.widget.as_controller_mut() {
+                controller.on_popup_close(choice, theme);
+            }
+
+            if let Some(owner) = popup.owner {
+                controllers::browse_controllers(&mut self.layout, &mut self.overlay, drawables, &mut |controller, id| {
+                    if id == owner {
+                        controller.on_popup_close(choice, theme);
+                    }
+                });
+            }
+        }
+
+        if focused_popup.is_some_and(|popup| (from..popups).contains(&popup)) {
+            let visible = self.focus.is_visible();
+            self.set_focus(drawables, closed[0].focus_back.or(closed[0].owner), visible);
+        }
+    }
+
+    /// Returns the index of the popup containing a controller, built or not,
+    /// `None` when it is in the view's layout.
+    fn popup_of_unbuilt(&mut self, id: usize) -> Option<usize> {
+        let mut popup = None;
+
+        controllers::browse_unbuilt(&mut self.layout, &mut self.overlay, &mut |_, other, index| {
+            if other == id {
+                popup = index;
+            }
+        });
+
+        popup
+    }
+
+    /// Opens the popups returned by the controllers, and closes the popup in
+    /// which an item was chosen.
+    fn update_overlay(&mut self, drawables: &[Drawable], snapshot: &Snapshot) {
+        let mut opened = vec![];
+        let mut chosen = None;
+
+        controllers::browse_controllers(&mut self.layout, &mut self.overlay, drawables, &mut |controller, id| {
+            if let Some(mut popup) = controller.take_popup() {
+                popup.owner = Some(id);
+                opened.push(popup);
+            }
+
+            if let Some(choice) = controller.take_choice() {
+                chosen = chosen.or(Some((id, choice)));
+            }
+        });
+
+        if let Some((id, choice)) = chosen {
+            match snapshot.popup_of(id) {
+                Some(popup) if popup == self.popups() => self.close_toast(drawables, snapshot, Some(choice)),
+                Some(popup) => self.close(drawables, popup, Some(choice)),
+                None => (),
+            }
+        }
+
+        for popup in opened {
+            self.open(popup);
+        }
+    }
+
+    /// Returns the number of popups under a hit drawable. A hit on the 
+    /// barrier of a modal popup is under the popup, and a hit on the toast is
+    /// over all the popups.
+    fn level(&self, snapshot: &Snapshot, hit: &Hit) -> usize {
+        match snapshot.popup_of_drawable(hit.index) {
+            None => 0,
+            Some(popup) if popup >= self.popups() => self.popups(),
+            Some(popup) if self.overlay[popup].modal && hit.ancestors.is_empty() => popup,
+            Some(popup) => popup + 1,
+        }
+    }
+
+    /// Whether a controller is reachable by the keyboard, not under the top 
+    /// modal popup.
+    fn is_reachable(&self, snapshot: &Snapshot, id: usize) -> bool {
+        match self.overlay[..self.popups()].iter().rposition(|popup| popup.modal) {
+            Some(modal) => snapshot.popup_of(id).is_some_and(|popup| popup >= modal),
+            None => true,
+        }
+    }
+
+    /// Returns the focus manager, to know which controller has the keyboard 
+    /// focus.
+    pub fn focus(&self) -> &focus::Manager {
+        &self.focus
+    }
+
+    /// Returns the identifiers of the focusable controllers, in the order 
+    /// the focus goes through them with tab. 
+    /// 
+    /// It is the order of the controllers in the widget tree, a controller 
+    /// wrapping a layout being before the controllers in the layout. The 
+    /// disabled controllers and the controllers under a modal popup are not 
+    /// focusable.
+    pub fn focus_order(&mut self, drawables: &[Drawable]) -> Vec<usize> {
+        let snapshot = Snapshot::new(&mut self.layout, &mut self.overlay, drawables);
+
+        snapshot.focusable()
+            .iter()
+            .copied()
+            .filter(|id| self.is_reachable(&snapshot, *id))
+            .collect()
+    }
+
+    /// Gives the keyboard focus to a controller, or removes it with `None`. 
+    /// The focus is visible when given with the keyboard. Returns whether the
+    /// focus changed, so the view has to be built again to restyle the 
+    /// drawables.
+    /// 
+    /// The controllers losing and gaining the focus are told with 
+    /// [`Handler::on_focus_change()`](controllers::Handler::on_focus_change).
+    pub fn set_focus(&mut self, drawables: &[Drawable], focused: Option<usize>, visible: bool) -> bool {
+        let previous = self.focus.focused();
+        let changed = self.focus.set(focused, visible);
+        let visible = self.focus.is_visible();
+        let theme = &self.theme;
+
+        controllers::browse_controllers(&mut self.layout, &mut self.overlay, drawables, &mut |controller, id| {
+            tell_focus(controller, id, previous, focused, visible, theme);
+        });
+
+        changed
+    }
+
+    /// Moves the visible focus to the next controller in the 
+    /// [focus order](Self::focus_order), like tab does. Returns whether the 
+    /// focus changed.
+    pub fn focus_next(&mut self, drawables: &[Drawable]) -> bool {
+        let order = self.focus_order(drawables);
+        let next = self.focus.next(&order);
+        self.set_focus(drawables, next, true)
+    }
+
+    /// Moves the visible focus to the previous controller in the 
+    /// [focus order](Self::focus_order), like shift-tab does. Returns whether
+    /// the focus changed.
+    pub fn focus_previous(&mut self, drawables: &[Drawable]) -> bool {
+        let order = self.focus_order(drawables);
+        let previous = self.focus.previous(&order);
+        self.set_focus(drawables, previous, true)
+    }
+
+    /// Dispatches a keyboard event of the backend to the focused controller, 
+    /// unless it is under a modal popup. Returns the identifier of the 
+    /// focused controller, if any.
+    /// 
+    /// The event [propagates](propagation) through the controllers containing 
+    /// the focused one, then the focused controller does its default action.
+    /// Unless a controller prevented it, tab moves the focus to the next 
+    /// controller and shift-tab to the previous one, and escape closes the 
+    /// top popup when it is dismissible. The interaction states 
+    /// of the controllers can change, so the view has to be built again.
+    pub fn key(&mut self, drawables: &[Drawable], event: keyboard::Event) -> Option<usize> {
+        let snapshot = Snapshot::new(&mut self.layout, &mut self.overlay, drawables);
+
+        let focused = self.focus
+            .focused()
+            .filter(|focused| snapshot.is_controller(*focused) && self.is_reachable(&snapshot, *focused));
+        let mut prevented = false;
+
+        if let Some(focused) = focused {
+            let theme = &self.theme;
+            let mut propagation = Propagation::new(drawables, &snapshot, focused, true);
+
+            propagation::propagate(&mut self.layout, &mut self.overlay, drawables, &mut propagation, &mut |controller, propagation| {
+                controller.on_key(&event, propagation, theme);
+            });
+
+            prevented = propagation.is_default_prevented();
+
+            if !prevented {
+                controllers::browse_controllers(&mut self.layout, &mut self.overlay, drawables, &mut |controller, id| {
+                    if id == focused {
+                        controller.on_key_default(&event, theme);
+                    }
+                });
+            }
+        }
+
+        self.update_overlay(drawables, &snapshot);
+
+        match event {
+            keyboard::Event::Down { key: keyboard::Key::Tab, modifiers, .. } 
+                if !prevented && modifiers.is_shift_only() => 
+            {
+                if modifiers.shift {
+                    self.focus_previous(drawables);
+                } else {
+                    self.focus_next(drawables);
+                }
+            }
+            keyboard::Event::Down { key: keyboard::Key::Escape, .. } 
+                if !prevented && self.overlay().last().is_some_and(|popup| popup.dismissible) =>
+            {
+                self.close(drawables, self.popups() - 1, None);
+            }
+            _ => (),
+        }
+
+        focused
+    }
+}
+
+/// Updates the state of a controller of identifier `id` when the focus moved
+/// from `previous` to `focused`, and tells it when it lost or gained the 
+/// focus.
+fn tell_focus(
+    controller: &mut dyn Controller, 
+    id: usize, 
+    previous: Option<usize>, 
+    focused: Option<usize>, 
+    visible: bool, 
+    theme: &Theme,
+) {
+    controller.focus(focused == Some(id), visible);
+
+    if previous != focused && previous == Some(id) {
+        controller.on_focus_change(false, theme);
+    }
+    if previous != focused && focused == Some(id) {
+        controller.on_focus_change(true, theme);
     }
 }
@@ -0,0 +1,91 @@
+// This file is part of "haussmann"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use any::ToAny;
+
+use haussmann_dev::Widget;
+
+use crate::{
+    controllers::Controller,
+    graphics::Matrix,
+    DebugWidget, Widget, themes::{State, Theme, Style}, Zone,
+};
+
+/// Wraps a widget giving it a share of the space of its layout.
+/// 
+/// The widgets of a layout which are not in a [`Container`](super::Container)
+/// share the space left by the containers. A widget has a weight of `1.0` by
+/// default, a widget of weight `2.0` takes twice its space.
+#[derive(Debug, Widget)]
+pub struct Weighted {
+    /// The weight of the widget, positive.
+    pub weight: f32,
+    /// The widget taking a share of the space.
+    pub widget: Box<dyn Widget>,
+}
+
+/// Creates a new weighted widget like its [`new`](Weighted::new) function.
+#[macro_export]
+macro_rules! weighted {
+    (weight: $weight:expr, widget: $widget:expr $(,)?) => {
+        Weighted::new($weight, $widget)
+    };
+}
+
+impl Widget for Weighted {
+    fn build(&self) -> Box<dyn Widget> {
+        self.widget.build()
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        self.widget.style(theme)
+    }
+
+    fn style_mut(&mut self, theme: &Theme) -> &mut Style {
+        self.widget.style_mut(theme)
+    }
+
+    fn transform(&self, zone: Zone) -> Matrix {
+        self.widget.transform(zone)
+    }
+
+    fn state(&self) -> State {
+        self.widget.state()
+    }
+
+    fn as_controller_mut(&mut self) -> Option<&mut dyn Controller> {
+        self.widget.as_controller_mut()
+    }
+
+    fn wrapped(&self) -> Option<&dyn Widget> {
+        Some(self.widget.as_ref())
+    }
+
+    fn wrapped_mut(&mut self) -> Option<&mut Box<dyn Widget>> {
+        Some(&mut self.widget)
+    }
+
+    fn id_count(&self) -> usize {
+        self.widget.id_count()
+    }
+}
+
+impl Weighted {
+    /// Creates a new weighted widget.
+    pub fn new<T: Widget + 'static>(weight: f32, widget: T) -> Self {
+        Self {
+            weight,
+            widget: Box::new(widget),
+        }
+    }
+
+    /// Returns the weight of a widget of a layout, `1.0` when it is not 
+    /// weighted.
+    pub(crate) fn of(widget: &dyn Widget) -> f32 {
+        widget
+            .as_any()
+            .downcast_ref::<Weighted>()
+            .map_or(1.0, |weighted| weighted.weight.max(0.0))
+    }
+}